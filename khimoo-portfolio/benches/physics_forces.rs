@@ -0,0 +1,111 @@
+//! Benchmark of the node graph force calculations
+//!
+//! Compares the exact O(n²) pairwise sums with the Barnes–Hut approximation on
+//! synthetic graphs of 50 to 5,000 nodes.
+//!
+//! Run with `cargo bench --bench physics_forces`; pass θ values to compare,
+//! e.g. `cargo bench --bench physics_forces -- 0.5 1.0`.
+
+use khimoo_portfolio::core::physics::{
+    attraction_forces, attraction_forces_exact, repulsion_forces, repulsion_forces_exact,
+    AttractionParams, Body, RepulsionParams,
+};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const NODE_COUNTS: [usize; 6] = [50, 200, 500, 1000, 2000, 5000];
+const DEFAULT_THETAS: [f32; 2] = [0.5, 0.7];
+/// Minimum measuring time per case
+const MEASURE_TIME: Duration = Duration::from_millis(300);
+
+/// Same parameters as the default ForceSettings
+const REPULSION: RepulsionParams = RepulsionParams {
+    strength: 136000.0,
+    min_distance: 150.0,
+};
+const ATTRACTION: AttractionParams = AttractionParams {
+    strength: 1500.0,
+    range: 300.0,
+};
+
+fn main() {
+    // cargo passes `--bench`; everything that parses as a number is a θ
+    let thetas: Vec<f32> = std::env::args()
+        .skip(1)
+        .filter_map(|arg| arg.parse().ok())
+        .collect();
+    let thetas = if thetas.is_empty() {
+        DEFAULT_THETAS.to_vec()
+    } else {
+        thetas
+    };
+
+    print!("{:>6} {:>12}", "nodes", "exact");
+    for theta in &thetas {
+        print!(" {:>12} {:>8}", format!("θ={}", theta), "speedup");
+    }
+    println!();
+
+    for count in NODE_COUNTS {
+        let bodies = synthetic_graph(count);
+        let exact = measure(|| {
+            black_box(repulsion_forces_exact(&bodies, &REPULSION));
+            black_box(attraction_forces_exact(&bodies, &ATTRACTION));
+        });
+
+        print!("{:>6} {:>12}", count, format_duration(exact));
+        for &theta in &thetas {
+            let approx = measure(|| {
+                black_box(repulsion_forces(&bodies, &REPULSION, theta));
+                black_box(attraction_forces(&bodies, &ATTRACTION, theta));
+            });
+            print!(
+                " {:>12} {:>7.1}x",
+                format_duration(approx),
+                exact.as_secs_f64() / approx.as_secs_f64()
+            );
+        }
+        println!();
+    }
+}
+
+/// Nodes scattered uniformly at roughly the density of the home graph
+/// A fixed xorshift seed keeps runs comparable.
+fn synthetic_graph(count: usize) -> Vec<Body> {
+    let mut state = 0x9e37_79b9_u32;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state as f32 / u32::MAX as f32
+    };
+    let side = (count as f32).sqrt() * 200.0;
+    (0..count)
+        .map(|_| Body {
+            x: next() * side,
+            y: next() * side,
+            radius: 30.0 + next() * 30.0,
+        })
+        .collect()
+}
+
+/// Mean time of one call, repeating until MEASURE_TIME has passed
+fn measure<F: FnMut()>(mut f: F) -> Duration {
+    f();
+    let start = Instant::now();
+    let mut iterations = 0u32;
+    while start.elapsed() < MEASURE_TIME {
+        f();
+        iterations += 1;
+    }
+    start.elapsed() / iterations
+}
+
+fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_secs_f64() * 1e6;
+    if micros >= 1000.0 {
+        format!("{:.2} ms", micros / 1000.0)
+    } else {
+        format!("{:.1} µs", micros)
+    }
+}
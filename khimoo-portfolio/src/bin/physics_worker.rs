@@ -0,0 +1,15 @@
+//! Web Worker that runs the graph physics off the main thread
+//!
+//! Built by Trunk next to the main application (see index.html); the app
+//! spawns it through the generated `physics_worker_loader.js`.
+
+#[cfg(target_arch = "wasm32")]
+fn main() {
+    khimoo_portfolio::web::physics_worker::run();
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() {
+    eprintln!("physics_worker only runs as a Web Worker in the browser");
+    std::process::exit(1);
+}
@@ -0,0 +1,120 @@
+use anyhow::{Context, Result};
+use clap::{Parser, ValueEnum};
+use std::path::PathBuf;
+
+use crate::core::graph::{DraftFilter, ExportFilter, ExportGraph};
+use crate::core::model::{ArticleIndex, ProcessedArticle, ARTICLE_SHARDS_DIR, INDEX_FILE};
+
+/// File format of the exported graph
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphExportFormat {
+    /// Graphviz DOT
+    Dot,
+    Graphml,
+    /// GEXF, as read by Gephi
+    Gexf,
+    /// JSON Graph Format
+    Json,
+}
+
+/// CLI arguments for the export graph command
+#[derive(Parser, Debug, Clone)]
+#[command(name = "export-graph")]
+#[command(about = "Export the article graph for Graphviz or Gephi")]
+pub struct ExportGraphArgs {
+    /// Directory containing processed data (index.json and article shards)
+    #[arg(short, long, default_value = "data")]
+    pub data_dir: PathBuf,
+
+    /// Output format
+    #[arg(short, long, value_enum)]
+    pub format: GraphExportFormat,
+
+    /// Output file (standard output if omitted)
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// Only export articles in these categories
+    #[arg(long, value_delimiter = ',')]
+    pub category: Vec<String>,
+
+    /// Only export articles with at least one of these tags
+    #[arg(long, value_delimiter = ',')]
+    pub tag: Vec<String>,
+
+    /// Whether to include drafts, leave them out or export only drafts
+    #[arg(long, value_enum, default_value_t = DraftFilter::Include)]
+    pub drafts: DraftFilter,
+}
+
+/// Command implementation for graph export
+pub struct ExportGraphCommand;
+
+impl ExportGraphCommand {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn execute(&self, args: ExportGraphArgs) -> Result<()> {
+        let articles = Self::load_articles(&args)?;
+        let filter = ExportFilter {
+            categories: args.category.clone(),
+            tags: args.tag.clone(),
+            drafts: args.drafts,
+        };
+        let graph = ExportGraph::build(&articles, &filter);
+
+        let content = match args.format {
+            GraphExportFormat::Dot => graph.to_dot(),
+            GraphExportFormat::Graphml => graph.to_graphml(),
+            GraphExportFormat::Gexf => graph.to_gexf(),
+            GraphExportFormat::Json => serde_json::to_string_pretty(&graph.to_json_graph())?,
+        };
+
+        match &args.output {
+            Some(path) => {
+                std::fs::write(path, content)
+                    .with_context(|| format!("Failed to write {}", path.display()))?;
+                println!(
+                    "✅ Exported {} articles and {} edges to {}",
+                    graph.nodes.len(),
+                    graph.edges.len(),
+                    path.display()
+                );
+            }
+            None => print!("{}", content),
+        }
+
+        Ok(())
+    }
+
+    /// Full article data from the shards listed in the index
+    fn load_articles(args: &ExportGraphArgs) -> Result<Vec<ProcessedArticle>> {
+        let index_path = args.data_dir.join(INDEX_FILE);
+        let json = std::fs::read_to_string(&index_path)
+            .with_context(|| format!("Failed to read {}", index_path.display()))?;
+        let index: ArticleIndex =
+            serde_json::from_str(&json).context("Failed to parse index.json")?;
+
+        index
+            .articles
+            .iter()
+            .map(|entry| {
+                let shard_path = args
+                    .data_dir
+                    .join(ARTICLE_SHARDS_DIR)
+                    .join(format!("{}.json", entry.slug));
+                let json = std::fs::read_to_string(&shard_path)
+                    .with_context(|| format!("Failed to read {}", shard_path.display()))?;
+                serde_json::from_str(&json)
+                    .with_context(|| format!("Failed to parse {}", shard_path.display()))
+            })
+            .collect()
+    }
+}
+
+impl Default for ExportGraphCommand {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,62 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use std::path::PathBuf;
+
+use crate::config::NodeConfig;
+use crate::core::graph::{SvgSnapshot, SvgTheme};
+use crate::core::model::{ArticleIndex, INDEX_FILE};
+
+/// CLI arguments for the export SVG command
+#[derive(Parser, Debug, Clone)]
+#[command(name = "export-svg")]
+#[command(about = "Render the precomputed graph layout as an SVG image")]
+pub struct ExportSvgArgs {
+    /// Directory containing processed data (index.json)
+    #[arg(short, long, default_value = "data")]
+    pub data_dir: PathBuf,
+
+    /// Output file
+    #[arg(short, long, default_value = "graph.svg")]
+    pub output: PathBuf,
+}
+
+/// Command implementation for SVG snapshots
+pub struct ExportSvgCommand;
+
+impl ExportSvgCommand {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn execute(&self, args: ExportSvgArgs) -> Result<()> {
+        let index_path = args.data_dir.join(INDEX_FILE);
+        let json = std::fs::read_to_string(&index_path)
+            .with_context(|| format!("Failed to read {}", index_path.display()))?;
+        let index: ArticleIndex =
+            serde_json::from_str(&json).context("Failed to parse index.json")?;
+
+        let node_config = NodeConfig::default().for_index(&index);
+        let snapshot = SvgSnapshot::from_layout(&index, &node_config).with_context(|| {
+            format!(
+                "{} has no precomputed layout; run process-articles with --layout-steps above 0",
+                index_path.display()
+            )
+        })?;
+        std::fs::write(&args.output, snapshot.to_svg(&SvgTheme::default()))
+            .with_context(|| format!("Failed to write {}", args.output.display()))?;
+
+        println!(
+            "✅ Exported {} nodes and {} edges to {}",
+            snapshot.nodes.len(),
+            snapshot.edges.len(),
+            args.output.display()
+        );
+        Ok(())
+    }
+}
+
+impl Default for ExportSvgCommand {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,125 @@
+use anyhow::{Context, Result};
+use clap::{Parser, ValueEnum};
+use std::path::PathBuf;
+
+use crate::core::graph::GraphAnalysis;
+use crate::core::model::{ArticleIndex, INDEX_FILE};
+
+/// Output format of the graph command
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GraphOutputFormat {
+    #[default]
+    Table,
+    Json,
+}
+
+/// CLI arguments for the graph command
+#[derive(Parser, Debug, Clone)]
+#[command(name = "graph")]
+#[command(about = "Analyse the article link graph")]
+pub struct GraphArgs {
+    /// Directory containing processed data (index.json)
+    #[arg(short, long, default_value = "data")]
+    pub data_dir: PathBuf,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = GraphOutputFormat::Table)]
+    pub format: GraphOutputFormat,
+
+    /// Number of articles to list in the table
+    #[arg(long, default_value_t = 20)]
+    pub top: usize,
+}
+
+/// Command implementation for link graph analytics
+pub struct GraphCommand;
+
+impl GraphCommand {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn execute(&self, args: GraphArgs) -> Result<()> {
+        let index_path = args.data_dir.join(INDEX_FILE);
+        let json = std::fs::read_to_string(&index_path)
+            .with_context(|| format!("Failed to read {}", index_path.display()))?;
+        let index: ArticleIndex =
+            serde_json::from_str(&json).context("Failed to parse index.json")?;
+
+        let analysis = GraphAnalysis::of(&index);
+        match args.format {
+            GraphOutputFormat::Table => Self::print_table(&index, &analysis, args.top),
+            GraphOutputFormat::Json => println!("{}", serde_json::to_string_pretty(&analysis)?),
+        }
+
+        Ok(())
+    }
+
+    fn print_table(index: &ArticleIndex, analysis: &GraphAnalysis, top: usize) {
+        let mut rows: Vec<_> = index
+            .articles
+            .iter()
+            .filter_map(|article| Some((article, analysis.metrics.get(&article.slug)?)))
+            .collect();
+        rows.sort_by(|a, b| {
+            b.1.pagerank
+                .total_cmp(&a.1.pagerank)
+                .then_with(|| a.0.slug.cmp(&b.0.slug))
+        });
+
+        println!("🕸️  Link Graph");
+        println!();
+        println!("   📚 Articles: {}", rows.len());
+        println!("   🧩 Components: {}", analysis.components.len());
+        println!("   👥 Communities: {}", analysis.communities.len());
+        println!("   🌉 Bridges: {}", analysis.bridges.len());
+
+        println!();
+        let slug_width = rows
+            .iter()
+            .take(top)
+            .map(|(article, _)| article.slug.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max("article".len());
+        println!(
+            "   {:<slug_width$}  {:>8}  {:>11}  {:>9}  {:>9}",
+            "article", "pagerank", "betweenness", "component", "community"
+        );
+        for (article, metrics) in rows.iter().take(top) {
+            let padding = slug_width - article.slug.chars().count();
+            println!(
+                "   {}{}  {:>8.4}  {:>11.4}  {:>9}  {:>9}",
+                article.slug,
+                " ".repeat(padding),
+                metrics.pagerank,
+                metrics.betweenness,
+                metrics.component,
+                metrics.community
+            );
+        }
+        if rows.len() > top {
+            println!("   … and {} more", rows.len() - top);
+        }
+
+        println!();
+        println!("👥 Communities:");
+        for (i, members) in analysis.communities.iter().enumerate() {
+            println!("   {}: {}", i, members.join(", "));
+        }
+
+        if !analysis.bridges.is_empty() {
+            println!();
+            println!("🌉 Bridges:");
+            for (from, to) in &analysis.bridges {
+                println!("   {} — {}", from, to);
+            }
+        }
+    }
+}
+
+impl Default for GraphCommand {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -3,15 +3,41 @@
 //! This module contains individual command implementations for
 //! article processing, link validation, and other CLI operations.
 
+#[cfg(feature = "cli-tools")]
+pub mod export_graph;
+#[cfg(feature = "cli-tools")]
+pub mod export_svg;
+#[cfg(feature = "cli-tools")]
+pub mod graph;
+#[cfg(feature = "cli-tools")]
+pub mod prerender_meta;
 #[cfg(feature = "cli-tools")]
 pub mod process_articles;
 #[cfg(feature = "cli-tools")]
+pub mod schema;
+#[cfg(feature = "cli-tools")]
+pub mod stats;
+#[cfg(feature = "cli-tools")]
+pub mod unlinked_mentions;
+#[cfg(feature = "cli-tools")]
 pub mod validate_links;
 
 // Re-export command implementations
 #[cfg(feature = "cli-tools")]
-pub use process_articles::{
-    ArticlesData, ProcessArticlesArgs, ProcessArticlesCommand, ProcessedArticle,
-};
+pub use export_graph::{ExportGraphArgs, ExportGraphCommand, GraphExportFormat};
+#[cfg(feature = "cli-tools")]
+pub use export_svg::{ExportSvgArgs, ExportSvgCommand};
+#[cfg(feature = "cli-tools")]
+pub use graph::{GraphArgs, GraphCommand, GraphOutputFormat};
+#[cfg(feature = "cli-tools")]
+pub use prerender_meta::{PrerenderMetaArgs, PrerenderMetaCommand};
+#[cfg(feature = "cli-tools")]
+pub use process_articles::{ProcessArticlesArgs, ProcessArticlesCommand};
+#[cfg(feature = "cli-tools")]
+pub use schema::{SchemaArgs, SchemaCommand};
+#[cfg(feature = "cli-tools")]
+pub use stats::{StatsArgs, StatsCommand};
+#[cfg(feature = "cli-tools")]
+pub use unlinked_mentions::{UnlinkedMentionsArgs, UnlinkedMentionsCommand};
 #[cfg(feature = "cli-tools")]
 pub use validate_links::{ValidateLinksArgs, ValidateLinksCommand};
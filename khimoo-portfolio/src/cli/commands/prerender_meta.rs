@@ -0,0 +1,187 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use std::path::PathBuf;
+
+use crate::config_loader::{get_deployment_config, get_og_card_config};
+use crate::core::media::og_card::OgCardConfig;
+use crate::core::model::{ArticleIndex, ProcessedArticle, ARTICLE_SHARDS_DIR, INDEX_FILE};
+
+/// CLI arguments for the prerender meta command
+#[derive(Parser, Debug, Clone)]
+#[command(name = "prerender-meta")]
+#[command(about = "Write per-article HTML pages with OpenGraph meta tags")]
+pub struct PrerenderMetaArgs {
+    /// Directory containing processed data (index.json and article shards)
+    #[arg(short, long, default_value = "data")]
+    pub data_dir: PathBuf,
+
+    /// Built index.html used as the page template
+    #[arg(short, long, default_value = "dist/index.html")]
+    pub index_html: PathBuf,
+
+    /// Deployment root the article pages are written into
+    #[arg(short, long, default_value = "dist")]
+    pub output_dir: PathBuf,
+
+    /// Enable verbose output
+    #[arg(short, long)]
+    pub verbose: bool,
+}
+
+/// Command implementation for pre-rendering article meta tags
+/// Crawlers do not run the WebAssembly app, so each article gets a copy of
+/// index.html at `article/<slug>/index.html` with its meta tags filled in.
+pub struct PrerenderMetaCommand {
+    og_config: OgCardConfig,
+    base_url: String,
+}
+
+impl PrerenderMetaCommand {
+    pub fn new() -> Result<Self> {
+        let og_config = get_og_card_config();
+        let (github_pages_path, _) = get_deployment_config();
+        let base_url = format!("{}{}", og_config.site_url, github_pages_path);
+
+        Ok(Self {
+            og_config,
+            base_url,
+        })
+    }
+
+    pub fn execute(&self, args: PrerenderMetaArgs) -> Result<()> {
+        let index_path = args.data_dir.join(INDEX_FILE);
+        let json = std::fs::read_to_string(&index_path)
+            .with_context(|| format!("Failed to read {}", index_path.display()))?;
+        let index: ArticleIndex =
+            serde_json::from_str(&json).context("Failed to parse index.json")?;
+
+        let template = std::fs::read_to_string(&args.index_html)
+            .with_context(|| format!("Failed to read {}", args.index_html.display()))?;
+
+        for entry in &index.articles {
+            let shard_path = args
+                .data_dir
+                .join(ARTICLE_SHARDS_DIR)
+                .join(format!("{}.json", entry.slug));
+            let json = std::fs::read_to_string(&shard_path)
+                .with_context(|| format!("Failed to read {}", shard_path.display()))?;
+            let article: ProcessedArticle = serde_json::from_str(&json)
+                .with_context(|| format!("Failed to parse {}", shard_path.display()))?;
+
+            let page = inject_meta_tags(
+                &template,
+                &self.page_title(&article),
+                &self.render_meta_tags(&article),
+            );
+
+            let page_dir = args.output_dir.join("article").join(&article.slug);
+            std::fs::create_dir_all(&page_dir)
+                .with_context(|| format!("Failed to create {}", page_dir.display()))?;
+            let page_path = page_dir.join("index.html");
+            std::fs::write(&page_path, page)
+                .with_context(|| format!("Failed to write {}", page_path.display()))?;
+
+            if args.verbose {
+                println!("📄 Wrote {}", page_path.display());
+            }
+        }
+
+        println!(
+            "✅ Pre-rendered meta tags for {} articles",
+            index.articles.len()
+        );
+
+        Ok(())
+    }
+
+    fn page_title(&self, article: &ProcessedArticle) -> String {
+        format!("{} | {}", article.title, self.og_config.site_name)
+    }
+
+    fn render_meta_tags(&self, article: &ProcessedArticle) -> String {
+        let mut tags = vec![
+            meta_property("og:type", "article"),
+            meta_property("og:title", &article.title),
+            meta_property("og:site_name", &self.og_config.site_name),
+            meta_property(
+                "og:url",
+                &format!("{}/article/{}", self.base_url, article.slug),
+            ),
+        ];
+
+        if let Some(summary) = &article.summary {
+            tags.push(meta_name("description", summary));
+            tags.push(meta_property("og:description", summary));
+        }
+
+        if let Some(og_image) = &article.og_image {
+            tags.push(meta_property(
+                "og:image",
+                &format!("{}/data/{}", self.base_url, og_image),
+            ));
+            tags.push(meta_property(
+                "og:image:width",
+                &self.og_config.width.to_string(),
+            ));
+            tags.push(meta_property(
+                "og:image:height",
+                &self.og_config.height.to_string(),
+            ));
+            tags.push(meta_name("twitter:card", "summary_large_image"));
+        } else {
+            tags.push(meta_name("twitter:card", "summary"));
+        }
+        tags.push(meta_name("twitter:title", &article.title));
+
+        tags.join("\n    ")
+    }
+}
+
+impl Default for PrerenderMetaCommand {
+    fn default() -> Self {
+        Self::new().expect("Failed to create PrerenderMetaCommand")
+    }
+}
+
+/// Replace the page title and add meta tags to the `<head>` of an HTML page
+fn inject_meta_tags(template: &str, title: &str, meta_tags: &str) -> String {
+    let title_tag = format!("<title>{}</title>", escape_html(title));
+    let head = format!("{}\n    {}", title_tag, meta_tags);
+
+    if let (Some(start), Some(end)) = (template.find("<title>"), template.find("</title>")) {
+        let end = end + "</title>".len();
+        format!("{}{}{}", &template[..start], head, &template[end..])
+    } else if let Some(head_end) = template.find("</head>") {
+        format!(
+            "{}    {}\n{}",
+            &template[..head_end],
+            head,
+            &template[head_end..]
+        )
+    } else {
+        template.to_string()
+    }
+}
+
+fn meta_property(property: &str, content: &str) -> String {
+    format!(
+        r#"<meta property="{}" content="{}">"#,
+        property,
+        escape_html(content)
+    )
+}
+
+fn meta_name(name: &str, content: &str) -> String {
+    format!(
+        r#"<meta name="{}" content="{}">"#,
+        name,
+        escape_html(content)
+    )
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
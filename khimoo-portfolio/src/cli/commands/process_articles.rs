@@ -1,16 +1,26 @@
 use anyhow::{Context, Result};
-use chrono::Utc;
 use clap::Parser;
-use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
-use crate::config_loader::{get_default_articles_dir, get_images_dir};
-use crate::core::articles::links::{ExtractedLink, ProcessedArticleRef};
-use crate::core::articles::metadata::ArticleMetadata;
+use crate::cli::utils::articles::read_articles;
+use crate::config::NodeConfig;
+use crate::config_loader::{
+    get_default_articles_dir, get_images_dir, get_og_card_config, get_recommendation_config,
+    get_size_by_centrality,
+};
+use crate::core::articles::dates::date_articles;
+use crate::core::articles::links::{ExtractedLink, LinkType};
 use crate::core::articles::processor::ArticleProcessor;
+use crate::core::articles::recommend::{recommend, term_counts, TermCounts};
+use crate::core::graph::GraphAnalysis;
 #[cfg(feature = "cli-tools")]
 use crate::core::media::image_optimizer::ImageOptimizer;
+#[cfg(feature = "cli-tools")]
+use crate::core::media::og_card::{OgCardGenerator, OgCardInput};
+use crate::core::model::{ArticleIndex, ProcessedArticle, ARTICLE_SHARDS_DIR, INDEX_FILE};
+use crate::core::physics::{ForceSettings, HomeGraph, Position, DEFAULT_LAYOUT_STEPS};
 
 /// CLI arguments for the process articles command
 #[derive(Parser, Debug, Clone)]
@@ -36,45 +46,18 @@ pub struct ProcessArticlesArgs {
     /// Optimize images during processing
     #[arg(long)]
     pub optimize_images: bool,
-}
-
-/// Processed article data structure
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ProcessedArticle {
-    pub slug: String,
-    pub title: String,
-    pub metadata: ArticleMetadata,
-    pub file_path: String,
-    pub outbound_links: Vec<ExtractedLink>,
-    pub inbound_links: Vec<ExtractedLink>,
-    pub processed_at: String,
-}
 
-impl ProcessedArticle {
-    /// Create from ProcessedArticleRef and file path
-    pub fn from_ref_and_file_path(article_ref: ProcessedArticleRef, file_path: String) -> Self {
-        use chrono::Utc;
+    /// Generate OpenGraph social card images for each article
+    #[arg(long)]
+    pub og_cards: bool,
 
-        Self {
-            slug: article_ref.slug,
-            title: article_ref.title,
-            metadata: article_ref.metadata,
-            file_path,
-            outbound_links: article_ref.outbound_links,
-            inbound_links: article_ref.inbound_links,
-            processed_at: Utc::now().to_rfc3339(),
-        }
-    }
+    /// Physics steps for the precomputed home graph layout (0 to skip)
+    #[arg(long, default_value_t = DEFAULT_LAYOUT_STEPS)]
+    pub layout_steps: usize,
 }
 
-/// Articles data structure for JSON output
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ArticlesData {
-    pub articles: Vec<ProcessedArticle>,
-    pub generated_at: String,
-    pub total_count: usize,
-    pub home_articles: Vec<String>,
-}
+/// Subdirectory of the data directory that holds OpenGraph cards
+pub const OG_CARDS_DIR: &str = "og";
 
 /// Command implementation for processing articles
 pub struct ProcessArticlesCommand {
@@ -103,7 +86,7 @@ impl ProcessArticlesCommand {
         let articles_dir = args
             .articles_dir
             .clone()
-            .unwrap_or_else(|| get_default_articles_dir());
+            .unwrap_or_else(get_default_articles_dir);
 
         if args.verbose {
             println!("Processing articles from: {}", articles_dir.display());
@@ -114,30 +97,129 @@ impl ProcessArticlesCommand {
         std::fs::create_dir_all(&args.output_dir).context("Failed to create output directory")?;
 
         // Process articles
-        let articles = self.process_articles(&articles_dir, &args)?;
-
-        // Create articles data structure
-        let home_articles = articles
-            .iter()
-            .filter(|a| a.metadata.home_display)
-            .map(|a| a.slug.clone())
-            .collect();
-
-        let articles_data = ArticlesData {
-            total_count: articles.len(),
-            articles,
-            generated_at: Utc::now().to_rfc3339(),
-            home_articles,
-        };
-
-        // Write JSON output
-        let output_path = args.output_dir.join("articles.json");
-        let json = serde_json::to_string_pretty(&articles_data)?;
-        std::fs::write(&output_path, json).context("Failed to write articles.json")?;
+        let (mut articles, terms) = self.process_articles(&articles_dir, &args)?;
+        Self::date_articles(&mut articles);
+        Self::populate_inbound_links(&mut articles);
+        Self::populate_recommendations(&mut articles, &terms, args.verbose);
+
+        // Generate OpenGraph cards if requested
+        #[cfg(feature = "cli-tools")]
+        if args.og_cards {
+            self.generate_og_cards(&mut articles, &args)?;
+        }
+
+        // Write one shard per article, then the index that points at them
+        self.write_article_shards(&articles, &args.output_dir)?;
+
+        let mut index = ArticleIndex::from_articles(&articles);
+        if get_size_by_centrality() {
+            // Metrics first, so the layout is settled with the same node sizes the web draws
+            let analysis = GraphAnalysis::of(&index);
+            analysis.apply(&mut index);
+            index.size_by_centrality = true;
+            if args.verbose {
+                println!(
+                    "🕸️  Sizing nodes by link graph centrality ({} communities)",
+                    analysis.communities.len()
+                );
+            }
+        }
+        if args.layout_steps > 0 {
+            // Settle the home graph here so the web app can start from it
+            let node_config = NodeConfig::default().for_index(&index);
+            let graph = HomeGraph::from_index(&index, &node_config, Position::default());
+            index.layout = Some(graph.settle(ForceSettings::default(), args.layout_steps));
+            if args.verbose {
+                println!(
+                    "🧭 Precomputed layout for {} nodes ({} steps)",
+                    graph.nodes.len(),
+                    args.layout_steps
+                );
+            }
+        }
+
+        let output_path = args.output_dir.join(INDEX_FILE);
+        let json = serde_json::to_string_pretty(&index)?;
+        std::fs::write(&output_path, json).context("Failed to write index.json")?;
 
         if args.verbose {
-            println!("✅ Processed {} articles", articles_data.total_count);
-            println!("📄 Output written to: {}", output_path.display());
+            println!("✅ Processed {} articles", index.total_count);
+            println!("📄 Index written to: {}", output_path.display());
+        }
+
+        Ok(())
+    }
+
+    /// Normalised creation dates; articles without a usable `created_at` or
+    /// `updated_at` are put at the start of the timeline
+    fn date_articles(articles: &mut [ProcessedArticle]) {
+        for slug in date_articles(articles) {
+            eprintln!(
+                "⚠️  {} has no created_at, placing it at the start of the timeline",
+                slug
+            );
+        }
+    }
+
+    /// Record each internal link as an inbound link on its target article
+    /// The inbound link's `target_slug` is the slug of the linking article
+    fn populate_inbound_links(articles: &mut [ProcessedArticle]) {
+        let mut inbound: HashMap<String, Vec<ExtractedLink>> = HashMap::new();
+        for article in articles.iter() {
+            for link in article
+                .outbound_links
+                .iter()
+                .filter(|link| link.link_type == LinkType::MarkdownLink)
+            {
+                if link.target_slug == article.slug {
+                    continue;
+                }
+                inbound
+                    .entry(link.target_slug.clone())
+                    .or_default()
+                    .push(ExtractedLink {
+                        target_slug: article.slug.clone(),
+                        ..link.clone()
+                    });
+            }
+        }
+
+        for article in articles.iter_mut() {
+            article.inbound_links = inbound.remove(&article.slug).unwrap_or_default();
+        }
+    }
+
+    /// Attach build-time recommendations, weighted by `[recommendations]` in project.toml
+    fn populate_recommendations(
+        articles: &mut [ProcessedArticle],
+        terms: &HashMap<String, TermCounts>,
+        verbose: bool,
+    ) {
+        let mut recommendations = recommend(articles, terms, &get_recommendation_config());
+        for article in articles.iter_mut() {
+            article.recommendations = recommendations.remove(&article.slug).unwrap_or_default();
+        }
+        if verbose {
+            let count: usize = articles.iter().map(|a| a.recommendations.len()).sum();
+            println!("💡 Computed {} recommendations", count);
+        }
+    }
+
+    /// Write `articles/<slug>.json` for every article, removing shards of deleted articles
+    fn write_article_shards(&self, articles: &[ProcessedArticle], output_dir: &Path) -> Result<()> {
+        let shards_dir = output_dir.join(ARTICLE_SHARDS_DIR);
+        if shards_dir.exists() {
+            std::fs::remove_dir_all(&shards_dir)
+                .with_context(|| format!("Failed to clear {}", shards_dir.display()))?;
+        }
+        std::fs::create_dir_all(&shards_dir)
+            .with_context(|| format!("Failed to create {}", shards_dir.display()))?;
+
+        for article in articles {
+            let shard_path = shards_dir.join(format!("{}.json", article.slug));
+            let json = serde_json::to_string_pretty(article)?;
+            std::fs::write(&shard_path, json)
+                .with_context(|| format!("Failed to write {}", shard_path.display()))?;
         }
 
         Ok(())
@@ -147,29 +229,18 @@ impl ProcessArticlesCommand {
         &self,
         articles_dir: &Path,
         args: &ProcessArticlesArgs,
-    ) -> Result<Vec<ProcessedArticle>> {
+    ) -> Result<(Vec<ProcessedArticle>, HashMap<String, TermCounts>)> {
         let mut articles = Vec::new();
-
-        // Find all markdown files
-        for entry in WalkDir::new(articles_dir)
-            .follow_links(true)
-            .into_iter()
-            .filter_map(|e| e.ok())
-        {
-            let path = entry.path();
-            if path.extension().and_then(|s| s.to_str()) == Some("md") {
-                if args.verbose {
-                    println!("Processing: {}", path.display());
-                }
-
-                let content = std::fs::read_to_string(path)
-                    .with_context(|| format!("Failed to read file: {}", path.display()))?;
-
-                let processed_ref = self.processor.process_article(path, &content)?;
-                let file_path = path.to_string_lossy().to_string();
-                let processed = ProcessedArticle::from_ref_and_file_path(processed_ref, file_path);
-                articles.push(processed);
-            }
+        // Body text is not kept in the data, so count its terms while it is at hand
+        let mut terms = HashMap::new();
+
+        for file in read_articles(&self.processor, articles_dir, args.verbose)? {
+            terms.insert(file.article.slug.clone(), term_counts(&file.body));
+            let file_path = file.path.to_string_lossy().to_string();
+            articles.push(ProcessedArticle::from_ref_and_file_path(
+                file.article,
+                file_path,
+            ));
         }
 
         // Optimize images if requested
@@ -180,7 +251,37 @@ impl ProcessArticlesCommand {
             }
         }
 
-        Ok(articles)
+        Ok((articles, terms))
+    }
+
+    #[cfg(feature = "cli-tools")]
+    fn generate_og_cards(
+        &self,
+        articles: &mut [ProcessedArticle],
+        args: &ProcessArticlesArgs,
+    ) -> Result<()> {
+        // Cards were asked for, so a missing font fails the build instead of
+        // silently leaving every og_image empty
+        let generator = OgCardGenerator::new(get_og_card_config(), args.verbose).context(
+            "Cannot generate OG cards; add the font set as font_path under [og_card] in project.toml",
+        )?;
+
+        let output_dir = args.output_dir.join(OG_CARDS_DIR);
+        for article in articles.iter_mut() {
+            let input = OgCardInput {
+                slug: &article.slug,
+                title: &article.title,
+                category: article.metadata.category.as_deref(),
+            };
+            let file_name = generator.generate(&input, &output_dir)?;
+            article.og_image = Some(format!("{}/{}", OG_CARDS_DIR, file_name));
+        }
+
+        if args.verbose {
+            println!("🖼️  OG cards ready in: {}", output_dir.display());
+        }
+
+        Ok(())
     }
 
     #[cfg(feature = "cli-tools")]
@@ -0,0 +1,51 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use std::path::PathBuf;
+
+use crate::core::model::{json_schemas, SCHEMA_VERSION};
+
+/// CLI arguments for the schema command
+#[derive(Parser, Debug, Clone)]
+#[command(name = "schema")]
+#[command(about = "Write JSON Schemas of the generated data files")]
+pub struct SchemaArgs {
+    /// Output directory for the schema files
+    #[arg(short, long, default_value = "schema")]
+    pub output_dir: PathBuf,
+}
+
+/// Command implementation for JSON Schema generation
+/// The schemas are derived from the shared data model in `core::model`.
+pub struct SchemaCommand;
+
+impl SchemaCommand {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn execute(&self, args: SchemaArgs) -> Result<()> {
+        std::fs::create_dir_all(&args.output_dir)
+            .context("Failed to create schema output directory")?;
+
+        for (file_name, schema) in json_schemas() {
+            let path = args.output_dir.join(file_name);
+            let json = serde_json::to_string_pretty(&schema)? + "\n";
+            std::fs::write(&path, json)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            println!("📄 Wrote {}", path.display());
+        }
+
+        println!(
+            "✅ JSON Schemas written for data schema version {}",
+            SCHEMA_VERSION
+        );
+
+        Ok(())
+    }
+}
+
+impl Default for SchemaCommand {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,142 @@
+use anyhow::Result;
+use clap::Parser;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::cli::utils::articles::read_articles;
+use crate::config_loader::get_default_articles_dir;
+use crate::core::articles::processor::ArticleProcessor;
+
+/// Width of the longest histogram bar in characters
+const HISTOGRAM_WIDTH: usize = 30;
+
+/// CLI arguments for the stats command
+#[derive(Parser, Debug, Clone)]
+#[command(name = "stats")]
+#[command(about = "Print site-wide content statistics")]
+pub struct StatsArgs {
+    /// Directory containing markdown articles
+    #[arg(short, long)]
+    pub articles_dir: Option<PathBuf>,
+
+    /// Number of tags to show in the tag histogram
+    #[arg(long, default_value_t = 20)]
+    pub top_tags: usize,
+
+    /// Enable verbose output
+    #[arg(short, long)]
+    pub verbose: bool,
+}
+
+/// Command implementation for content statistics
+pub struct StatsCommand {
+    processor: ArticleProcessor,
+}
+
+impl StatsCommand {
+    pub fn new() -> Result<Self> {
+        let processor = ArticleProcessor::new()?;
+
+        Ok(Self { processor })
+    }
+
+    pub fn execute(&self, args: StatsArgs) -> Result<()> {
+        let articles_dir = args
+            .articles_dir
+            .clone()
+            .unwrap_or_else(get_default_articles_dir);
+
+        let articles: Vec<_> = read_articles(&self.processor, &articles_dir, args.verbose)?
+            .into_iter()
+            .map(|file| file.article)
+            .collect();
+
+        let total_words: usize = articles.iter().map(|a| a.stats.word_count).sum();
+        let total_minutes: u32 = articles.iter().map(|a| a.stats.reading_time_minutes).sum();
+        let total_code_blocks: usize = articles.iter().map(|a| a.stats.code_blocks).sum();
+        let total_images: usize = articles.iter().map(|a| a.stats.images).sum();
+        let total_links: usize = articles.iter().map(|a| a.stats.links).sum();
+
+        println!("📊 Content Statistics");
+        println!();
+        println!("   📚 Articles: {}", articles.len());
+        println!("   📝 Words: {}", total_words);
+        println!("   ⏱️  Reading time: {} min", total_minutes);
+        println!("   💻 Code blocks: {}", total_code_blocks);
+        println!("   🖼️  Images: {}", total_images);
+        println!("   🔗 Links: {}", total_links);
+
+        if let Some(longest) = articles.iter().max_by_key(|a| a.stats.word_count) {
+            println!(
+                "   📏 Longest: {} ({} words, {} min)",
+                longest.title, longest.stats.word_count, longest.stats.reading_time_minutes
+            );
+        }
+
+        let mut categories: HashMap<String, usize> = HashMap::new();
+        let mut tags: HashMap<String, usize> = HashMap::new();
+        for article in &articles {
+            let category = article
+                .metadata
+                .category
+                .as_deref()
+                .filter(|c| !c.trim().is_empty())
+                .unwrap_or("(none)");
+            *categories.entry(category.to_string()).or_default() += 1;
+
+            for tag in &article.metadata.tags {
+                *tags.entry(tag.clone()).or_default() += 1;
+            }
+        }
+
+        println!();
+        println!("📂 Categories:");
+        print_histogram(categories, usize::MAX);
+
+        println!();
+        println!("🏷️  Tags:");
+        if tags.is_empty() {
+            println!("   (no tags)");
+        } else {
+            print_histogram(tags, args.top_tags);
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for StatsCommand {
+    fn default() -> Self {
+        Self::new().expect("Failed to create StatsCommand")
+    }
+}
+
+/// Print counts as a bar chart, largest first, limited to `limit` rows
+fn print_histogram(counts: HashMap<String, usize>, limit: usize) {
+    let mut rows: Vec<(String, usize)> = counts.into_iter().collect();
+    rows.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+    let max_count = rows.first().map(|(_, count)| *count).unwrap_or(1).max(1);
+    let label_width = rows
+        .iter()
+        .take(limit)
+        .map(|(label, _)| label.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    for (label, count) in rows.iter().take(limit) {
+        let bar_len = (count * HISTOGRAM_WIDTH).div_ceil(max_count);
+        let padding = label_width - label.chars().count();
+        println!(
+            "   {}{} {} {}",
+            label,
+            " ".repeat(padding),
+            "█".repeat(bar_len),
+            count
+        );
+    }
+
+    if rows.len() > limit {
+        println!("   … and {} more", rows.len() - limit);
+    }
+}
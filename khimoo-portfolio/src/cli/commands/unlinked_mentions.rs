@@ -0,0 +1,126 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use std::path::PathBuf;
+
+use crate::cli::utils::articles::read_articles;
+use crate::config_loader::get_default_articles_dir;
+use crate::core::articles::mentions::{find_mentions, link_first_mentions, Mention, MentionTarget};
+use crate::core::articles::processor::ArticleProcessor;
+
+/// CLI arguments for the unlinked mentions command
+#[derive(Parser, Debug, Clone)]
+#[command(name = "unlinked-mentions")]
+#[command(about = "Find plain-text mentions of other articles that could be links")]
+pub struct UnlinkedMentionsArgs {
+    /// Directory containing markdown articles
+    #[arg(short, long)]
+    pub articles_dir: Option<PathBuf>,
+
+    /// Rewrite the first mention of each article into a link
+    #[arg(long)]
+    pub apply: bool,
+
+    /// With --apply, show the changes without writing them
+    #[arg(long, requires = "apply")]
+    pub dry_run: bool,
+
+    /// Enable verbose output
+    #[arg(short, long)]
+    pub verbose: bool,
+}
+
+/// Command implementation for unlinked-mention detection
+pub struct UnlinkedMentionsCommand {
+    processor: ArticleProcessor,
+}
+
+impl UnlinkedMentionsCommand {
+    pub fn new() -> Result<Self> {
+        let processor = ArticleProcessor::new()?;
+
+        Ok(Self { processor })
+    }
+
+    pub fn execute(&self, args: UnlinkedMentionsArgs) -> Result<()> {
+        let articles_dir = args
+            .articles_dir
+            .clone()
+            .unwrap_or_else(get_default_articles_dir);
+
+        if args.verbose {
+            println!("Scanning articles in: {}", articles_dir.display());
+        }
+
+        let files = read_articles(&self.processor, &articles_dir, args.verbose)?;
+        let targets: Vec<MentionTarget> = files
+            .iter()
+            .map(|file| MentionTarget::of(&file.article.slug, &file.article.metadata))
+            .collect();
+
+        println!("🔎 Unlinked Mentions");
+        println!();
+        let mut total = 0;
+        let mut changed_files = 0;
+        for file in &files {
+            let mentions = find_mentions(&file.content, &file.article.slug, &targets);
+            if mentions.is_empty() {
+                continue;
+            }
+            total += mentions.len();
+
+            println!("📄 {}", file.path.display());
+            for mention in &mentions {
+                println!(
+                    "   {}: \"{}\" → {}",
+                    mention.line, mention.text, mention.target
+                );
+            }
+
+            if args.apply {
+                let linked = link_first_mentions(&file.content, &mentions);
+                Self::print_diff(&file.content, &linked, &mentions);
+                if !args.dry_run {
+                    std::fs::write(&file.path, &linked)
+                        .with_context(|| format!("Failed to write {}", file.path.display()))?;
+                }
+                changed_files += 1;
+            }
+            println!();
+        }
+
+        println!("📊 Summary:");
+        println!("   📚 Articles scanned: {}", files.len());
+        println!("   🔗 Unlinked mentions: {}", total);
+        if args.apply {
+            if args.dry_run {
+                println!("   👀 Dry run: {} files would change", changed_files);
+            } else {
+                println!("   ✏️  Linked first mentions in {} files", changed_files);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Show the lines rewritten by --apply
+    fn print_diff(original: &str, linked: &str, mentions: &[Mention]) {
+        let mut lines: Vec<usize> = mentions.iter().map(|mention| mention.line).collect();
+        lines.dedup();
+        let before: Vec<&str> = original.lines().collect();
+        let after: Vec<&str> = linked.lines().collect();
+        for line in lines {
+            let (old, new) = (before[line - 1], after[line - 1]);
+            if old != new {
+                println!("   @@ line {} @@", line);
+                println!("   - {}", old);
+                println!("   + {}", new);
+            }
+        }
+    }
+}
+
+impl Default for UnlinkedMentionsCommand {
+    fn default() -> Self {
+        Self::new().expect("Failed to create UnlinkedMentionsCommand")
+    }
+}
@@ -1,8 +1,8 @@
 use anyhow::Result;
 use clap::Parser;
 use std::path::PathBuf;
-use walkdir::WalkDir;
 
+use crate::cli::utils::articles::read_articles;
 use crate::config_loader::get_default_articles_dir;
 use crate::core::articles::links::{LinkValidator, ProcessedArticleRef};
 use crate::core::articles::processor::ArticleProcessor;
@@ -37,7 +37,7 @@ impl ValidateLinksCommand {
         let articles_dir = args
             .articles_dir
             .clone()
-            .unwrap_or_else(|| get_default_articles_dir());
+            .unwrap_or_else(get_default_articles_dir);
 
         if args.verbose {
             println!(
@@ -47,7 +47,11 @@ impl ValidateLinksCommand {
         }
 
         // Process articles and extract links
-        let processed_articles = self.process_articles(&articles_dir, &args)?;
+        let processed_articles: Vec<ProcessedArticleRef> =
+            read_articles(&self.processor, &articles_dir, args.verbose)?
+                .into_iter()
+                .map(|file| file.article)
+                .collect();
 
         // Create validator with processed articles
         let validator = LinkValidator::new(&processed_articles);
@@ -69,11 +73,15 @@ impl ValidateLinksCommand {
             validation_results.summary.total_links
         );
 
-        if validation_results.summary.broken_links > 0 {
+        if !validation_results.errors.is_empty() {
             println!(
                 "   ❌ Broken links: {}",
                 validation_results.summary.broken_links
             );
+            println!(
+                "   📋 Invalid references: {}",
+                validation_results.summary.invalid_references
+            );
             println!();
             println!("❌ Errors:");
             for (i, error) in validation_results.errors.iter().enumerate() {
@@ -84,6 +92,9 @@ impl ValidateLinksCommand {
                     crate::core::articles::links::ValidationErrorType::InvalidRelatedArticle => {
                         "📋 Invalid Related Article"
                     }
+                    crate::core::articles::links::ValidationErrorType::InvalidRelation => {
+                        "🧭 Invalid Relation"
+                    }
                     crate::core::articles::links::ValidationErrorType::MissingMetadata => {
                         "📝 Missing Metadata"
                     }
@@ -109,6 +120,9 @@ impl ValidateLinksCommand {
                 if let Some(context) = &error.context {
                     formatted.push_str(&format!(" ({})", context));
                 }
+                if let Some(suggestion) = &error.suggestion {
+                    formatted.push_str(&format!(" - {}", suggestion));
+                }
 
                 println!("{}", formatted);
             }
@@ -125,34 +139,6 @@ impl ValidateLinksCommand {
 
         Ok(())
     }
-
-    fn process_articles(
-        &self,
-        articles_dir: &std::path::Path,
-        args: &ValidateLinksArgs,
-    ) -> Result<Vec<ProcessedArticleRef>> {
-        let mut processed_articles = Vec::new();
-
-        // Find all markdown files
-        for entry in WalkDir::new(articles_dir)
-            .follow_links(true)
-            .into_iter()
-            .filter_map(|e| e.ok())
-        {
-            let path = entry.path();
-            if path.extension().and_then(|s| s.to_str()) == Some("md") {
-                if args.verbose {
-                    println!("Processing: {}", path.display());
-                }
-
-                let content = std::fs::read_to_string(path)?;
-                let processed = self.processor.process_article(path, &content)?;
-                processed_articles.push(processed);
-            }
-        }
-
-        Ok(processed_articles)
-    }
 }
 
 impl Default for ValidateLinksCommand {
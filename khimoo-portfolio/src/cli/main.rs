@@ -2,7 +2,10 @@ use anyhow::Result;
 use clap::{Parser, Subcommand};
 
 use crate::cli::commands::{
-    ProcessArticlesArgs, ProcessArticlesCommand, ValidateLinksArgs, ValidateLinksCommand,
+    ExportGraphArgs, ExportGraphCommand, ExportSvgArgs, ExportSvgCommand, GraphArgs, GraphCommand,
+    PrerenderMetaArgs, PrerenderMetaCommand, ProcessArticlesArgs, ProcessArticlesCommand,
+    SchemaArgs, SchemaCommand, StatsArgs, StatsCommand, UnlinkedMentionsArgs,
+    UnlinkedMentionsCommand, ValidateLinksArgs, ValidateLinksCommand,
 };
 
 /// CLI for khimoo-portfolio tools
@@ -20,6 +23,20 @@ pub enum Commands {
     ProcessArticles(ProcessArticlesArgs),
     /// Validate links in markdown articles
     ValidateLinks(ValidateLinksArgs),
+    /// Write per-article HTML pages with OpenGraph meta tags
+    PrerenderMeta(PrerenderMetaArgs),
+    /// Print site-wide content statistics
+    Stats(StatsArgs),
+    /// Write JSON Schemas of the generated data files
+    Schema(SchemaArgs),
+    /// Analyse the article link graph: PageRank, centrality, communities
+    Graph(GraphArgs),
+    /// Export the article graph as DOT, GraphML, GEXF or JSON Graph
+    ExportGraph(ExportGraphArgs),
+    /// Render the precomputed graph layout as an SVG image
+    ExportSvg(ExportSvgArgs),
+    /// Find plain-text mentions of other articles, optionally linking them
+    UnlinkedMentions(UnlinkedMentionsArgs),
 }
 
 impl Cli {
@@ -33,6 +50,34 @@ impl Cli {
                 let command = ValidateLinksCommand::new()?;
                 command.execute(args)
             }
+            Commands::PrerenderMeta(args) => {
+                let command = PrerenderMetaCommand::new()?;
+                command.execute(args)
+            }
+            Commands::Stats(args) => {
+                let command = StatsCommand::new()?;
+                command.execute(args)
+            }
+            Commands::Schema(args) => {
+                let command = SchemaCommand::new();
+                command.execute(args)
+            }
+            Commands::Graph(args) => {
+                let command = GraphCommand::new();
+                command.execute(args)
+            }
+            Commands::ExportGraph(args) => {
+                let command = ExportGraphCommand::new();
+                command.execute(args)
+            }
+            Commands::ExportSvg(args) => {
+                let command = ExportSvgCommand::new();
+                command.execute(args)
+            }
+            Commands::UnlinkedMentions(args) => {
+                let command = UnlinkedMentionsCommand::new()?;
+                command.execute(args)
+            }
         }
     }
 }
@@ -0,0 +1,66 @@
+//! Which files under the articles directory are articles
+//!
+//! Every command walks the articles directory through here, so they all
+//! agree on what counts as an article.
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use walkdir::{DirEntry, WalkDir};
+
+use crate::core::articles::links::ProcessedArticleRef;
+use crate::core::articles::processor::ArticleProcessor;
+
+/// Obsidian folder of note templates, which are not articles
+pub const TEMPLATES_DIR: &str = "Templates";
+
+/// A markdown article as read from disk
+pub struct ArticleFile {
+    pub path: PathBuf,
+    /// Full file content, front matter included
+    pub content: String,
+    /// Markdown after the front matter
+    pub body: String,
+    pub article: ProcessedArticleRef,
+}
+
+/// Markdown files under the articles directory, skipping templates and
+/// hidden folders such as `.obsidian`
+pub fn article_paths(articles_dir: &Path) -> impl Iterator<Item = PathBuf> {
+    WalkDir::new(articles_dir)
+        .follow_links(true)
+        .into_iter()
+        .filter_entry(|entry| entry.depth() == 0 || !is_skipped_dir(entry))
+        .filter_map(|e| e.ok())
+        .filter(|entry| entry.path().extension().and_then(|s| s.to_str()) == Some("md"))
+        .map(DirEntry::into_path)
+}
+
+/// Read and process every article under the articles directory
+pub fn read_articles(
+    processor: &ArticleProcessor,
+    articles_dir: &Path,
+    verbose: bool,
+) -> Result<Vec<ArticleFile>> {
+    article_paths(articles_dir)
+        .map(|path| {
+            if verbose {
+                println!("Processing: {}", path.display());
+            }
+
+            let content = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read file: {}", path.display()))?;
+            let (article, body) = processor.process_article_with_body(&path, &content)?;
+            Ok(ArticleFile {
+                path,
+                content,
+                body,
+                article,
+            })
+        })
+        .collect()
+}
+
+fn is_skipped_dir(entry: &DirEntry) -> bool {
+    let name = entry.file_name().to_string_lossy();
+    entry.file_type().is_dir() && (name == TEMPLATES_DIR || name.starts_with('.'))
+}
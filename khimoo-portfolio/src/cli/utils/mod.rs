@@ -3,5 +3,7 @@
 //! This module contains utility functions and helpers for CLI operations.
 //! Following KISS principle, we keep utilities minimal and avoid over-engineering.
 
+pub mod articles;
+
 // Note: OutputFormatter intentionally not implemented to avoid over-engineering
 // CLI commands output JSON directly to stdout for simplicity
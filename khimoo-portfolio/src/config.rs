@@ -1,7 +1,12 @@
 use serde::{Deserialize, Serialize};
 
+use crate::core::graph::DEFAULT_LOCAL_DEPTH;
+use crate::core::model::{ArticleIndex, ArticleIndexEntry};
 use std::sync::OnceLock;
 
+/// Highest `importance` an article can have
+const MAX_IMPORTANCE: u8 = 5;
+
 /// Node configuration structure
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct NodeConfig {
@@ -15,6 +20,10 @@ pub struct NodeConfig {
     pub physics_radius_multiplier_default: f32,
     pub physics_radius_multiplier_high_importance: f32,
     pub high_importance_threshold: u8,
+    /// Size articles by link graph centrality when they have metrics,
+    /// instead of by their `importance`; see `for_index`
+    #[serde(default)]
+    pub size_by_centrality: bool,
 }
 
 impl NodeConfig {
@@ -34,6 +43,7 @@ impl NodeConfig {
             physics_radius_multiplier_default: 1.2,
             physics_radius_multiplier_high_importance: 1.5,
             high_importance_threshold: 5,
+            size_by_centrality: false,
         }
     }
 
@@ -98,6 +108,58 @@ impl NodeConfig {
         // Clamp to min/max bounds
         calculated_radius.clamp(self.min_node_radius, self.max_node_radius)
     }
+
+    /// Drawn radius of an article node in the home graph
+    /// Importance above or below the default grows or shrinks the node.
+    pub fn article_node_radius(&self, importance: Option<u8>) -> i32 {
+        let importance = importance.unwrap_or(self.default_importance) as i32;
+        let importance_bonus =
+            (importance - self.default_importance as i32) * self.importance_multiplier;
+        (self.default_node_radius + importance_bonus)
+            .clamp(self.min_node_radius, self.max_node_radius)
+    }
+
+    /// Drawn radius of an article node sized by centrality (0 to 1)
+    /// Spans the same radii as importance 1 to 5.
+    pub fn centrality_node_radius(&self, centrality: f32) -> i32 {
+        let importance = 1.0 + centrality.clamp(0.0, 1.0) * (MAX_IMPORTANCE - 1) as f32;
+        let importance_bonus =
+            (importance - self.default_importance as f32) * self.importance_multiplier as f32;
+        (self.default_node_radius + importance_bonus.round() as i32)
+            .clamp(self.min_node_radius, self.max_node_radius)
+    }
+
+    /// This configuration with the node sizing the index was built for
+    /// (`[graph] size_by_centrality` in project.toml)
+    pub fn for_index(&self, index: &ArticleIndex) -> Self {
+        Self {
+            size_by_centrality: index.size_by_centrality,
+            ..self.clone()
+        }
+    }
+
+    /// Drawn radius of an article node: by centrality if enabled and known,
+    /// else by importance
+    pub fn entry_node_radius(&self, article: &ArticleIndexEntry) -> i32 {
+        match &article.metrics {
+            Some(metrics) if self.size_by_centrality => {
+                self.centrality_node_radius(metrics.centrality)
+            }
+            _ => self.article_node_radius(Some(article.metadata.importance)),
+        }
+    }
+
+    /// Collider radius for a node of the given drawn radius
+    /// Important nodes keep more space around them.
+    pub fn physics_radius(&self, visual_radius: i32, importance: Option<u8>) -> f32 {
+        let multiplier = match importance {
+            Some(importance) if importance >= self.high_importance_threshold => {
+                self.physics_radius_multiplier_high_importance
+            }
+            _ => self.physics_radius_multiplier_default,
+        };
+        visual_radius as f32 * multiplier
+    }
 }
 
 impl Default for NodeConfig {
@@ -106,6 +168,31 @@ impl Default for NodeConfig {
     }
 }
 
+/// Category color palette shared by the node graph and generated images
+/// Each entry is (category, primary, secondary)
+pub const CATEGORY_PALETTE: &[(&str, &str, &str)] = &[
+    ("programming", "#4A90E2", "#357ABD"),
+    ("web", "#7ED321", "#5BA517"),
+    ("rust", "#CE422B", "#A0341F"),
+    ("design", "#BD10E0", "#9013B0"),
+    ("tutorial", "#F5A623", "#D1891C"),
+    ("default", "#9B9B9B", "#7B7B7B"),
+];
+
+/// Get (primary, secondary) colors for a category, falling back to "default"
+pub fn category_palette(category: &str) -> (&'static str, &'static str) {
+    CATEGORY_PALETTE
+        .iter()
+        .find(|(name, _, _)| *name == category)
+        .or_else(|| {
+            CATEGORY_PALETTE
+                .iter()
+                .find(|(name, _, _)| *name == "default")
+        })
+        .map(|(_, primary, secondary)| (*primary, *secondary))
+        .unwrap_or(("#9B9B9B", "#7B7B7B"))
+}
+
 /// Application configuration that handles environment-specific settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppConfig {
@@ -114,6 +201,8 @@ pub struct AppConfig {
     pub articles_path: String,
     pub assets_path: String,
     pub node_config: NodeConfig,
+    /// Hops shown around the article in the article page's local graph
+    pub local_graph_depth: usize,
 }
 
 impl AppConfig {
@@ -127,6 +216,7 @@ impl AppConfig {
             assets_path: format!("{base_path}/assets"),
             base_path,
             node_config: NodeConfig::default(),
+            local_graph_depth: DEFAULT_LOCAL_DEPTH,
         }
     }
 
@@ -1,8 +1,12 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+#[cfg(feature = "cli-tools")]
+use crate::core::articles::recommend::RecommendationConfig;
 #[cfg(feature = "cli-tools")]
 use crate::core::media::image_optimizer::ImageOptimizationConfig;
+#[cfg(feature = "cli-tools")]
+use crate::core::media::og_card::OgCardConfig;
 
 /// Load configuration from project.toml
 pub fn load_project_config() -> Result<HashMap<String, String>, Box<dyn std::error::Error>> {
@@ -87,6 +91,74 @@ pub fn get_image_optimization_config() -> ImageOptimizationConfig {
     }
 }
 
+/// Get OpenGraph card configuration from project.toml
+#[cfg(feature = "cli-tools")]
+pub fn get_og_card_config() -> OgCardConfig {
+    let mut og_config = OgCardConfig::default();
+
+    if let Ok(config) = load_full_config() {
+        if let Some(og_card) = config.get("og_card").and_then(|v| v.as_table()) {
+            if let Some(site_name) = og_card.get("site_name").and_then(|v| v.as_str()) {
+                og_config.site_name = site_name.to_string();
+            }
+            if let Some(site_url) = og_card.get("site_url").and_then(|v| v.as_str()) {
+                og_config.site_url = site_url.trim_end_matches('/').to_string();
+            }
+            if let Some(font_path) = og_card.get("font_path").and_then(|v| v.as_str()) {
+                og_config.font_path = PathBuf::from(format!("../{}", font_path));
+            }
+            if let Some(avatar_path) = og_card.get("avatar_path").and_then(|v| v.as_str()) {
+                og_config.avatar_path = PathBuf::from(format!("../{}", avatar_path));
+            }
+        }
+    }
+
+    og_config
+}
+
+/// Get related-article recommendation weights from project.toml
+#[cfg(feature = "cli-tools")]
+pub fn get_recommendation_config() -> RecommendationConfig {
+    let mut rec_config = RecommendationConfig::default();
+
+    if let Ok(config) = load_full_config() {
+        if let Some(recommendations) = config.get("recommendations").and_then(|v| v.as_table()) {
+            let weight = |key: &str| {
+                recommendations
+                    .get(key)
+                    .and_then(|v| v.as_float().or_else(|| v.as_integer().map(|i| i as f64)))
+                    .map(|w| w as f32)
+            };
+            if let Some(tag_weight) = weight("tag_weight") {
+                rec_config.tag_weight = tag_weight;
+            }
+            if let Some(citation_weight) = weight("citation_weight") {
+                rec_config.citation_weight = citation_weight;
+            }
+            if let Some(text_weight) = weight("text_weight") {
+                rec_config.text_weight = text_weight;
+            }
+            if let Some(min_score) = weight("min_score") {
+                rec_config.min_score = min_score;
+            }
+            if let Some(top_k) = recommendations.get("top_k").and_then(|v| v.as_integer()) {
+                rec_config.top_k = top_k.max(0) as usize;
+            }
+        }
+    }
+
+    rec_config
+}
+
+/// Whether `[graph] size_by_centrality` in project.toml is set (off by default)
+#[cfg(feature = "cli-tools")]
+pub fn get_size_by_centrality() -> bool {
+    load_full_config()
+        .ok()
+        .and_then(|config| config.get("graph")?.get("size_by_centrality")?.as_bool())
+        .unwrap_or(false)
+}
+
 /// Get default articles directory from configuration
 pub fn get_default_articles_dir() -> PathBuf {
     match load_project_config() {
@@ -120,30 +192,27 @@ pub fn get_images_dir() -> PathBuf {
 pub fn get_deployment_config() -> (String, String) {
     #[cfg(feature = "cli-tools")]
     {
-        match load_full_config() {
-            Ok(config) => {
-                if let Some(deployment) = config.get("deployment").and_then(|v| v.as_table()) {
-                    let github_pages_path = deployment
-                        .get("github_pages_path")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("/portfolio-page/")
-                        .trim_end_matches('/')
-                        .to_string();
-                    
-                    let local_dev_path = deployment
-                        .get("local_dev_path")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("/")
-                        .trim_end_matches('/')
-                        .to_string();
-                    
-                    return (github_pages_path, local_dev_path);
-                }
+        if let Ok(config) = load_full_config() {
+            if let Some(deployment) = config.get("deployment").and_then(|v| v.as_table()) {
+                let github_pages_path = deployment
+                    .get("github_pages_path")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("/portfolio-page/")
+                    .trim_end_matches('/')
+                    .to_string();
+
+                let local_dev_path = deployment
+                    .get("local_dev_path")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("/")
+                    .trim_end_matches('/')
+                    .to_string();
+
+                return (github_pages_path, local_dev_path);
             }
-            Err(_) => {}
         }
     }
-    
+
     // Fallback values
     ("/portfolio-page".to_string(), String::new())
 }
@@ -0,0 +1,124 @@
+//! Article dates
+//!
+//! Front matter dates are free-form strings. The processed data carries one
+//! normalised `YYYY-MM-DD` creation date per article, so the web app can order
+//! and compare articles by date without parsing.
+
+use super::metadata::ArticleMetadata;
+use crate::core::model::ProcessedArticle;
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+
+/// Format of normalised dates
+pub const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Parse a front matter date: RFC 3339, a date with an optional time, or a
+/// date with slashes
+pub fn parse_date(value: &str) -> Option<NaiveDate> {
+    let value = value.trim();
+    if let Ok(date_time) = DateTime::parse_from_rfc3339(value) {
+        return Some(date_time.date_naive());
+    }
+    for format in ["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M"] {
+        if let Ok(date_time) = NaiveDateTime::parse_from_str(value, format) {
+            return Some(date_time.date());
+        }
+    }
+    ["%Y-%m-%d", "%Y/%m/%d"]
+        .into_iter()
+        .find_map(|format| NaiveDate::parse_from_str(value, format).ok())
+}
+
+/// Normalise a front matter date to `YYYY-MM-DD`
+pub fn normalize_date(value: &str) -> Option<String> {
+    parse_date(value).map(|date| date.format(DATE_FORMAT).to_string())
+}
+
+/// Creation date of an article: `created_at`, else `updated_at`
+pub fn creation_date(metadata: &ArticleMetadata) -> Option<String> {
+    [&metadata.created_at, &metadata.updated_at]
+        .into_iter()
+        .flatten()
+        .find_map(|value| normalize_date(value))
+}
+
+/// Date every article, falling back to the earliest date of the others so
+/// undated articles stand at the start of the timeline on every build
+/// Returns the slugs of the articles that got the fallback. Nothing is dated
+/// when no article has a date.
+pub fn date_articles(articles: &mut [ProcessedArticle]) -> Vec<String> {
+    for article in articles.iter_mut() {
+        article.created_on = creation_date(&article.metadata);
+    }
+    let Some(earliest) = articles.iter().filter_map(|a| a.created_on.clone()).min() else {
+        return Vec::new();
+    };
+
+    articles
+        .iter_mut()
+        .filter(|article| article.created_on.is_none())
+        .map(|article| {
+            article.created_on = Some(earliest.clone());
+            article.slug.clone()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_date_formats() {
+        for value in [
+            "2024-03-05",
+            " 2024/03/05 ",
+            "2024-03-05 10:30:00",
+            "2024-03-05T10:30:00",
+            "2024-03-05T10:30:00+09:00",
+        ] {
+            assert_eq!(
+                normalize_date(value).as_deref(),
+                Some("2024-03-05"),
+                "{value}"
+            );
+        }
+        assert_eq!(normalize_date("March 2024"), None);
+    }
+
+    #[test]
+    fn test_creation_date_falls_back() {
+        let mut metadata = ArticleMetadata {
+            created_at: Some("someday".to_string()),
+            updated_at: Some("2023-07-08".to_string()),
+            ..ArticleMetadata::default()
+        };
+        assert_eq!(creation_date(&metadata).as_deref(), Some("2023-07-08"));
+        metadata.created_at = Some("2022-01-01T00:00:00Z".to_string());
+        assert_eq!(creation_date(&metadata).as_deref(), Some("2022-01-01"));
+        assert_eq!(creation_date(&ArticleMetadata::default()), None);
+    }
+
+    #[test]
+    fn test_undated_articles_start_the_timeline() {
+        let dated = |slug: &str, created_at: &str| {
+            let mut article = ProcessedArticle::for_test(slug);
+            article.metadata.created_at = Some(created_at.to_string());
+            article
+        };
+        let mut articles = vec![
+            dated("rust", "2024-03-05"),
+            ProcessedArticle::for_test("topology"),
+            dated("nix", "2023/07/08"),
+        ];
+        assert_eq!(date_articles(&mut articles), ["topology"]);
+        let dates: Vec<&str> = articles
+            .iter()
+            .filter_map(|a| a.created_on.as_deref())
+            .collect();
+        assert_eq!(dates, ["2024-03-05", "2023-07-08", "2023-07-08"]);
+
+        let mut undated = vec![ProcessedArticle::for_test("topology")];
+        assert!(date_articles(&mut undated).is_empty());
+        assert_eq!(undated[0].created_on, None);
+    }
+}
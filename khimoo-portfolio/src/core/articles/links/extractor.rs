@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 
 /// Types of links that can be extracted from markdown content
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "cli-tools", derive(schemars::JsonSchema))]
 pub enum LinkType {
     MarkdownLink, // [text](slug) format
     ExternalLink, // [text](http://...) format
@@ -11,6 +12,7 @@ pub enum LinkType {
 
 /// Represents a link found in markdown content
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "cli-tools", derive(schemars::JsonSchema))]
 pub struct ExtractedLink {
     pub target_slug: String,
     pub link_type: LinkType,
@@ -4,12 +4,16 @@ use std::collections::{HashMap, HashSet};
 
 use super::ExtractedLink;
 use crate::core::articles::metadata::ArticleMetadata;
+use crate::core::articles::relations::RELATION_TYPES;
+use crate::core::articles::stats::ContentStats;
 
 /// Validation error types
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ValidationErrorType {
     BrokenLink,
     InvalidRelatedArticle,
+    /// A front matter relation to a missing article, to itself or of an unknown type
+    InvalidRelation,
     MissingMetadata,
     InvalidMetadata,
     CircularReference,
@@ -53,6 +57,8 @@ pub struct ProcessedArticleRef {
     pub outbound_links: Vec<ExtractedLink>,
     pub inbound_links: Vec<ExtractedLink>,
     pub file_path: String,
+    pub summary: Option<String>,
+    pub stats: ContentStats,
 }
 
 /// Link validation system
@@ -150,6 +156,31 @@ impl LinkValidator {
             }
         }
 
+        // Validate typed relations in metadata
+        for relation in &article.metadata.relations {
+            let context = Some(format!("front matter relations ({})", relation.kind));
+            let problem = if !self.existing_articles.contains(&relation.to) {
+                Some(None)
+            } else if relation.to == article.slug {
+                Some(Some("an article cannot relate to itself".to_string()))
+            } else if !relation.is_known_kind() {
+                let known: Vec<&str> = RELATION_TYPES.iter().map(|(kind, _)| *kind).collect();
+                Some(Some(format!("use one of: {}", known.join(", "))))
+            } else {
+                None
+            };
+            if let Some(suggestion) = problem {
+                errors.push(ValidationError {
+                    error_type: ValidationErrorType::InvalidRelation,
+                    source_article: article.slug.clone(),
+                    target_reference: relation.to.clone(),
+                    context,
+                    line_number: None,
+                    suggestion,
+                });
+            }
+        }
+
         Ok(errors)
     }
 
@@ -169,7 +200,13 @@ impl LinkValidator {
 
         let invalid_references = errors
             .iter()
-            .filter(|e| matches!(e.error_type, ValidationErrorType::InvalidRelatedArticle))
+            .filter(|e| {
+                matches!(
+                    e.error_type,
+                    ValidationErrorType::InvalidRelatedArticle
+                        | ValidationErrorType::InvalidRelation
+                )
+            })
             .count();
 
         ValidationSummary {
@@ -195,6 +232,7 @@ impl LinkValidator {
 mod tests {
     use super::*;
     use crate::core::articles::metadata::ArticleMetadata;
+    use crate::core::articles::relations::Relation;
 
     fn create_test_article(slug: &str, title: &str) -> ProcessedArticleRef {
         ProcessedArticleRef {
@@ -204,6 +242,8 @@ mod tests {
             outbound_links: Vec::new(),
             inbound_links: Vec::new(),
             file_path: format!("{slug}.md"),
+            summary: None,
+            stats: ContentStats::default(),
         }
     }
 
@@ -245,4 +285,31 @@ mod tests {
         assert_eq!(errors.len(), 1);
         assert_eq!(errors[0].target_reference, "missing-article");
     }
+
+    #[test]
+    fn test_validate_relations() {
+        let mut source = create_test_article("borrowing", "Borrowing");
+        source.metadata.relations = vec![
+            Relation::new("ownership", "builds-on"),
+            Relation::new("missing", "builds-on"),
+            Relation::new("borrowing", "see-also"),
+            Relation::new("ownership", "rhymes-with"),
+        ];
+        let articles = vec![source, create_test_article("ownership", "Ownership")];
+
+        let report = LinkValidator::new(&articles).validate_all().unwrap();
+        let targets: Vec<_> = report
+            .errors
+            .iter()
+            .filter(|e| e.error_type == ValidationErrorType::InvalidRelation)
+            .map(|e| e.target_reference.as_str())
+            .collect();
+        assert_eq!(targets, ["missing", "borrowing", "ownership"]);
+        assert!(report.errors[2]
+            .suggestion
+            .as_deref()
+            .unwrap()
+            .contains("builds-on"));
+        assert_eq!(report.summary.invalid_references, 3);
+    }
 }
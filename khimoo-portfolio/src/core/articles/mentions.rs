@@ -0,0 +1,214 @@
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use std::collections::HashSet;
+use std::ops::Range;
+
+use super::metadata::ArticleMetadata;
+use super::text::is_cjk;
+
+/// Names shorter than this (in characters) are too ambiguous to match
+pub const MIN_MENTION_CHARS: usize = 2;
+
+/// An article and the names it can be mentioned by
+#[derive(Debug, Clone, PartialEq)]
+pub struct MentionTarget {
+    pub slug: String,
+    /// Title and aliases, longest first
+    pub names: Vec<String>,
+}
+
+impl MentionTarget {
+    /// Target named by the article's title and `aliases`
+    pub fn of(slug: &str, metadata: &ArticleMetadata) -> Self {
+        let mut seen = HashSet::new();
+        let mut names: Vec<String> = std::iter::once(&metadata.title)
+            .chain(&metadata.aliases)
+            .map(|name| name.trim().to_string())
+            .filter(|name| name.chars().count() >= MIN_MENTION_CHARS)
+            .filter(|name| seen.insert(name.to_ascii_lowercase()))
+            .collect();
+        names.sort_by_key(|name| std::cmp::Reverse(name.len()));
+        Self {
+            slug: slug.to_string(),
+            names,
+        }
+    }
+}
+
+/// Plain-text occurrence of another article's name
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mention {
+    /// Slug of the mentioned article
+    pub target: String,
+    /// The name as written in the source
+    pub text: String,
+    /// 1-based line in the source file
+    pub line: usize,
+    /// Byte range in the source file
+    pub range: Range<usize>,
+}
+
+/// Find mentions of other articles in a markdown file (front matter included)
+/// Code, headings, links and images are skipped, as are articles the file
+/// already links to. Latin names must match whole words, ignoring ASCII case.
+pub fn find_mentions(content: &str, source_slug: &str, targets: &[MentionTarget]) -> Vec<Mention> {
+    let options = Options::ENABLE_YAML_STYLE_METADATA_BLOCKS;
+    let linked: HashSet<String> = Parser::new_ext(content, options)
+        .filter_map(|event| match event {
+            Event::Start(Tag::Link { dest_url, .. }) => Some(dest_url.to_string()),
+            _ => None,
+        })
+        .collect();
+    let mut names: Vec<(&str, &str)> = targets
+        .iter()
+        .filter(|target| target.slug != source_slug && !linked.contains(&target.slug))
+        .flat_map(|target| {
+            target
+                .names
+                .iter()
+                .map(move |name| (target.slug.as_str(), name.as_str()))
+        })
+        .collect();
+    names.sort_by_key(|(_, name)| std::cmp::Reverse(name.len()));
+
+    let mut mentions = Vec::new();
+    let mut skip_depth = 0usize;
+    for (event, range) in Parser::new_ext(content, options).into_offset_iter() {
+        match event {
+            Event::Start(
+                Tag::CodeBlock(_)
+                | Tag::Heading { .. }
+                | Tag::Link { .. }
+                | Tag::Image { .. }
+                | Tag::MetadataBlock(_),
+            ) => skip_depth += 1,
+            Event::End(
+                TagEnd::CodeBlock
+                | TagEnd::Heading(_)
+                | TagEnd::Link
+                | TagEnd::Image
+                | TagEnd::MetadataBlock(_),
+            ) => skip_depth = skip_depth.saturating_sub(1),
+            Event::Text(_) if skip_depth == 0 => {
+                find_in_span(content, range, &names, &mut mentions)
+            }
+            _ => {}
+        }
+    }
+
+    mentions.sort_by_key(|mention| mention.range.start);
+    mentions
+}
+
+/// Match names in one span of text, longest name first and without overlaps
+fn find_in_span(
+    content: &str,
+    span: Range<usize>,
+    names: &[(&str, &str)],
+    mentions: &mut Vec<Mention>,
+) {
+    let lowered = content[span.clone()].to_ascii_lowercase();
+    let mut taken: Vec<Range<usize>> = Vec::new();
+    for (slug, name) in names {
+        let needle = name.to_ascii_lowercase();
+        for (offset, _) in lowered.match_indices(&needle) {
+            let range = span.start + offset..span.start + offset + needle.len();
+            if taken
+                .iter()
+                .any(|other| range.start < other.end && other.start < range.end)
+                || !is_whole_word(content, &range)
+            {
+                continue;
+            }
+            taken.push(range.clone());
+            mentions.push(Mention {
+                target: slug.to_string(),
+                text: content[range.clone()].to_string(),
+                line: content[..range.start].matches('\n').count() + 1,
+                range,
+            });
+        }
+    }
+}
+
+/// Whether a match is not part of a longer Latin word
+/// CJK text has no spaces, so names written in it match anywhere.
+fn is_whole_word(content: &str, range: &Range<usize>) -> bool {
+    let is_word_char = |c: char| c.is_alphanumeric() && !is_cjk(c);
+    let text = &content[range.clone()];
+    let before = content[..range.start].chars().next_back();
+    let after = content[range.end..].chars().next();
+    let starts_word = text.chars().next().is_some_and(is_word_char);
+    let ends_word = text.chars().next_back().is_some_and(is_word_char);
+    let clear_before = !starts_word || !before.is_some_and(is_word_char);
+    let clear_after = !ends_word || !after.is_some_and(is_word_char);
+    clear_before && clear_after
+}
+
+/// Rewrite the first mention of each article into a link to it
+pub fn link_first_mentions(content: &str, mentions: &[Mention]) -> String {
+    let mut seen = HashSet::new();
+    let mut first: Vec<&Mention> = mentions
+        .iter()
+        .filter(|mention| seen.insert(mention.target.as_str()))
+        .collect();
+    first.sort_by_key(|mention| std::cmp::Reverse(mention.range.start));
+
+    let mut linked = content.to_string();
+    for mention in first {
+        linked.replace_range(
+            mention.range.clone(),
+            &format!("[{}]({})", mention.text, mention.target),
+        );
+    }
+    linked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn targets() -> Vec<MentionTarget> {
+        let neovim = ArticleMetadata {
+            title: "Neovim".to_string(),
+            aliases: vec!["nvim".to_string()],
+            ..Default::default()
+        };
+        let physics = ArticleMetadata {
+            title: "物理演算".to_string(),
+            ..Default::default()
+        };
+        vec![
+            MentionTarget::of("Neovim", &neovim),
+            MentionTarget::of("physics", &physics),
+        ]
+    }
+
+    #[test]
+    fn test_finds_plain_mentions_only() {
+        let content = "---\ntitle: Neovim notes\n---\n# Neovim\n\nI use neovim and NVIM daily, not neovimish.\n\n```\nneovim\n```\n\n`nvim` and 物理演算の話\n";
+        let mentions = find_mentions(content, "notes", &targets());
+        let found: Vec<(&str, &str, usize)> = mentions
+            .iter()
+            .map(|m| (m.target.as_str(), m.text.as_str(), m.line))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("Neovim", "neovim", 6),
+                ("Neovim", "NVIM", 6),
+                ("physics", "物理演算", 12),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_links_first_mention_and_skips_linked_articles() {
+        let content = "Neovim, then Neovim again. See [physics](physics) for 物理演算.\n";
+        let mentions = find_mentions(content, "notes", &targets());
+        assert!(mentions.iter().all(|m| m.target == "Neovim"));
+        assert_eq!(
+            link_first_mentions(content, &mentions),
+            "[Neovim](Neovim), then Neovim again. See [physics](physics) for 物理演算.\n"
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use super::relations::Relation;
 use anyhow::{Context, Result};
 use chrono::DateTime;
 use serde::{Deserialize, Serialize};
@@ -5,6 +6,7 @@ use yaml_front_matter::{Document, YamlFrontMatter};
 
 /// Article metadata structure with default values
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "cli-tools", derive(schemars::JsonSchema))]
 pub struct ArticleMetadata {
     pub title: String,
     #[serde(default)]
@@ -19,6 +21,18 @@ pub struct ArticleMetadata {
     pub created_at: Option<String>,
     pub updated_at: Option<String>,
     pub author_image: Option<String>,
+    /// Hand-written summary; `summary` is accepted as an alias
+    #[serde(default, alias = "summary")]
+    pub description: Option<String>,
+    /// Typed relations to other articles
+    #[serde(default)]
+    pub relations: Vec<Relation>,
+    /// Work in progress; graph exports can leave drafts out
+    #[serde(default)]
+    pub draft: bool,
+    /// Other names the article goes by, matched by unlinked-mention detection
+    #[serde(default)]
+    pub aliases: Vec<String>,
 }
 
 impl Default for ArticleMetadata {
@@ -33,6 +47,10 @@ impl Default for ArticleMetadata {
             created_at: None,
             updated_at: None,
             author_image: None,
+            description: None,
+            relations: Vec::new(),
+            draft: false,
+            aliases: Vec::new(),
         }
     }
 }
@@ -181,6 +199,16 @@ tags: ["rust", "web"]
         assert_eq!(result.0.importance, 4);
         assert_eq!(result.0.tags, vec!["rust", "web"]);
         assert_eq!(result.1.trim(), "# Content here");
+        assert_eq!(result.0.description, None);
+    }
+
+    #[test]
+    fn test_extract_frontmatter_summary_alias() {
+        let extractor = MetadataExtractor::new();
+        let content = "---\ntitle: \"Test\"\nsummary: \"Short text\"\n---\n\nBody";
+
+        let result = extractor.extract_frontmatter(content).unwrap();
+        assert_eq!(result.0.description.as_deref(), Some("Short text"));
     }
 
     #[test]
@@ -3,9 +3,16 @@
 //! This module contains core logic for article processing, metadata extraction,
 //! and content management functionality.
 
+pub mod dates;
 pub mod links;
+pub mod mentions;
 pub mod metadata;
 pub mod processor;
+pub mod recommend;
+pub mod relations;
+pub mod stats;
+pub mod summary;
+pub mod text;
 
 // Re-export main components
 pub use links::{
@@ -13,3 +20,7 @@ pub use links::{
 };
 pub use metadata::{ArticleMetadata, MetadataExtractor};
 pub use processor::{ArticleProcessor, ProcessingError};
+pub use recommend::{Recommendation, RecommendationConfig};
+pub use relations::Relation;
+pub use stats::ContentStats;
+pub use summary::SummaryExtractor;
@@ -3,12 +3,15 @@ use std::path::Path;
 
 use super::links::{LinkExtractor, ProcessedArticleRef};
 use super::metadata::MetadataExtractor;
+use super::stats::ContentStats;
+use super::summary::SummaryExtractor;
 
 /// High-level article processing functionality
 /// Provides UI-independent business logic for article processing
 pub struct ArticleProcessor {
     metadata_extractor: MetadataExtractor,
     link_extractor: LinkExtractor,
+    summary_extractor: SummaryExtractor,
 }
 
 impl ArticleProcessor {
@@ -17,11 +20,21 @@ impl ArticleProcessor {
         Ok(Self {
             metadata_extractor: MetadataExtractor::new(),
             link_extractor: LinkExtractor::new()?,
+            summary_extractor: SummaryExtractor::new(),
         })
     }
 
     /// Process a single article file and return processed article reference
     pub fn process_article(&self, file_path: &Path, content: &str) -> Result<ProcessedArticleRef> {
+        Ok(self.process_article_with_body(file_path, content)?.0)
+    }
+
+    /// Process a single article file, also returning its markdown without the front matter
+    pub fn process_article_with_body(
+        &self,
+        file_path: &Path,
+        content: &str,
+    ) -> Result<(ProcessedArticleRef, String)> {
         // Parse front matter and content
         let (metadata, markdown_content) = self.metadata_extractor.extract_frontmatter(content)?;
 
@@ -31,17 +44,31 @@ impl ArticleProcessor {
         // Extract links from content
         let outbound_links = self.link_extractor.extract_links(&markdown_content);
 
+        // Prefer the front-matter description, falling back to the article's prose
+        let summary = match metadata.description.as_deref().map(str::trim) {
+            Some(description) if !description.is_empty() => {
+                Some(self.summary_extractor.truncate(description))
+            }
+            _ => self.summary_extractor.extract(&markdown_content),
+        };
+
+        // Length and structure statistics
+        let stats = ContentStats::from_markdown(&markdown_content);
+
         // Generate slug from file path
         let slug = self.generate_slug_from_path(file_path);
 
-        Ok(ProcessedArticleRef {
+        let article = ProcessedArticleRef {
             slug,
             title: metadata.title.clone(),
             metadata,
             outbound_links,
             inbound_links: Vec::new(), // Will be populated later during validation
             file_path: file_path.to_string_lossy().to_string(),
-        })
+            summary,
+            stats,
+        };
+        Ok((article, markdown_content))
     }
 
     /// Process multiple articles from a directory
@@ -137,6 +164,11 @@ This is a test article with [markdown link](other-article).
         assert_eq!(result.title, "Test Article");
         assert_eq!(result.metadata.importance, 4);
         assert_eq!(result.outbound_links.len(), 1);
+        assert_eq!(result.stats.links, 1);
+        assert_eq!(
+            result.summary.as_deref(),
+            Some("This is a test article with markdown link.")
+        );
     }
 
     #[test]
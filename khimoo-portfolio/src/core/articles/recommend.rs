@@ -0,0 +1,272 @@
+use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use super::text::{tokenize, Token};
+use crate::core::model::ProcessedArticle;
+
+/// Occurrences of each normalised term in an article body
+pub type TermCounts = HashMap<String, u32>;
+
+/// Article suggested for reading next, with its combined similarity score
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "cli-tools", derive(schemars::JsonSchema))]
+pub struct Recommendation {
+    pub slug: String,
+    pub title: String,
+    /// Weighted mean of the similarity signals (0 to 1)
+    pub score: f32,
+}
+
+/// Weights of the similarity signals and how many articles to keep,
+/// read from `[recommendations]` in project.toml
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecommendationConfig {
+    /// Jaccard similarity of the tag sets
+    pub tag_weight: f32,
+    /// Co-citation (linked from the same articles) and bibliographic coupling
+    /// (linking to the same articles)
+    pub citation_weight: f32,
+    /// TF-IDF cosine similarity of the body text
+    pub text_weight: f32,
+    /// Recommendations kept per article
+    pub top_k: usize,
+    /// Scores below this are not recommended
+    pub min_score: f32,
+}
+
+impl Default for RecommendationConfig {
+    fn default() -> Self {
+        Self {
+            tag_weight: 1.0,
+            citation_weight: 1.0,
+            text_weight: 1.0,
+            top_k: 5,
+            min_score: 0.05,
+        }
+    }
+}
+
+/// Count the terms of the prose in markdown content (front matter already removed)
+/// Code blocks are skipped. CJK text counts each character and each pair of
+/// adjacent characters, since single characters carry little of the topic.
+pub fn term_counts(markdown_content: &str) -> TermCounts {
+    let mut counts = TermCounts::new();
+    let mut in_code_block = false;
+
+    for event in Parser::new(markdown_content) {
+        match event {
+            Event::Start(Tag::CodeBlock(_)) => in_code_block = true,
+            Event::End(TagEnd::CodeBlock) => in_code_block = false,
+            Event::Text(text) if !in_code_block => {
+                let mut previous_cjk = None;
+                for token in tokenize(&text) {
+                    if let Token::Cjk(c) = token {
+                        if let Some(previous) = previous_cjk {
+                            *counts.entry(format!("{previous}{c}")).or_default() += 1;
+                        }
+                        previous_cjk = Some(c);
+                    } else {
+                        previous_cjk = None;
+                    }
+                    *counts.entry(token.normalized()).or_default() += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    counts
+}
+
+/// Top-k related articles of every article, keyed by slug
+/// Articles the reader can already reach from the page (its links and
+/// `related_articles`) are not recommended again.
+pub fn recommend(
+    articles: &[ProcessedArticle],
+    terms: &HashMap<String, TermCounts>,
+    config: &RecommendationConfig,
+) -> BTreeMap<String, Vec<Recommendation>> {
+    let outbound: Vec<BTreeSet<String>> = articles
+        .iter()
+        .map(|article| {
+            article
+                .internal_link_targets()
+                .into_iter()
+                .filter(|target| *target != article.slug)
+                .collect()
+        })
+        .collect();
+    let mut inbound: HashMap<&str, BTreeSet<&str>> = HashMap::new();
+    for (article, targets) in articles.iter().zip(&outbound) {
+        for target in targets {
+            inbound
+                .entry(target.as_str())
+                .or_default()
+                .insert(article.slug.as_str());
+        }
+    }
+    let vectors = tf_idf(articles, terms);
+    // All weights at zero leave every score at zero, so nothing is recommended
+    let total_weight =
+        (config.tag_weight + config.citation_weight + config.text_weight).max(f32::EPSILON);
+
+    let mut recommendations = BTreeMap::new();
+    for (i, article) in articles.iter().enumerate() {
+        let shown: HashSet<&str> = outbound[i]
+            .iter()
+            .chain(&article.metadata.related_articles)
+            .map(String::as_str)
+            .collect();
+        let empty = BTreeSet::new();
+        let cited_by = inbound.get(article.slug.as_str()).unwrap_or(&empty);
+
+        let mut scored: Vec<Recommendation> = articles
+            .iter()
+            .enumerate()
+            .filter(|(j, other)| *j != i && !shown.contains(other.slug.as_str()))
+            .filter_map(|(j, other)| {
+                let tags = jaccard(&article.metadata.tags, &other.metadata.tags);
+                let co_citation =
+                    overlap(cited_by, inbound.get(other.slug.as_str()).unwrap_or(&empty));
+                let coupling = overlap(&outbound[i], &outbound[j]);
+                let citations = (co_citation + coupling) / 2.0;
+                let text = cosine(&vectors[i], &vectors[j]);
+
+                let score = (config.tag_weight * tags
+                    + config.citation_weight * citations
+                    + config.text_weight * text)
+                    / total_weight;
+                (score >= config.min_score && score > 0.0).then(|| Recommendation {
+                    slug: other.slug.clone(),
+                    title: other.title.clone(),
+                    score,
+                })
+            })
+            .collect();
+        scored.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| a.slug.cmp(&b.slug))
+        });
+        scored.truncate(config.top_k);
+        recommendations.insert(article.slug.clone(), scored);
+    }
+
+    recommendations
+}
+
+/// Jaccard similarity of two tag lists (0 when both are empty)
+fn jaccard(a: &[String], b: &[String]) -> f32 {
+    let a: HashSet<&String> = a.iter().collect();
+    let b: HashSet<&String> = b.iter().collect();
+    let union = a.union(&b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(&b).count() as f32 / union as f32
+}
+
+/// Shared members over the geometric mean of the set sizes (cosine of the sets)
+fn overlap<T: Ord>(a: &BTreeSet<T>, b: &BTreeSet<T>) -> f32 {
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    a.intersection(b).count() as f32 / ((a.len() * b.len()) as f32).sqrt()
+}
+
+/// Unit-length TF-IDF vector of every article, in article order
+/// Terms used by every article weigh nothing. Terms are kept sorted so the
+/// scores, summed over them, come out the same on every build.
+fn tf_idf(
+    articles: &[ProcessedArticle],
+    terms: &HashMap<String, TermCounts>,
+) -> Vec<BTreeMap<String, f32>> {
+    let empty = TermCounts::new();
+    let counts: Vec<&TermCounts> = articles
+        .iter()
+        .map(|article| terms.get(&article.slug).unwrap_or(&empty))
+        .collect();
+    let mut document_frequency: HashMap<&str, usize> = HashMap::new();
+    for count in &counts {
+        for term in count.keys() {
+            *document_frequency.entry(term.as_str()).or_default() += 1;
+        }
+    }
+
+    let documents = articles.len() as f32;
+    counts
+        .iter()
+        .map(|count| {
+            let mut vector: BTreeMap<String, f32> = count
+                .iter()
+                .map(|(term, &n)| {
+                    let idf = (documents / document_frequency[term.as_str()] as f32).ln();
+                    (term.clone(), (1.0 + (n as f32).ln()) * idf)
+                })
+                .filter(|(_, weight)| *weight > 0.0)
+                .collect();
+            let norm = vector.values().map(|w| w * w).sum::<f32>().sqrt();
+            if norm > 0.0 {
+                vector.values_mut().for_each(|w| *w /= norm);
+            }
+            vector
+        })
+        .collect()
+}
+
+/// Dot product of two unit vectors
+fn cosine(a: &BTreeMap<String, f32>, b: &BTreeMap<String, f32>) -> f32 {
+    let (small, large) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    small
+        .iter()
+        .filter_map(|(term, weight)| Some(weight * large.get(term)?))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn article(slug: &str, tags: &[&str], links: &[&str]) -> ProcessedArticle {
+        ProcessedArticle::for_test(slug)
+            .title(&slug.to_uppercase())
+            .tags(tags)
+            .links(links)
+    }
+
+    #[test]
+    fn test_term_counts_skip_code_and_pair_cjk() {
+        let counts = term_counts("Rust 物理演算\n\n```\nlet hidden = 1;\n```\n");
+        assert_eq!(counts.get("rust"), Some(&1));
+        assert_eq!(counts.get("物理"), Some(&1));
+        assert_eq!(counts.get("演"), Some(&1));
+        assert!(!counts.contains_key("hidden"));
+    }
+
+    #[test]
+    fn test_recommends_similar_articles_first() {
+        let articles = vec![
+            article("a", &["rust", "physics"], &["hub"]),
+            article("b", &["rust", "physics"], &["hub"]),
+            article("c", &["cooking"], &[]),
+            article("hub", &[], &[]),
+        ];
+        let terms: HashMap<String, TermCounts> = [
+            ("a", "rapier physics engine"),
+            ("b", "physics engine demo"),
+            ("c", "bread recipe"),
+            ("hub", "index"),
+        ]
+        .into_iter()
+        .map(|(slug, text)| (slug.to_string(), term_counts(text)))
+        .collect();
+
+        let recommendations = recommend(&articles, &terms, &RecommendationConfig::default());
+        let for_a = &recommendations["a"];
+        assert_eq!(for_a[0].slug, "b");
+        assert_eq!(for_a[0].title, "B");
+        // Linked articles are already shown on the page, unrelated ones score nothing
+        assert!(for_a.iter().all(|r| r.slug != "hub" && r.slug != "c"));
+    }
+}
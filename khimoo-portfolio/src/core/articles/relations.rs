@@ -0,0 +1,110 @@
+//! Typed semantic relations between articles
+//!
+//! Front matter can state how an article relates to another one:
+//!
+//! ```yaml
+//! relations:
+//!   - to: ownership
+//!     type: builds-on
+//!     note: Borrowing only makes sense once ownership is clear
+//! ```
+//!
+//! Unlike plain links, relations carry a type that the graph draws as an edge
+//! label and the article page groups by.
+
+use serde::{Deserialize, Serialize};
+
+/// Relation types the site knows how to label, with their display labels
+pub const RELATION_TYPES: &[(&str, &str)] = &[
+    ("builds-on", "Builds on"),
+    ("extends", "Extends"),
+    ("example-of", "Example of"),
+    ("supports", "Supports"),
+    ("contradicts", "Contradicts"),
+    ("see-also", "See also"),
+];
+
+/// A typed relation from one article to another
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "cli-tools", derive(schemars::JsonSchema))]
+pub struct Relation {
+    /// Slug of the related article
+    pub to: String,
+    /// Relation type, one of `RELATION_TYPES`
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+impl Relation {
+    pub fn new(to: &str, kind: &str) -> Self {
+        Self {
+            to: to.to_string(),
+            kind: kind.to_string(),
+            note: None,
+        }
+    }
+
+    pub fn is_known_kind(&self) -> bool {
+        RELATION_TYPES.iter().any(|(kind, _)| *kind == self.kind)
+    }
+
+    pub fn label(&self) -> String {
+        relation_label(&self.kind)
+    }
+}
+
+/// Display label of a relation type; unknown types are shown with spaces for
+/// dashes
+pub fn relation_label(kind: &str) -> String {
+    RELATION_TYPES
+        .iter()
+        .find(|(known, _)| *known == kind)
+        .map(|(_, label)| label.to_string())
+        .unwrap_or_else(|| kind.replace('-', " "))
+}
+
+/// Relations grouped by type, in the order the types first appear
+pub fn group_relations(relations: &[Relation]) -> Vec<(&str, Vec<&Relation>)> {
+    let mut groups: Vec<(&str, Vec<&Relation>)> = Vec::new();
+    for relation in relations {
+        match groups.iter_mut().find(|(kind, _)| *kind == relation.kind) {
+            Some((_, group)) => group.push(relation),
+            None => groups.push((&relation.kind, vec![relation])),
+        }
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relations_parse_from_yaml() {
+        let yaml = "- to: ownership\n  type: builds-on\n  note: needs ownership first\n- to: nix\n  type: see-also\n";
+        let relations: Vec<Relation> = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(relations[0].to, "ownership");
+        assert_eq!(relations[0].kind, "builds-on");
+        assert_eq!(relations[0].note.as_deref(), Some("needs ownership first"));
+        assert_eq!(relations[1], Relation::new("nix", "see-also"));
+    }
+
+    #[test]
+    fn test_labels_and_grouping() {
+        assert_eq!(relation_label("example-of"), "Example of");
+        assert_eq!(relation_label("inspired-by"), "inspired by");
+
+        let relations = vec![
+            Relation::new("a", "builds-on"),
+            Relation::new("b", "contradicts"),
+            Relation::new("c", "builds-on"),
+        ];
+        let groups = group_relations(&relations);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].0, "builds-on");
+        assert_eq!(groups[0].1.len(), 2);
+        assert_eq!(groups[1].1[0].to, "b");
+    }
+}
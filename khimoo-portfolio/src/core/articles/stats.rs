@@ -0,0 +1,100 @@
+use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+use serde::{Deserialize, Serialize};
+
+use super::text::{tokenize, Token};
+
+/// Reading speed for space-separated languages (words per minute)
+pub const WORDS_PER_MINUTE: f32 = 200.0;
+/// Reading speed for CJK text (characters per minute)
+pub const CJK_CHARS_PER_MINUTE: f32 = 500.0;
+
+/// Length and structure statistics of an article body
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "cli-tools", derive(schemars::JsonSchema))]
+pub struct ContentStats {
+    /// Words of prose; every CJK character counts as one word
+    pub word_count: usize,
+    /// Estimated reading time, rounded up to whole minutes
+    pub reading_time_minutes: u32,
+    pub code_blocks: usize,
+    pub images: usize,
+    pub links: usize,
+}
+
+impl ContentStats {
+    /// Compute statistics from markdown content (front matter already removed)
+    /// Code blocks are counted but their contents do not add to the word count.
+    pub fn from_markdown(markdown_content: &str) -> Self {
+        let mut stats = Self::default();
+        let mut latin_words = 0usize;
+        let mut cjk_chars = 0usize;
+        let mut in_code_block = false;
+
+        for event in Parser::new(markdown_content) {
+            match event {
+                Event::Start(Tag::CodeBlock(_)) => {
+                    stats.code_blocks += 1;
+                    in_code_block = true;
+                }
+                Event::End(TagEnd::CodeBlock) => in_code_block = false,
+                Event::Start(Tag::Image { .. }) => stats.images += 1,
+                Event::Start(Tag::Link { .. }) => stats.links += 1,
+                Event::Text(text) if !in_code_block => {
+                    for token in tokenize(&text) {
+                        match token {
+                            Token::Word(_) => latin_words += 1,
+                            Token::Cjk(_) => cjk_chars += 1,
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        stats.word_count = latin_words + cjk_chars;
+        stats.reading_time_minutes = Self::estimate_reading_time(latin_words, cjk_chars);
+        stats
+    }
+
+    /// Estimated reading time in minutes; any non-empty article takes at least a minute
+    fn estimate_reading_time(latin_words: usize, cjk_chars: usize) -> u32 {
+        if latin_words + cjk_chars == 0 {
+            return 0;
+        }
+        let minutes =
+            latin_words as f32 / WORDS_PER_MINUTE + cjk_chars as f32 / CJK_CHARS_PER_MINUTE;
+        (minutes.ceil() as u32).max(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counts_structure() {
+        let content = "Intro [link](other) and ![img](a.png).\n\n```rust\nlet x = 1;\n```\n";
+        let stats = ContentStats::from_markdown(content);
+        assert_eq!(stats.code_blocks, 1);
+        assert_eq!(stats.images, 1);
+        assert_eq!(stats.links, 1);
+        // "Intro", "link", "and", "img"; code is not counted
+        assert_eq!(stats.word_count, 4);
+        assert_eq!(stats.reading_time_minutes, 1);
+    }
+
+    #[test]
+    fn test_cjk_word_count_and_reading_time() {
+        let content = "日本語".repeat(400);
+        let stats = ContentStats::from_markdown(&content);
+        assert_eq!(stats.word_count, 1200);
+        // 1200 characters at 500 per minute
+        assert_eq!(stats.reading_time_minutes, 3);
+    }
+
+    #[test]
+    fn test_empty_article() {
+        let stats = ContentStats::from_markdown("");
+        assert_eq!(stats, ContentStats::default());
+    }
+}
@@ -0,0 +1,127 @@
+use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Maximum summary length in grapheme clusters
+pub const SUMMARY_MAX_GRAPHEMES: usize = 120;
+
+/// Summary extractor for article list display
+/// Summaries are computed at build time so the client does not need the markdown
+pub struct SummaryExtractor {
+    max_graphemes: usize,
+}
+
+impl SummaryExtractor {
+    /// Create a new summary extractor with the default length
+    pub fn new() -> Self {
+        Self::with_max_graphemes(SUMMARY_MAX_GRAPHEMES)
+    }
+
+    /// Create a summary extractor with a custom length
+    pub fn with_max_graphemes(max_graphemes: usize) -> Self {
+        Self { max_graphemes }
+    }
+
+    /// Extract a summary from markdown content (front matter already removed)
+    /// Prose is read from the markdown AST; headings, code, images and raw HTML
+    /// are skipped. Returns None when the article has no prose.
+    pub fn extract(&self, markdown_content: &str) -> Option<String> {
+        let paragraphs = Self::extract_paragraphs(markdown_content);
+        if paragraphs.is_empty() {
+            return None;
+        }
+
+        Some(self.truncate(&paragraphs.join(" ")))
+    }
+
+    /// Truncate text to the maximum length on a grapheme boundary
+    pub fn truncate(&self, text: &str) -> String {
+        let text = text.trim();
+        let graphemes: Vec<&str> = text.graphemes(true).collect();
+        if graphemes.len() <= self.max_graphemes {
+            return text.to_string();
+        }
+
+        // Reserve one grapheme for the ellipsis
+        let kept = graphemes[..self.max_graphemes.saturating_sub(1)].concat();
+        format!("{}…", kept.trim_end())
+    }
+
+    /// Collect the plain text of each prose block in document order
+    fn extract_paragraphs(content: &str) -> Vec<String> {
+        let mut paragraphs = Vec::new();
+        let mut current = String::new();
+        // Depth of skipped elements; nested skips (e.g. an image in a heading) are counted
+        let mut skip_depth = 0usize;
+
+        for event in Parser::new(content) {
+            match event {
+                Event::Start(Tag::Heading { .. })
+                | Event::Start(Tag::CodeBlock(_))
+                | Event::Start(Tag::Image { .. })
+                | Event::Start(Tag::HtmlBlock) => skip_depth += 1,
+                Event::End(TagEnd::Heading(_))
+                | Event::End(TagEnd::CodeBlock)
+                | Event::End(TagEnd::Image)
+                | Event::End(TagEnd::HtmlBlock) => skip_depth = skip_depth.saturating_sub(1),
+                _ if skip_depth > 0 => {}
+                Event::Text(text) | Event::Code(text) => current.push_str(&text),
+                Event::SoftBreak | Event::HardBreak => current.push(' '),
+                Event::End(TagEnd::Paragraph) | Event::End(TagEnd::Item) => {
+                    Self::flush(&mut current, &mut paragraphs)
+                }
+                _ => {}
+            }
+        }
+        Self::flush(&mut current, &mut paragraphs);
+
+        paragraphs
+    }
+
+    fn flush(current: &mut String, paragraphs: &mut Vec<String>) {
+        let paragraph = current.split_whitespace().collect::<Vec<_>>().join(" ");
+        if !paragraph.is_empty() {
+            paragraphs.push(paragraph);
+        }
+        current.clear();
+    }
+}
+
+impl Default for SummaryExtractor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_summary_skips_headings_and_code() {
+        let extractor = SummaryExtractor::new();
+        let content = "# Title\n\n```rust\nfn main() {}\n```\n\nSome **bold** text\nwith `code`.\n\n## Next\n\nMore.";
+        let summary = extractor.extract(content).unwrap();
+        assert_eq!(summary, "Some bold text with code. More.");
+    }
+
+    #[test]
+    fn test_extract_summary_empty() {
+        let extractor = SummaryExtractor::new();
+        assert_eq!(
+            extractor.extract("# Only a heading\n\n![image](a.png)"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_truncate_on_grapheme_boundary() {
+        let extractor = SummaryExtractor::with_max_graphemes(5);
+        assert_eq!(extractor.truncate("日本語の文章です"), "日本語の…");
+        // Combining sequences stay intact
+        assert_eq!(
+            extractor.truncate("ge\u{301}ge\u{301}ge\u{301}"),
+            "ge\u{301}ge\u{301}…"
+        );
+        assert_eq!(extractor.truncate("short"), "short");
+    }
+}
@@ -0,0 +1,87 @@
+/// A word-like unit of text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token<'a> {
+    /// A run of non-CJK alphanumeric characters (e.g. an English word or a number)
+    Word(&'a str),
+    /// A single CJK character; CJK text has no spaces, so each character counts as a word
+    Cjk(char),
+}
+
+impl Token<'_> {
+    /// Normalised form of the token for indexing (lowercased words)
+    pub fn normalized(&self) -> String {
+        match self {
+            Token::Word(word) => word.to_lowercase(),
+            Token::Cjk(c) => c.to_string(),
+        }
+    }
+}
+
+/// Whether a character belongs to a CJK script (Han, kana, Hangul or CJK symbols)
+pub fn is_cjk(c: char) -> bool {
+    matches!(c as u32,
+        0x3040..=0x30FF     // Hiragana, Katakana
+        | 0x31F0..=0x31FF   // Katakana phonetic extensions
+        | 0x3400..=0x4DBF   // CJK Unified Ideographs Extension A
+        | 0x4E00..=0x9FFF   // CJK Unified Ideographs
+        | 0xAC00..=0xD7AF   // Hangul syllables
+        | 0xF900..=0xFAFF   // CJK Compatibility Ideographs
+        | 0xFF66..=0xFF9F   // Half-width Katakana
+        | 0x20000..=0x2FA1F // CJK Unified Ideographs Extension B and later
+    )
+}
+
+/// Split text into words, treating every CJK character as its own token
+/// Punctuation and whitespace separate tokens and are dropped.
+pub fn tokenize(text: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut word_start: Option<usize> = None;
+
+    for (i, c) in text.char_indices() {
+        if is_cjk(c) {
+            if let Some(start) = word_start.take() {
+                tokens.push(Token::Word(&text[start..i]));
+            }
+            tokens.push(Token::Cjk(c));
+        } else if c.is_alphanumeric() || (c == '\'' && word_start.is_some()) {
+            word_start.get_or_insert(i);
+        } else if let Some(start) = word_start.take() {
+            tokens.push(Token::Word(&text[start..i]));
+        }
+    }
+    if let Some(start) = word_start {
+        tokens.push(Token::Word(&text[start..]));
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize_mixed_text() {
+        let tokens = tokenize("Rustで物理 engine's demo!");
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Word("Rust"),
+                Token::Cjk('で'),
+                Token::Cjk('物'),
+                Token::Cjk('理'),
+                Token::Word("engine's"),
+                Token::Word("demo"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_cjk() {
+        assert!(is_cjk('漢'));
+        assert!(is_cjk('カ'));
+        assert!(is_cjk('한'));
+        assert!(!is_cjk('a'));
+        assert!(!is_cjk('。'));
+    }
+}
@@ -0,0 +1,463 @@
+//! Structure of the article link graph
+//!
+//! PageRank follows links in their direction; betweenness, components,
+//! communities and bridges treat a link as a connection either way. Articles
+//! are numbered in index order, and every result is deterministic for the
+//! same index.
+
+use crate::core::model::{ArticleIndex, GraphMetrics};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+/// Probability of following a link rather than jumping to a random article
+pub const PAGERANK_DAMPING: f64 = 0.85;
+
+/// PageRank stops after this many iterations even if it has not converged
+const PAGERANK_MAX_ITERATIONS: usize = 100;
+
+/// Total change below which PageRank counts as converged
+const PAGERANK_TOLERANCE: f64 = 1e-10;
+
+/// Links between the articles of an index
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkGraph {
+    pub slugs: Vec<String>,
+    /// Outbound links of each article, by position in `slugs`
+    outbound: Vec<Vec<usize>>,
+    /// Articles joined to each article by a link either way
+    adjacent: Vec<Vec<usize>>,
+}
+
+impl LinkGraph {
+    /// Links between indexed articles; links to unknown articles and to the
+    /// article itself are dropped
+    pub fn from_index(index: &ArticleIndex) -> Self {
+        let slugs: Vec<String> = index.articles.iter().map(|a| a.slug.clone()).collect();
+        let position: HashMap<&str, usize> = slugs
+            .iter()
+            .enumerate()
+            .map(|(i, slug)| (slug.as_str(), i))
+            .collect();
+
+        let mut outbound = vec![Vec::new(); slugs.len()];
+        let mut adjacent = vec![Vec::new(); slugs.len()];
+        for (from, article) in index.articles.iter().enumerate() {
+            for target in &article.outbound_links {
+                let Some(&to) = position.get(target.as_str()) else {
+                    continue;
+                };
+                if to == from || outbound[from].contains(&to) {
+                    continue;
+                }
+                outbound[from].push(to);
+                if !adjacent[from].contains(&to) {
+                    adjacent[from].push(to);
+                    adjacent[to].push(from);
+                }
+            }
+        }
+        Self {
+            slugs,
+            outbound,
+            adjacent,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.slugs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slugs.is_empty()
+    }
+
+    /// PageRank of every article; articles without links share their rank
+    /// with everyone
+    pub fn pagerank(&self, damping: f64) -> Vec<f64> {
+        let n = self.len();
+        if n == 0 {
+            return Vec::new();
+        }
+        let mut rank = vec![1.0 / n as f64; n];
+        for _ in 0..PAGERANK_MAX_ITERATIONS {
+            let dangling: f64 = (0..n)
+                .filter(|&i| self.outbound[i].is_empty())
+                .map(|i| rank[i])
+                .sum();
+            let base = (1.0 - damping + damping * dangling) / n as f64;
+            let mut next = vec![base; n];
+            for (from, targets) in self.outbound.iter().enumerate() {
+                for &to in targets {
+                    next[to] += damping * rank[from] / targets.len() as f64;
+                }
+            }
+            let change: f64 = next.iter().zip(&rank).map(|(a, b)| (a - b).abs()).sum();
+            rank = next;
+            if change < PAGERANK_TOLERANCE {
+                break;
+            }
+        }
+        rank
+    }
+
+    /// Betweenness centrality (Brandes), normalised to 0..1 by the number of
+    /// pairs of other articles
+    pub fn betweenness(&self) -> Vec<f64> {
+        let n = self.len();
+        let mut centrality = vec![0.0; n];
+        for source in 0..n {
+            let mut stack = Vec::new();
+            let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); n];
+            let mut paths = vec![0.0; n];
+            let mut distance: Vec<Option<usize>> = vec![None; n];
+            paths[source] = 1.0;
+            distance[source] = Some(0);
+            let mut queue = VecDeque::from([source]);
+            while let Some(v) = queue.pop_front() {
+                stack.push(v);
+                let next_distance = distance[v].map(|d| d + 1);
+                for &w in &self.adjacent[v] {
+                    if distance[w].is_none() {
+                        distance[w] = next_distance;
+                        queue.push_back(w);
+                    }
+                    if distance[w] == next_distance {
+                        paths[w] += paths[v];
+                        predecessors[w].push(v);
+                    }
+                }
+            }
+            let mut dependency = vec![0.0; n];
+            while let Some(w) = stack.pop() {
+                for &v in &predecessors[w] {
+                    dependency[v] += paths[v] / paths[w] * (1.0 + dependency[w]);
+                }
+                if w != source {
+                    centrality[w] += dependency[w];
+                }
+            }
+        }
+        // Every pair was counted from both ends
+        let pairs = (n.saturating_sub(1) * n.saturating_sub(2)) as f64;
+        for value in &mut centrality {
+            *value = if pairs > 0.0 { *value / pairs } else { 0.0 };
+        }
+        centrality
+    }
+
+    /// Connected components, largest first, each in index order
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        let mut seen = vec![false; self.len()];
+        let mut components = Vec::new();
+        for start in 0..self.len() {
+            if seen[start] {
+                continue;
+            }
+            seen[start] = true;
+            let mut component = vec![start];
+            let mut queue = VecDeque::from([start]);
+            while let Some(v) = queue.pop_front() {
+                for &w in &self.adjacent[v] {
+                    if !seen[w] {
+                        seen[w] = true;
+                        component.push(w);
+                        queue.push_back(w);
+                    }
+                }
+            }
+            component.sort_unstable();
+            components.push(component);
+        }
+        sort_groups(components)
+    }
+
+    /// Communities found by Louvain modularity optimisation, largest first
+    pub fn communities(&self) -> Vec<Vec<usize>> {
+        let n = self.len();
+        // Weighted undirected graph; the weight of a node to itself is twice
+        // the weight of the links inside it
+        let mut graph: Vec<BTreeMap<usize, f64>> = vec![BTreeMap::new(); n];
+        for (from, targets) in self.outbound.iter().enumerate() {
+            for &to in targets {
+                *graph[from].entry(to).or_default() += 1.0;
+                *graph[to].entry(from).or_default() += 1.0;
+            }
+        }
+
+        let mut membership: Vec<usize> = (0..n).collect();
+        loop {
+            let (community, count) = move_nodes(&graph);
+            if count == graph.len() {
+                break;
+            }
+            for member in &mut membership {
+                *member = community[*member];
+            }
+            let mut aggregated: Vec<BTreeMap<usize, f64>> = vec![BTreeMap::new(); count];
+            for (v, weights) in graph.iter().enumerate() {
+                for (&w, &weight) in weights {
+                    *aggregated[community[v]].entry(community[w]).or_default() += weight;
+                }
+            }
+            graph = aggregated;
+        }
+
+        let mut groups: Vec<Vec<usize>> = vec![Vec::new(); graph.len()];
+        for (v, community) in membership.into_iter().enumerate() {
+            groups[community].push(v);
+        }
+        sort_groups(groups)
+    }
+
+    /// Links whose removal would split their component, as (from, to) with
+    /// `from < to`, sorted
+    pub fn bridges(&self) -> Vec<(usize, usize)> {
+        let n = self.len();
+        let mut order = vec![usize::MAX; n];
+        let mut low = vec![0; n];
+        let mut bridges = Vec::new();
+        let mut counter = 0;
+        for root in 0..n {
+            if order[root] != usize::MAX {
+                continue;
+            }
+            // Iterative depth-first search: (node, parent, next neighbour)
+            let mut stack = vec![(root, usize::MAX, 0)];
+            order[root] = counter;
+            low[root] = counter;
+            counter += 1;
+            while let Some(&mut (v, parent, ref mut next)) = stack.last_mut() {
+                if let Some(&w) = self.adjacent[v].get(*next) {
+                    *next += 1;
+                    if order[w] == usize::MAX {
+                        order[w] = counter;
+                        low[w] = counter;
+                        counter += 1;
+                        stack.push((w, v, 0));
+                    } else if w != parent {
+                        low[v] = low[v].min(order[w]);
+                    }
+                } else {
+                    stack.pop();
+                    if parent != usize::MAX {
+                        low[parent] = low[parent].min(low[v]);
+                        if low[v] > order[parent] {
+                            bridges.push((parent.min(v), parent.max(v)));
+                        }
+                    }
+                }
+            }
+        }
+        bridges.sort_unstable();
+        bridges
+    }
+}
+
+/// One pass of Louvain: move nodes to the neighbouring community with the
+/// best modularity gain until nothing moves. Returns each node's community,
+/// numbered from 0 in order of first member, and the number of communities.
+fn move_nodes(graph: &[BTreeMap<usize, f64>]) -> (Vec<usize>, usize) {
+    let n = graph.len();
+    let degree: Vec<f64> = graph.iter().map(|weights| weights.values().sum()).collect();
+    let total: f64 = degree.iter().sum();
+    let mut community: Vec<usize> = (0..n).collect();
+    if total > 0.0 {
+        let mut community_degree = degree.clone();
+        let mut moved = true;
+        while moved {
+            moved = false;
+            for v in 0..n {
+                let current = community[v];
+                community_degree[current] -= degree[v];
+                let mut links: BTreeMap<usize, f64> = BTreeMap::from([(current, 0.0)]);
+                for (&w, &weight) in &graph[v] {
+                    if w != v {
+                        *links.entry(community[w]).or_default() += weight;
+                    }
+                }
+                let gain = |c: usize| links[&c] - community_degree[c] * degree[v] / total;
+                let mut best = current;
+                for &c in links.keys() {
+                    if gain(c) > gain(best) + f64::EPSILON {
+                        best = c;
+                    }
+                }
+                community_degree[best] += degree[v];
+                if best != current {
+                    community[v] = best;
+                    moved = true;
+                }
+            }
+        }
+    }
+
+    let mut renumbered: HashMap<usize, usize> = HashMap::new();
+    for c in &mut community {
+        let next = renumbered.len();
+        *c = *renumbered.entry(*c).or_insert(next);
+    }
+    (community, renumbered.len())
+}
+
+/// Drop empty groups and sort the rest largest first, then by first member
+fn sort_groups(mut groups: Vec<Vec<usize>>) -> Vec<Vec<usize>> {
+    groups.retain(|group| !group.is_empty());
+    groups.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a[0].cmp(&b[0])));
+    groups
+}
+
+/// Everything the `graph` command reports
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct GraphAnalysis {
+    /// Metrics by article slug
+    pub metrics: BTreeMap<String, GraphMetrics>,
+    /// Connected components, largest first
+    pub components: Vec<Vec<String>>,
+    /// Communities, largest first
+    pub communities: Vec<Vec<String>>,
+    /// Links whose removal would split the graph
+    pub bridges: Vec<(String, String)>,
+}
+
+impl GraphAnalysis {
+    pub fn of(index: &ArticleIndex) -> Self {
+        let graph = LinkGraph::from_index(index);
+        let pagerank = graph.pagerank(PAGERANK_DAMPING);
+        let betweenness = graph.betweenness();
+        let components = graph.components();
+        let communities = graph.communities();
+        let max_rank = pagerank.iter().cloned().fold(0.0, f64::max);
+
+        let group_of = |groups: &[Vec<usize>]| {
+            let mut of = vec![0; graph.len()];
+            for (group, members) in groups.iter().enumerate() {
+                for &member in members {
+                    of[member] = group;
+                }
+            }
+            of
+        };
+        let component_of = group_of(&components);
+        let community_of = group_of(&communities);
+
+        let slugs = |groups: Vec<Vec<usize>>| -> Vec<Vec<String>> {
+            groups
+                .into_iter()
+                .map(|group| group.into_iter().map(|i| graph.slugs[i].clone()).collect())
+                .collect()
+        };
+        Self {
+            metrics: (0..graph.len())
+                .map(|i| {
+                    let metrics = GraphMetrics {
+                        pagerank: pagerank[i] as f32,
+                        centrality: if max_rank > 0.0 {
+                            (pagerank[i] / max_rank) as f32
+                        } else {
+                            0.0
+                        },
+                        betweenness: betweenness[i] as f32,
+                        component: component_of[i],
+                        community: community_of[i],
+                    };
+                    (graph.slugs[i].clone(), metrics)
+                })
+                .collect(),
+            bridges: graph
+                .bridges()
+                .into_iter()
+                .map(|(a, b)| (graph.slugs[a].clone(), graph.slugs[b].clone()))
+                .collect(),
+            components: slugs(components),
+            communities: slugs(communities),
+        }
+    }
+
+    /// Store the metrics on the index entries
+    pub fn apply(&self, index: &mut ArticleIndex) {
+        for article in &mut index.articles {
+            article.metrics = self.metrics.get(&article.slug).cloned();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::model::ArticleIndexEntry;
+
+    fn index(links: &[(&str, &[&str])]) -> ArticleIndex {
+        let mut index = ArticleIndex::empty();
+        index.articles = links
+            .iter()
+            .map(|(slug, targets)| ArticleIndexEntry::for_test(slug).links(targets))
+            .collect();
+        index
+    }
+
+    /// Two triangles joined by the link c -> d, and a lone article
+    fn two_triangles() -> ArticleIndex {
+        index(&[
+            ("a", &["b", "c"]),
+            ("b", &["c"]),
+            ("c", &["a", "d", "missing"]),
+            ("d", &["e", "f"]),
+            ("e", &["f"]),
+            ("f", &["d"]),
+            ("lone", &["lone"]),
+        ])
+    }
+
+    #[test]
+    fn test_pagerank_sums_to_one_and_favours_linked_articles() {
+        let graph =
+            LinkGraph::from_index(&index(&[("hub", &[]), ("a", &["hub"]), ("b", &["hub"])]));
+        let rank = graph.pagerank(PAGERANK_DAMPING);
+        assert!((rank.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(rank[0] > rank[1]);
+        assert!((rank[1] - rank[2]).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_betweenness_of_a_path() {
+        let graph = LinkGraph::from_index(&index(&[("a", &["b"]), ("b", &["c"]), ("c", &[])]));
+        assert_eq!(graph.betweenness(), [0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_components_communities_and_bridges() {
+        let graph = LinkGraph::from_index(&two_triangles());
+        assert_eq!(graph.components(), [vec![0, 1, 2, 3, 4, 5], vec![6]]);
+        assert_eq!(graph.communities(), [vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+        assert_eq!(graph.bridges(), [(2, 3)]);
+    }
+
+    #[test]
+    fn test_analysis_applies_metrics_to_index() {
+        let mut index = two_triangles();
+        let analysis = GraphAnalysis::of(&index);
+        assert_eq!(analysis.bridges, [("c".to_string(), "d".to_string())]);
+        assert_eq!(analysis.communities[2], ["lone"]);
+
+        analysis.apply(&mut index);
+        let metrics = |slug: &str| {
+            index
+                .articles
+                .iter()
+                .find(|a| a.slug == slug)
+                .and_then(|a| a.metrics.clone())
+                .unwrap()
+        };
+        assert_eq!(metrics("lone").component, 1);
+        assert_eq!(metrics("a").community, metrics("b").community);
+        assert_ne!(metrics("a").community, metrics("d").community);
+        // The ends of the bridge carry every path between the triangles
+        assert!(metrics("c").betweenness > metrics("a").betweenness);
+        let top = analysis
+            .metrics
+            .values()
+            .map(|m| m.centrality)
+            .fold(0.0, f32::max);
+        assert_eq!(top, 1.0);
+    }
+}
@@ -0,0 +1,194 @@
+//! Edges between the articles shown in the graph
+//!
+//! The home graph and the expansion build their edges the same way: typed
+//! relations become labelled strong edges, links keep their direction (the
+//! author's typed as such) and expanded articles are joined to their related
+//! articles by weak edges.
+
+use crate::core::model::ArticleIndex;
+use crate::core::physics::{merge_reciprocal, ConnectionLineType, Edge, NodeId};
+use std::collections::{HashMap, HashSet};
+
+/// Labels of the labelled edges, by `(from, to)`
+pub type EdgeLabels = HashMap<(NodeId, NodeId), String>;
+
+/// An edge between two articles, by slug
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArticleEdge {
+    pub from: String,
+    pub to: String,
+    pub kind: ConnectionLineType,
+    /// Relation label drawn on the edge
+    pub label: Option<String>,
+}
+
+impl ArticleEdge {
+    fn new(from: &str, to: &str, kind: ConnectionLineType) -> Self {
+        Self {
+            from: from.to_string(),
+            to: to.to_string(),
+            kind,
+            label: None,
+        }
+    }
+
+    fn joins(&self, a: &str, b: &str) -> bool {
+        (self.from == a && self.to == b) || (self.from == b && self.to == a)
+    }
+}
+
+/// Edges between the `visible` articles
+///
+/// Relations come first, so a link in the same direction as a relation is
+/// drawn as the relation. `expanded` articles are also joined to their related
+/// articles when nothing else connects them.
+pub fn article_edges(
+    index: &ArticleIndex,
+    visible: &[String],
+    expanded: &[String],
+) -> Vec<ArticleEdge> {
+    let shown: HashSet<&str> = visible.iter().map(String::as_str).collect();
+    let articles: Vec<_> = index
+        .articles
+        .iter()
+        .filter(|article| shown.contains(article.slug.as_str()))
+        .collect();
+
+    let relations = articles.iter().flat_map(|article| {
+        article
+            .metadata
+            .relations
+            .iter()
+            .filter(|relation| shown.contains(relation.to.as_str()))
+            .map(|relation| ArticleEdge {
+                label: Some(relation.label()),
+                ..ArticleEdge::new(&article.slug, &relation.to, ConnectionLineType::Strong)
+            })
+    });
+    let links = articles.iter().flat_map(|article| {
+        let kind = if article.metadata.author_image.is_some() {
+            ConnectionLineType::AuthorToArticle
+        } else {
+            ConnectionLineType::DirectLink
+        };
+        article
+            .outbound_links
+            .iter()
+            .filter(|target| shown.contains(target.as_str()))
+            .map(move |target| ArticleEdge::new(&article.slug, target, kind))
+    });
+    let mut edges: Vec<ArticleEdge> = relations.chain(links).collect();
+
+    for article in articles
+        .iter()
+        .filter(|article| expanded.contains(&article.slug))
+    {
+        for related in &article.metadata.related_articles {
+            let joined = edges.iter().any(|edge| edge.joins(&article.slug, related));
+            if shown.contains(related.as_str()) && related != &article.slug && !joined {
+                edges.push(ArticleEdge::new(
+                    &article.slug,
+                    related,
+                    ConnectionLineType::Weak,
+                ));
+            }
+        }
+    }
+    edges
+}
+
+/// Physics edges for article edges whose ends have node ids
+///
+/// Unlabelled edges going both ways are merged into one bidirectional edge;
+/// labelled ones keep their direction and their label.
+pub fn resolve_edges(
+    edges: &[ArticleEdge],
+    node_id: impl Fn(&str) -> Option<NodeId>,
+) -> (Vec<Edge>, EdgeLabels) {
+    let mut labelled: Vec<Edge> = Vec::new();
+    let mut labels = EdgeLabels::new();
+    let mut plain = Vec::new();
+    for edge in edges {
+        let (Some(from), Some(to)) = (node_id(&edge.from), node_id(&edge.to)) else {
+            continue;
+        };
+        let resolved = Edge::new(from, to, edge.kind);
+        match &edge.label {
+            Some(label) if from != to && !labels.contains_key(&resolved.key()) => {
+                labels.insert(resolved.key(), label.clone());
+                labelled.push(resolved);
+            }
+            Some(_) => {}
+            None => plain.push(resolved),
+        }
+    }
+    let plain = plain
+        .into_iter()
+        .filter(|edge| !labels.contains_key(&edge.key()));
+    let mut resolved = merge_reciprocal(plain);
+    resolved.extend(labelled);
+    (resolved, labels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::articles::relations::Relation;
+    use crate::core::model::ArticleIndexEntry;
+
+    fn index() -> ArticleIndex {
+        let entry = |slug: &str, links: &[&str], relations: Vec<Relation>| {
+            ArticleIndexEntry::for_test(slug)
+                .links(links)
+                .relations(relations)
+        };
+        let mut index = ArticleIndex::empty();
+        index.articles = vec![
+            entry(
+                "borrowing",
+                &["ownership"],
+                vec![
+                    Relation::new("ownership", "builds-on"),
+                    Relation::new("hidden", "see-also"),
+                ],
+            ),
+            entry("ownership", &["borrowing", "lifetimes"], vec![]),
+            entry("lifetimes", &["ownership"], vec![]),
+            entry("hidden", &[], vec![]),
+        ];
+        index
+    }
+
+    #[test]
+    fn test_relations_become_labelled_edges() {
+        let visible: Vec<String> = ["borrowing", "ownership", "lifetimes"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let edges = article_edges(&index(), &visible, &[]);
+        assert_eq!(edges[0].label.as_deref(), Some("Builds on"));
+        assert_eq!(edges[0].kind, ConnectionLineType::Strong);
+        // The relation to a hidden article is left out
+        assert_eq!(edges.len(), 5);
+
+        let id = |slug: &str| {
+            visible
+                .iter()
+                .position(|s| s == slug)
+                .map(|i| NodeId(i as u32 + 1))
+        };
+        let (resolved, labels) = resolve_edges(&edges, id);
+        let link = ConnectionLineType::DirectLink;
+        assert_eq!(
+            resolved,
+            [
+                // The reverse link stays separate from the relation
+                Edge::new(NodeId(2), NodeId(1), link),
+                Edge::new(NodeId(2), NodeId(3), ConnectionLineType::Bidirectional),
+                Edge::new(NodeId(1), NodeId(2), ConnectionLineType::Strong),
+            ]
+        );
+        assert_eq!(labels[&(NodeId(1), NodeId(2))], "Builds on");
+        assert_eq!(labels.len(), 1);
+    }
+}
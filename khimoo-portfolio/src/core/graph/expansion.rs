@@ -0,0 +1,294 @@
+//! Progressive disclosure of the article graph
+//!
+//! The home graph starts with the `home_display` articles. Expanding a node
+//! reveals the articles it links to, the ones linking to it, its
+//! `related_articles` and the articles related to it by `relations`; collapsing it hides them again. The visible set is
+//! always derived from the list of expanded articles, so collapsing in any
+//! order gives the same result as never having expanded.
+
+use super::edges::{article_edges, ArticleEdge};
+use crate::core::model::{ArticleIndex, ArticleIndexEntry};
+use crate::core::physics::Position;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// How much of the graph stays visible while articles are expanded
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExpansionView {
+    /// The home graph plus everything revealed by expansions
+    #[default]
+    KeepGraph,
+    /// Only the expanded articles and their neighbours
+    FrontierOnly,
+}
+
+/// Expansion state of the graph
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expansion {
+    /// Articles visible before anything is expanded
+    base: Vec<String>,
+    /// Expanded articles in the order they were expanded
+    expanded: Vec<String>,
+    view: ExpansionView,
+}
+
+impl Expansion {
+    pub fn new(base: Vec<String>) -> Self {
+        Self {
+            base,
+            expanded: Vec::new(),
+            view: ExpansionView::default(),
+        }
+    }
+
+    pub fn view(&self) -> ExpansionView {
+        self.view
+    }
+
+    pub fn set_view(&mut self, view: ExpansionView) {
+        self.view = view;
+    }
+
+    pub fn expanded(&self) -> &[String] {
+        &self.expanded
+    }
+
+    pub fn is_expanded(&self, slug: &str) -> bool {
+        self.expanded.iter().any(|expanded| expanded == slug)
+    }
+
+    /// Expand an article; returns false if it already was
+    pub fn expand(&mut self, slug: &str) -> bool {
+        if self.is_expanded(slug) {
+            return false;
+        }
+        self.expanded.push(slug.to_string());
+        true
+    }
+
+    /// Collapse an article; returns false if it was not expanded
+    /// Articles it revealed stay visible only if something else shows them.
+    pub fn collapse(&mut self, slug: &str) -> bool {
+        let before = self.expanded.len();
+        self.expanded.retain(|expanded| expanded != slug);
+        self.expanded.len() != before
+    }
+
+    /// Expand or collapse an article; returns whether it is expanded afterwards
+    pub fn toggle(&mut self, slug: &str) -> bool {
+        if self.collapse(slug) {
+            false
+        } else {
+            self.expand(slug)
+        }
+    }
+
+    pub fn collapse_all(&mut self) {
+        self.expanded.clear();
+    }
+
+    /// Slugs of the visible articles, in index order
+    pub fn visible(&self, index: &ArticleIndex) -> Vec<String> {
+        let mut shown: HashSet<&str> = HashSet::new();
+        if self.view == ExpansionView::KeepGraph || self.expanded.is_empty() {
+            shown.extend(self.base.iter().map(String::as_str));
+        }
+        for slug in &self.expanded {
+            shown.insert(slug);
+            shown.extend(neighbours(index, slug));
+        }
+        index
+            .articles
+            .iter()
+            .filter(|article| shown.contains(article.slug.as_str()))
+            .map(|article| article.slug.clone())
+            .collect()
+    }
+
+    /// Edges between the visible articles, as in the home graph
+    /// An expanded article is also joined to its related articles by a weak
+    /// edge when nothing else connects them.
+    pub fn edges(&self, index: &ArticleIndex, visible: &[String]) -> Vec<ArticleEdge> {
+        article_edges(index, visible, &self.expanded)
+    }
+}
+
+/// Articles an expansion of `slug` reveals: outbound links, inbound links,
+/// related articles and relations both ways, without duplicates
+pub fn neighbours<'a>(index: &'a ArticleIndex, slug: &str) -> Vec<&'a str> {
+    let mut seen = HashSet::new();
+    let outbound = find(index, slug)
+        .into_iter()
+        .flat_map(|article| &article.outbound_links);
+    let inbound = index
+        .articles
+        .iter()
+        .filter(|article| article.outbound_links.iter().any(|target| target == slug))
+        .map(|article| &article.slug);
+    let related = find(index, slug)
+        .into_iter()
+        .flat_map(|article| &article.metadata.related_articles);
+    let relations = find(index, slug)
+        .into_iter()
+        .flat_map(|article| article.metadata.relations.iter().map(|r| &r.to));
+    let related_from = index
+        .articles
+        .iter()
+        .filter(|article| article.metadata.relations.iter().any(|r| r.to == slug))
+        .map(|article| &article.slug);
+
+    outbound
+        .chain(inbound)
+        .chain(related)
+        .chain(relations)
+        .chain(related_from)
+        .filter_map(|target| find(index, target))
+        .map(|article| article.slug.as_str())
+        .filter(|target| *target != slug && seen.insert(*target))
+        .collect()
+}
+
+fn find<'a>(index: &'a ArticleIndex, slug: &str) -> Option<&'a ArticleIndexEntry> {
+    index.articles.iter().find(|article| article.slug == slug)
+}
+
+/// `count` points evenly spaced on a circle around `center`, starting at the top
+pub fn ring_positions(center: Position, count: usize, distance: f32) -> Vec<Position> {
+    (0..count)
+        .map(|i| {
+            let angle =
+                -std::f32::consts::FRAC_PI_2 + i as f32 * std::f32::consts::TAU / count as f32;
+            Position {
+                x: center.x + distance * angle.cos(),
+                y: center.y + distance * angle.sin(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::articles::relations::Relation;
+    use crate::core::physics::ConnectionLineType;
+
+    fn index() -> ArticleIndex {
+        let entry = |slug: &str, links: &[&str], related: &[&str]| {
+            ArticleIndexEntry::for_test(slug)
+                .links(links)
+                .related(related)
+        };
+        let mut index = ArticleIndex::empty();
+        index.articles = vec![
+            entry("about", &["rust"], &[]),
+            entry("rust", &["ownership"], &["nix", "missing"]),
+            entry("ownership", &[], &[]),
+            entry("nix", &[], &[]),
+            entry("lifetimes", &["rust", "ownership"], &[]),
+            entry("topology", &[], &[]).relations(vec![Relation::new("nix", "example-of")]),
+        ];
+        index
+    }
+
+    fn expansion() -> Expansion {
+        Expansion::new(vec!["about".to_string(), "rust".to_string()])
+    }
+
+    #[test]
+    fn test_neighbours_cover_links_in_both_directions_and_related() {
+        let index = index();
+        assert_eq!(
+            neighbours(&index, "rust"),
+            ["ownership", "about", "lifetimes", "nix"]
+        );
+        assert_eq!(neighbours(&index, "topology"), ["nix"]);
+        assert_eq!(neighbours(&index, "nix"), ["topology"]);
+    }
+
+    #[test]
+    fn test_expand_and_collapse() {
+        let index = index();
+        let mut expansion = expansion();
+        assert_eq!(expansion.visible(&index), ["about", "rust"]);
+
+        assert!(expansion.toggle("rust"));
+        assert_eq!(
+            expansion.visible(&index),
+            ["about", "rust", "ownership", "nix", "lifetimes"]
+        );
+        assert!(expansion.expand("nix"));
+        assert!(!expansion.expand("nix"));
+
+        // Collapsing restores what was visible before the expansion
+        assert!(!expansion.toggle("rust"));
+        assert_eq!(
+            expansion.visible(&index),
+            ["about", "rust", "nix", "topology"]
+        );
+        expansion.collapse_all();
+        assert_eq!(expansion.visible(&index), ["about", "rust"]);
+    }
+
+    #[test]
+    fn test_frontier_only_hides_the_rest() {
+        let index = index();
+        let mut expansion = expansion();
+        expansion.set_view(ExpansionView::FrontierOnly);
+        // Nothing expanded yet, so the home graph is shown
+        assert_eq!(expansion.visible(&index), ["about", "rust"]);
+
+        expansion.expand("ownership");
+        assert_eq!(
+            expansion.visible(&index),
+            ["rust", "ownership", "lifetimes"]
+        );
+    }
+
+    #[test]
+    fn test_edges_follow_links_and_related_articles() {
+        let index = index();
+        let mut expansion = expansion();
+        let pairs = |edges: &[ArticleEdge]| -> Vec<(String, String, ConnectionLineType)> {
+            edges
+                .iter()
+                .map(|edge| (edge.from.clone(), edge.to.clone(), edge.kind))
+                .collect()
+        };
+        assert_eq!(
+            pairs(&expansion.edges(&index, &expansion.visible(&index))),
+            [(
+                "about".to_string(),
+                "rust".to_string(),
+                ConnectionLineType::DirectLink
+            )]
+        );
+
+        expansion.expand("rust");
+        let edges = pairs(&expansion.edges(&index, &expansion.visible(&index)));
+        let pairs: Vec<_> = edges
+            .iter()
+            .map(|(from, to, kind)| (from.as_str(), to.as_str(), *kind))
+            .collect();
+        let link = ConnectionLineType::DirectLink;
+        assert_eq!(
+            pairs,
+            [
+                ("about", "rust", link),
+                ("rust", "ownership", link),
+                ("lifetimes", "rust", link),
+                ("lifetimes", "ownership", link),
+                ("rust", "nix", ConnectionLineType::Weak),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ring_positions_surround_the_centre() {
+        let center = Position { x: 100.0, y: 50.0 };
+        let ring = ring_positions(center, 4, 10.0);
+        assert_eq!(ring.len(), 4);
+        assert!((ring[0].x - 100.0).abs() < 1e-4 && (ring[0].y - 40.0).abs() < 1e-4);
+        assert!((ring[1].x - 110.0).abs() < 1e-4 && (ring[1].y - 50.0).abs() < 1e-4);
+        assert!(ring_positions(center, 0, 10.0).is_empty());
+    }
+}
@@ -0,0 +1,505 @@
+//! Article graph export for Graphviz and Gephi
+//!
+//! The exported graph has one node per article and one edge per internal
+//! link, `related_articles` entry and typed relation, each with its
+//! attributes. The writers produce DOT, GraphML, GEXF and JSON Graph Format.
+
+use crate::core::articles::dates::normalize_date;
+use crate::core::articles::links::LinkType;
+use crate::core::model::ProcessedArticle;
+use serde_json::{json, Map, Value};
+use std::collections::HashSet;
+
+/// Edge type of internal links
+pub const LINK_EDGE: &str = "link";
+/// Edge type of `related_articles` entries
+pub const RELATED_EDGE: &str = "related";
+
+/// Which drafts to export
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "cli-tools", derive(clap::ValueEnum))]
+pub enum DraftFilter {
+    #[default]
+    Include,
+    Exclude,
+    Only,
+}
+
+/// Articles to export; empty lists keep everything
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExportFilter {
+    /// Keep articles in one of these categories
+    pub categories: Vec<String>,
+    /// Keep articles with at least one of these tags
+    pub tags: Vec<String>,
+    pub drafts: DraftFilter,
+}
+
+impl ExportFilter {
+    pub fn keeps(&self, article: &ProcessedArticle) -> bool {
+        let metadata = &article.metadata;
+        let category = self.categories.is_empty()
+            || metadata
+                .category
+                .as_ref()
+                .is_some_and(|category| self.categories.contains(category));
+        let tag = self.tags.is_empty() || metadata.tags.iter().any(|tag| self.tags.contains(tag));
+        let draft = match self.drafts {
+            DraftFilter::Include => true,
+            DraftFilter::Exclude => !metadata.draft,
+            DraftFilter::Only => metadata.draft,
+        };
+        category && tag && draft
+    }
+}
+
+/// An exported article
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportNode {
+    pub slug: String,
+    pub title: String,
+    pub category: Option<String>,
+    pub tags: Vec<String>,
+    pub importance: u8,
+    /// Normalised creation date
+    pub created_on: Option<String>,
+    /// Normalised `updated_at`
+    pub updated_on: Option<String>,
+    pub draft: bool,
+    pub inbound_count: usize,
+    pub outbound_count: usize,
+}
+
+/// An exported connection between two articles
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportEdge {
+    pub from: String,
+    pub to: String,
+    /// `LINK_EDGE`, `RELATED_EDGE` or a relation type
+    pub kind: String,
+    /// Link text or relation note
+    pub label: Option<String>,
+}
+
+/// The article graph as exported
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExportGraph {
+    pub nodes: Vec<ExportNode>,
+    pub edges: Vec<ExportEdge>,
+}
+
+impl ExportGraph {
+    /// Articles passing `filter` and the edges between them
+    /// A link appears once per target, with the text of its first occurrence.
+    pub fn build(articles: &[ProcessedArticle], filter: &ExportFilter) -> Self {
+        let kept: Vec<&ProcessedArticle> = articles.iter().filter(|a| filter.keeps(a)).collect();
+        let slugs: HashSet<&str> = kept.iter().map(|a| a.slug.as_str()).collect();
+
+        let nodes = kept
+            .iter()
+            .map(|article| ExportNode {
+                slug: article.slug.clone(),
+                title: article.title.clone(),
+                category: article.metadata.category.clone(),
+                tags: article.metadata.tags.clone(),
+                importance: article.metadata.importance,
+                created_on: article.created_on.clone(),
+                updated_on: article
+                    .metadata
+                    .updated_at
+                    .as_deref()
+                    .and_then(normalize_date),
+                draft: article.metadata.draft,
+                inbound_count: article.inbound_links.len(),
+                outbound_count: article.internal_link_targets().len(),
+            })
+            .collect();
+
+        let mut edges = Vec::new();
+        for article in &kept {
+            let mut edge = |to: &str, kind: &str, label: Option<String>| {
+                if slugs.contains(to) && to != article.slug {
+                    edges.push(ExportEdge {
+                        from: article.slug.clone(),
+                        to: to.to_string(),
+                        kind: kind.to_string(),
+                        label,
+                    });
+                }
+            };
+            let mut linked = HashSet::new();
+            for link in &article.outbound_links {
+                if link.link_type == LinkType::MarkdownLink && linked.insert(&link.target_slug) {
+                    edge(&link.target_slug, LINK_EDGE, link.display_text.clone());
+                }
+            }
+            for related in &article.metadata.related_articles {
+                edge(related, RELATED_EDGE, None);
+            }
+            for relation in &article.metadata.relations {
+                edge(&relation.to, &relation.kind, relation.note.clone());
+            }
+        }
+
+        Self { nodes, edges }
+    }
+
+    /// Graphviz DOT
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph articles {\n");
+        for node in &self.nodes {
+            let mut attributes = vec![("label", node.title.clone())];
+            attributes.extend(
+                node.attributes()
+                    .into_iter()
+                    .filter(|(name, _)| *name != "title"),
+            );
+            out.push_str(&format!(
+                "  {} [{}];\n",
+                dot_id(&node.slug),
+                dot_attributes(&attributes)
+            ));
+        }
+        for edge in &self.edges {
+            out.push_str(&format!(
+                "  {} -> {} [{}];\n",
+                dot_id(&edge.from),
+                dot_id(&edge.to),
+                dot_attributes(&edge.attributes())
+            ));
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// GraphML with typed attribute keys
+    pub fn to_graphml(&self) -> String {
+        let mut out = String::from(concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n"
+        ));
+        for (name, kind) in NODE_ATTRIBUTES {
+            out.push_str(&format!(
+                "  <key id=\"{name}\" for=\"node\" attr.name=\"{name}\" attr.type=\"{}\"/>\n",
+                kind.graphml()
+            ));
+        }
+        for name in EDGE_ATTRIBUTES {
+            out.push_str(&format!(
+                "  <key id=\"edge_{name}\" for=\"edge\" attr.name=\"{name}\" attr.type=\"string\"/>\n"
+            ));
+        }
+        out.push_str("  <graph id=\"articles\" edgedefault=\"directed\">\n");
+        for node in &self.nodes {
+            out.push_str(&format!("    <node id=\"{}\">\n", xml_escape(&node.slug)));
+            for (name, value) in node.attributes() {
+                out.push_str(&format!(
+                    "      <data key=\"{name}\">{}</data>\n",
+                    xml_escape(&value)
+                ));
+            }
+            out.push_str("    </node>\n");
+        }
+        for (i, edge) in self.edges.iter().enumerate() {
+            out.push_str(&format!(
+                "    <edge id=\"e{i}\" source=\"{}\" target=\"{}\">\n",
+                xml_escape(&edge.from),
+                xml_escape(&edge.to)
+            ));
+            for (name, value) in edge.attributes() {
+                out.push_str(&format!(
+                    "      <data key=\"edge_{name}\">{}</data>\n",
+                    xml_escape(&value)
+                ));
+            }
+            out.push_str("    </edge>\n");
+        }
+        out.push_str("  </graph>\n</graphml>\n");
+        out
+    }
+
+    /// GEXF 1.3, as read by Gephi
+    pub fn to_gexf(&self) -> String {
+        let mut out = String::from(concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<gexf xmlns=\"http://gexf.net/1.3\" version=\"1.3\">\n",
+            "  <graph defaultedgetype=\"directed\">\n",
+            "    <attributes class=\"node\">\n"
+        ));
+        for (i, (name, kind)) in NODE_ATTRIBUTES.iter().enumerate() {
+            out.push_str(&format!(
+                "      <attribute id=\"{i}\" title=\"{name}\" type=\"{}\"/>\n",
+                kind.gexf()
+            ));
+        }
+        out.push_str("    </attributes>\n    <attributes class=\"edge\">\n");
+        for (i, name) in EDGE_ATTRIBUTES.iter().enumerate() {
+            out.push_str(&format!(
+                "      <attribute id=\"{i}\" title=\"{name}\" type=\"string\"/>\n"
+            ));
+        }
+        out.push_str("    </attributes>\n    <nodes>\n");
+        for node in &self.nodes {
+            out.push_str(&format!(
+                "      <node id=\"{}\" label=\"{}\">\n        <attvalues>\n",
+                xml_escape(&node.slug),
+                xml_escape(&node.title)
+            ));
+            for (name, value) in node.attributes() {
+                out.push_str(&format!(
+                    "          <attvalue for=\"{}\" value=\"{}\"/>\n",
+                    attribute_index(NODE_ATTRIBUTES.iter().map(|(n, _)| *n), name),
+                    xml_escape(&value)
+                ));
+            }
+            out.push_str("        </attvalues>\n      </node>\n");
+        }
+        out.push_str("    </nodes>\n    <edges>\n");
+        for (i, edge) in self.edges.iter().enumerate() {
+            out.push_str(&format!(
+                "      <edge id=\"{i}\" source=\"{}\" target=\"{}\" label=\"{}\">\n        <attvalues>\n",
+                xml_escape(&edge.from),
+                xml_escape(&edge.to),
+                xml_escape(&edge.kind)
+            ));
+            for (name, value) in edge.attributes() {
+                out.push_str(&format!(
+                    "          <attvalue for=\"{}\" value=\"{}\"/>\n",
+                    attribute_index(EDGE_ATTRIBUTES.iter().copied(), name),
+                    xml_escape(&value)
+                ));
+            }
+            out.push_str("        </attvalues>\n      </edge>\n");
+        }
+        out.push_str("    </edges>\n  </graph>\n</gexf>\n");
+        out
+    }
+
+    /// JSON Graph Format (version 2)
+    pub fn to_json_graph(&self) -> Value {
+        let nodes: Map<String, Value> = self
+            .nodes
+            .iter()
+            .map(|node| {
+                let value = json!({
+                    "label": node.title,
+                    "metadata": {
+                        "category": node.category,
+                        "tags": node.tags,
+                        "importance": node.importance,
+                        "created_on": node.created_on,
+                        "updated_on": node.updated_on,
+                        "draft": node.draft,
+                        "inbound_count": node.inbound_count,
+                        "outbound_count": node.outbound_count,
+                    },
+                });
+                (node.slug.clone(), value)
+            })
+            .collect();
+        let edges: Vec<Value> = self
+            .edges
+            .iter()
+            .map(|edge| {
+                json!({
+                    "source": edge.from,
+                    "target": edge.to,
+                    "relation": edge.kind,
+                    "metadata": { "label": edge.label },
+                })
+            })
+            .collect();
+        json!({
+            "graph": {
+                "id": "articles",
+                "directed": true,
+                "nodes": nodes,
+                "edges": edges,
+            }
+        })
+    }
+}
+
+/// Value type of an exported attribute
+#[derive(Debug, Clone, Copy)]
+enum AttributeKind {
+    Text,
+    Integer,
+    Boolean,
+}
+
+impl AttributeKind {
+    fn graphml(self) -> &'static str {
+        match self {
+            AttributeKind::Text => "string",
+            AttributeKind::Integer => "int",
+            AttributeKind::Boolean => "boolean",
+        }
+    }
+
+    fn gexf(self) -> &'static str {
+        match self {
+            AttributeKind::Text => "string",
+            AttributeKind::Integer => "integer",
+            AttributeKind::Boolean => "boolean",
+        }
+    }
+}
+
+/// Node attributes in export order
+const NODE_ATTRIBUTES: [(&str, AttributeKind); 9] = [
+    ("title", AttributeKind::Text),
+    ("category", AttributeKind::Text),
+    ("tags", AttributeKind::Text),
+    ("importance", AttributeKind::Integer),
+    ("created_on", AttributeKind::Text),
+    ("updated_on", AttributeKind::Text),
+    ("draft", AttributeKind::Boolean),
+    ("inbound_count", AttributeKind::Integer),
+    ("outbound_count", AttributeKind::Integer),
+];
+
+/// Edge attributes in export order
+const EDGE_ATTRIBUTES: [&str; 2] = ["type", "label"];
+
+impl ExportNode {
+    /// Attribute values as text, leaving out missing ones; tags are joined
+    /// with commas
+    fn attributes(&self) -> Vec<(&'static str, String)> {
+        let optional = [
+            ("category", self.category.clone()),
+            ("tags", (!self.tags.is_empty()).then(|| self.tags.join(","))),
+            ("created_on", self.created_on.clone()),
+            ("updated_on", self.updated_on.clone()),
+        ];
+        let mut attributes = vec![("title", self.title.clone())];
+        attributes.extend(
+            optional
+                .into_iter()
+                .filter_map(|(name, value)| Some((name, value?))),
+        );
+        attributes.extend([
+            ("importance", self.importance.to_string()),
+            ("draft", self.draft.to_string()),
+            ("inbound_count", self.inbound_count.to_string()),
+            ("outbound_count", self.outbound_count.to_string()),
+        ]);
+        attributes
+    }
+}
+
+impl ExportEdge {
+    fn attributes(&self) -> Vec<(&'static str, String)> {
+        let mut attributes = vec![("type", self.kind.clone())];
+        if let Some(label) = &self.label {
+            attributes.push(("label", label.clone()));
+        }
+        attributes
+    }
+}
+
+fn attribute_index<'a>(mut names: impl Iterator<Item = &'a str>, name: &str) -> usize {
+    names.position(|n| n == name).unwrap_or_default()
+}
+
+/// Escape text for XML content and attribute values, single-quoted ones included
+pub(crate) fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Quoted DOT identifier
+fn dot_id(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn dot_attributes(attributes: &[(&str, String)]) -> String {
+    attributes
+        .iter()
+        .map(|(name, value)| format!("{}={}", name, dot_id(value)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::articles::relations::Relation;
+
+    fn article(slug: &str, category: &str, links: &[&str]) -> ProcessedArticle {
+        let mut article = ProcessedArticle::for_test(slug)
+            .title(&format!("{slug} & co"))
+            .category(category)
+            .links(links)
+            .created_on("2024-01-02");
+        for link in &mut article.outbound_links {
+            link.display_text = Some(format!("see \"{}\"", link.target_slug));
+        }
+        article
+    }
+
+    fn articles() -> Vec<ProcessedArticle> {
+        let mut rust = article("rust", "programming", &["nix", "nix", "draft"]);
+        rust.metadata.related_articles = vec!["topology".to_string()];
+        rust.metadata.relations = vec![Relation::new("nix", "see-also")];
+        let mut draft = article("draft", "programming", &[]);
+        draft.metadata.draft = true;
+        vec![
+            rust,
+            article("nix", "programming", &["rust"]),
+            article("topology", "math", &[]),
+            draft,
+        ]
+    }
+
+    #[test]
+    fn test_filter_drops_articles_and_their_edges() {
+        let all = ExportGraph::build(&articles(), &ExportFilter::default());
+        assert_eq!(all.nodes.len(), 4);
+        // Repeated links count once
+        assert_eq!(all.edges.len(), 5);
+        assert_eq!(all.nodes[0].outbound_count, 2);
+
+        let filter = ExportFilter {
+            categories: vec!["programming".to_string()],
+            drafts: DraftFilter::Exclude,
+            ..ExportFilter::default()
+        };
+        let graph = ExportGraph::build(&articles(), &filter);
+        let slugs: Vec<&str> = graph.nodes.iter().map(|n| n.slug.as_str()).collect();
+        assert_eq!(slugs, ["rust", "nix"]);
+        let kinds: Vec<&str> = graph.edges.iter().map(|e| e.kind.as_str()).collect();
+        assert_eq!(kinds, [LINK_EDGE, "see-also", LINK_EDGE]);
+
+        let drafts = ExportFilter {
+            drafts: DraftFilter::Only,
+            ..ExportFilter::default()
+        };
+        assert_eq!(ExportGraph::build(&articles(), &drafts).nodes.len(), 1);
+    }
+
+    #[test]
+    fn test_writers_escape_and_include_attributes() {
+        let graph = ExportGraph::build(&articles()[..2], &ExportFilter::default());
+
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph articles {"));
+        assert!(dot.contains(r#""rust" -> "nix" [type="link", label="see \"nix\""];"#));
+
+        let graphml = graph.to_graphml();
+        assert!(graphml.contains("<data key=\"title\">rust &amp; co</data>"));
+        assert!(graphml.contains("attr.name=\"importance\" attr.type=\"int\""));
+
+        let gexf = graph.to_gexf();
+        assert!(gexf.contains("<node id=\"nix\" label=\"nix &amp; co\">"));
+        assert!(gexf.contains("label=\"see-also\""));
+
+        let json = graph.to_json_graph();
+        assert_eq!(json["graph"]["nodes"]["rust"]["metadata"]["importance"], 3);
+        assert_eq!(json["graph"]["edges"][0]["relation"], LINK_EDGE);
+    }
+}
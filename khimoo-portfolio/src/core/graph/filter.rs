@@ -0,0 +1,267 @@
+//! Category, tag and date filters for the article graph
+//!
+//! Hidden categories and tags are kept in the URL query as comma-separated
+//! lists, and the timeline date as `until`, so a filtered view can be shared
+//! as a link.
+
+use crate::core::articles::dates::normalize_date;
+use crate::core::model::{ArticleIndex, ArticleIndexEntry};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Legend and filter key of articles without a category
+pub const UNCATEGORIZED: &str = "uncategorized";
+
+/// Which articles are hidden from the graph
+/// Author articles are always shown so the graph keeps its centre.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GraphFilter {
+    pub hidden_categories: BTreeSet<String>,
+    pub hidden_tags: BTreeSet<String>,
+    /// Show only articles created on or before this `YYYY-MM-DD` date
+    pub until: Option<String>,
+}
+
+/// URL query form of a `GraphFilter`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FilterQuery {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub hide_categories: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub hide_tags: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub until: String,
+}
+
+/// Category and tag counts of the articles in the graph
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Legend {
+    /// (category, article count), sorted by category
+    pub categories: Vec<(String, usize)>,
+    /// (tag, article count), sorted by tag
+    pub tags: Vec<(String, usize)>,
+}
+
+/// A category name, or `UNCATEGORIZED` when it is missing or blank
+/// Front matter often has `category: ""`, which must not become a nameless group.
+pub fn category_or_uncategorized(category: Option<&str>) -> &str {
+    category
+        .filter(|category| !category.trim().is_empty())
+        .unwrap_or(UNCATEGORIZED)
+}
+
+impl GraphFilter {
+    pub fn is_empty(&self) -> bool {
+        self.hidden_categories.is_empty() && self.hidden_tags.is_empty() && self.until.is_none()
+    }
+
+    /// Legend key of an article's category
+    pub fn category_key(article: &ArticleIndexEntry) -> &str {
+        category_or_uncategorized(article.metadata.category.as_deref())
+    }
+
+    /// Whether an article passes the filter
+    /// Articles without a creation date are never hidden by the date.
+    pub fn shows(&self, article: &ArticleIndexEntry) -> bool {
+        if article.metadata.author_image.is_some() {
+            return true;
+        }
+        let created = match (&self.until, &article.created_on) {
+            (Some(until), Some(created_on)) => created_on <= until,
+            _ => true,
+        };
+        created
+            && !self.hidden_categories.contains(Self::category_key(article))
+            && !article
+                .metadata
+                .tags
+                .iter()
+                .any(|tag| self.hidden_tags.contains(tag))
+    }
+
+    /// Show a hidden category or hide a shown one
+    pub fn toggle_category(&mut self, category: &str) {
+        toggle(&mut self.hidden_categories, category);
+    }
+
+    pub fn toggle_tag(&mut self, tag: &str) {
+        toggle(&mut self.hidden_tags, tag);
+    }
+
+    /// Keep the slugs of articles that pass the filter
+    pub fn apply(&self, index: &ArticleIndex, slugs: Vec<String>) -> Vec<String> {
+        slugs
+            .into_iter()
+            .filter(|slug| {
+                index
+                    .articles
+                    .iter()
+                    .find(|article| &article.slug == slug)
+                    .is_some_and(|article| self.shows(article))
+            })
+            .collect()
+    }
+}
+
+fn toggle(set: &mut BTreeSet<String>, value: &str) {
+    if !set.remove(value) {
+        set.insert(value.to_string());
+    }
+}
+
+impl From<&FilterQuery> for GraphFilter {
+    fn from(query: &FilterQuery) -> Self {
+        let split = |list: &str| {
+            list.split(',')
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+                .collect()
+        };
+        Self {
+            hidden_categories: split(&query.hide_categories),
+            hidden_tags: split(&query.hide_tags),
+            until: normalize_date(&query.until),
+        }
+    }
+}
+
+impl From<&GraphFilter> for FilterQuery {
+    fn from(filter: &GraphFilter) -> Self {
+        let join = |set: &BTreeSet<String>| set.iter().cloned().collect::<Vec<_>>().join(",");
+        Self {
+            hide_categories: join(&filter.hidden_categories),
+            hide_tags: join(&filter.hidden_tags),
+            until: filter.until.clone().unwrap_or_default(),
+        }
+    }
+}
+
+impl Legend {
+    /// Count categories and tags over the given articles
+    /// Uncategorised articles are counted under `UNCATEGORIZED`.
+    pub fn of<'a>(articles: impl IntoIterator<Item = &'a ArticleIndexEntry>) -> Self {
+        let mut categories: BTreeMap<String, usize> = BTreeMap::new();
+        let mut tags: BTreeMap<String, usize> = BTreeMap::new();
+        for article in articles {
+            *categories
+                .entry(GraphFilter::category_key(article).to_string())
+                .or_default() += 1;
+            for tag in &article.metadata.tags {
+                *tags.entry(tag.clone()).or_default() += 1;
+            }
+        }
+        Self {
+            categories: categories.into_iter().collect(),
+            tags: tags.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index() -> ArticleIndex {
+        let mut index = ArticleIndex::empty();
+        index.articles = vec![
+            ArticleIndexEntry::for_test("about")
+                .category("profile")
+                .author(),
+            ArticleIndexEntry::for_test("rust")
+                .category("programming")
+                .tags(&["rust", "lang"]),
+            ArticleIndexEntry::for_test("nix")
+                .category("programming")
+                .tags(&["nix"]),
+            ArticleIndexEntry::for_test("topology").tags(&["math"]),
+        ];
+        index
+    }
+
+    fn slugs(index: &ArticleIndex) -> Vec<String> {
+        index.articles.iter().map(|a| a.slug.clone()).collect()
+    }
+
+    #[test]
+    fn test_hidden_categories_and_tags() {
+        let index = index();
+        let mut filter = GraphFilter::default();
+        assert_eq!(filter.apply(&index, slugs(&index)).len(), 4);
+
+        filter.toggle_category("programming");
+        filter.toggle_category("profile");
+        filter.toggle_tag("math");
+        // The author stays, everything else is hidden
+        assert_eq!(filter.apply(&index, slugs(&index)), ["about"]);
+
+        filter.toggle_category("programming");
+        filter.toggle_tag("nix");
+        assert_eq!(filter.apply(&index, slugs(&index)), ["about", "rust"]);
+
+        filter.toggle_tag("math");
+        filter.toggle_category(UNCATEGORIZED);
+        assert_eq!(filter.apply(&index, slugs(&index)), ["about", "rust"]);
+    }
+
+    #[test]
+    fn test_query_round_trip() {
+        let mut filter = GraphFilter::default();
+        assert_eq!(FilterQuery::from(&filter), FilterQuery::default());
+
+        filter.toggle_category("web");
+        filter.toggle_category("design");
+        filter.toggle_tag("幾何学");
+        let query = FilterQuery::from(&filter);
+        assert_eq!(query.hide_categories, "design,web");
+        assert_eq!(GraphFilter::from(&query), filter);
+
+        let sloppy = FilterQuery {
+            hide_categories: " web,,design ".to_string(),
+            hide_tags: String::new(),
+            until: "2024/05/01".to_string(),
+        };
+        assert_eq!(
+            GraphFilter::from(&sloppy).hidden_categories,
+            filter.hidden_categories
+        );
+        assert_eq!(
+            GraphFilter::from(&sloppy).until.as_deref(),
+            Some("2024-05-01")
+        );
+    }
+
+    #[test]
+    fn test_legend_counts() {
+        let index = index();
+        let legend = Legend::of(&index.articles);
+        assert_eq!(
+            legend.categories,
+            [
+                ("profile".to_string(), 1),
+                ("programming".to_string(), 2),
+                (UNCATEGORIZED.to_string(), 1),
+            ]
+        );
+        assert_eq!(legend.tags.len(), 4);
+        assert_eq!(legend.tags[0], ("lang".to_string(), 1));
+    }
+
+    #[test]
+    fn test_blank_category_is_uncategorized() {
+        let blank = ArticleIndexEntry::for_test("blank").category("");
+        let spaces = ArticleIndexEntry::for_test("spaces").category("  ");
+        assert_eq!(GraphFilter::category_key(&blank), UNCATEGORIZED);
+        assert_eq!(
+            Legend::of([&blank, &spaces]).categories,
+            [(UNCATEGORIZED.to_string(), 2)]
+        );
+
+        // Hiding the group survives a round trip through the URL query
+        let mut filter = GraphFilter::default();
+        filter.toggle_category(GraphFilter::category_key(&blank));
+        let filter = GraphFilter::from(&FilterQuery::from(&filter));
+        assert!(!filter.shows(&blank));
+        assert!(!filter.shows(&spaces));
+    }
+}
@@ -0,0 +1,79 @@
+//! Local neighbourhood of one article
+//!
+//! The article page shows a small graph of the articles around the one being
+//! read. It uses the same neighbours as an expansion, repeated up to a depth.
+
+use super::expansion::neighbours;
+use crate::core::model::ArticleIndex;
+use std::collections::HashSet;
+
+/// Default number of hops shown around an article
+pub const DEFAULT_LOCAL_DEPTH: usize = 1;
+
+/// Slugs of the articles at most `depth` hops from `slug`, nearest first
+/// The article itself comes first; an unknown slug gives an empty list.
+pub fn neighbourhood(index: &ArticleIndex, slug: &str, depth: usize) -> Vec<String> {
+    let Some(article) = index.articles.iter().find(|article| article.slug == slug) else {
+        return Vec::new();
+    };
+    let mut seen: HashSet<&str> = HashSet::from([article.slug.as_str()]);
+    let mut found = vec![article.slug.as_str()];
+    let mut frontier = 0;
+    for _ in 0..depth {
+        let end = found.len();
+        for i in frontier..end {
+            for next in neighbours(index, found[i]) {
+                if seen.insert(next) {
+                    found.push(next);
+                }
+            }
+        }
+        if found.len() == end {
+            break;
+        }
+        frontier = end;
+    }
+    found.into_iter().map(str::to_string).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::model::ArticleIndexEntry;
+
+    fn index() -> ArticleIndex {
+        let entry = |slug: &str, links: &[&str], related: &[&str]| {
+            ArticleIndexEntry::for_test(slug)
+                .links(links)
+                .related(related)
+        };
+        let mut index = ArticleIndex::empty();
+        index.articles = vec![
+            entry("about", &["rust"], &[]),
+            entry("rust", &["ownership"], &["nix"]),
+            entry("ownership", &["lifetimes"], &[]),
+            entry("lifetimes", &[], &[]),
+            entry("nix", &[], &[]),
+            entry("topology", &[], &[]),
+        ];
+        index
+    }
+
+    #[test]
+    fn test_neighbourhood_grows_with_depth() {
+        let index = index();
+        assert_eq!(neighbourhood(&index, "rust", 0), ["rust"]);
+        // Outbound, inbound and related articles, nearest first
+        assert_eq!(
+            neighbourhood(&index, "rust", 1),
+            ["rust", "ownership", "about", "nix"]
+        );
+        assert_eq!(
+            neighbourhood(&index, "rust", 2),
+            ["rust", "ownership", "about", "nix", "lifetimes"]
+        );
+        assert_eq!(neighbourhood(&index, "rust", 5).len(), 5);
+        assert_eq!(neighbourhood(&index, "topology", 2), ["topology"]);
+        assert!(neighbourhood(&index, "missing", 1).is_empty());
+    }
+}
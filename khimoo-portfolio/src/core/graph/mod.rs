@@ -0,0 +1,22 @@
+//! Article graph module
+//!
+//! Which articles and links make up the node graph, independent of how it is
+//! drawn or simulated.
+
+pub mod analytics;
+pub mod edges;
+pub mod expansion;
+pub mod export;
+pub mod filter;
+pub mod local;
+pub mod svg;
+pub mod timeline;
+
+pub use analytics::{GraphAnalysis, LinkGraph, PAGERANK_DAMPING};
+pub use edges::{article_edges, resolve_edges, ArticleEdge, EdgeLabels};
+pub use export::{DraftFilter, ExportFilter, ExportGraph};
+pub use expansion::{neighbours, ring_positions, Expansion, ExpansionView};
+pub use filter::{category_or_uncategorized, FilterQuery, GraphFilter, Legend, UNCATEGORIZED};
+pub use local::{neighbourhood, DEFAULT_LOCAL_DEPTH};
+pub use svg::{SvgSnapshot, SvgTheme};
+pub use timeline::Timeline;
@@ -0,0 +1,364 @@
+//! Standalone SVG snapshots of the node graph
+//!
+//! The web app snapshots what is on screen and the CLI snapshots the
+//! precomputed home layout; both draw nodes and edges the way the graph does.
+
+use super::export::xml_escape;
+use crate::config::{category_palette, NodeConfig};
+use crate::core::model::ArticleIndex;
+use crate::core::physics::{ConnectionLineType, HomeGraph, Position};
+
+/// How much edges with a reverse edge are bent, as a fraction of their length
+pub const PARALLEL_EDGE_BEND: f32 = 0.15;
+
+/// Space around the outermost nodes
+const SNAPSHOT_MARGIN: f32 = 40.0;
+
+/// Fill of nodes without a category
+pub const DEFAULT_NODE_FILL: &str = "slateblue";
+
+/// Edge kinds that get their own arrowhead
+pub const ARROW_KINDS: [ConnectionLineType; 6] = [
+    ConnectionLineType::DirectLink,
+    ConnectionLineType::Bidirectional,
+    ConnectionLineType::AuthorToArticle,
+    ConnectionLineType::Strong,
+    ConnectionLineType::Medium,
+    ConnectionLineType::Weak,
+];
+
+/// Colours of a snapshot
+#[derive(Debug, Clone, Copy)]
+pub struct SvgTheme {
+    pub background: &'static str,
+    pub text: &'static str,
+    /// Edge and arrowhead colour by connection type
+    pub edge_color: fn(ConnectionLineType) -> &'static str,
+}
+
+impl Default for SvgTheme {
+    /// The web app's dark theme
+    fn default() -> Self {
+        Self {
+            background: "#081D35",
+            text: "#FFFFFF",
+            edge_color: |kind| match kind {
+                ConnectionLineType::DirectLink
+                | ConnectionLineType::Medium
+                | ConnectionLineType::Weak => "#999999",
+                ConnectionLineType::Bidirectional => "lightblue",
+                ConnectionLineType::AuthorToArticle => "#F5A623",
+                ConnectionLineType::Strong => "#E0E0E0",
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgNode {
+    pub position: Position,
+    pub radius: f32,
+    pub fill: String,
+    pub label: String,
+}
+
+/// Edge between two nodes, by position in `SvgSnapshot::nodes`
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgEdge {
+    pub from: usize,
+    pub to: usize,
+    pub kind: ConnectionLineType,
+    pub strength: f32,
+    pub label: Option<String>,
+}
+
+/// Nodes and edges to draw
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SvgSnapshot {
+    pub nodes: Vec<SvgNode>,
+    pub edges: Vec<SvgEdge>,
+}
+
+/// Stroke width and opacity of an edge; heavier and stronger edges are wider
+/// and more opaque
+pub fn edge_stroke(kind: ConnectionLineType, strength: f32) -> (f32, f32) {
+    let strength = strength.clamp(0.0, 1.0);
+    (
+        1.5 * kind.weight() * (0.5 + 0.5 * strength),
+        0.3 + 0.4 * strength,
+    )
+}
+
+/// Path of an edge and its midpoint; the ends stop at the node rims so the
+/// arrowheads stay visible. A curved edge bulges to one side of its
+/// direction, away from its reverse edge.
+pub fn edge_path(
+    from: Position,
+    to: Position,
+    from_radius: f32,
+    to_radius: f32,
+    curved: bool,
+) -> Option<(String, Position)> {
+    let (dx, dy) = (to.x - from.x, to.y - from.y);
+    let length = (dx * dx + dy * dy).sqrt();
+    if length <= from_radius + to_radius {
+        return None;
+    }
+    let bend = if curved {
+        length * PARALLEL_EDGE_BEND
+    } else {
+        0.0
+    };
+    let control = Position {
+        x: (from.x + to.x) / 2.0 - dy / length * bend,
+        y: (from.y + to.y) / 2.0 + dx / length * bend,
+    };
+    // The point `radius` along the way from an end towards the control point
+    let toward = |point: Position, radius: f32| {
+        let (dx, dy) = (control.x - point.x, control.y - point.y);
+        let distance = (dx * dx + dy * dy).sqrt().max(f32::EPSILON);
+        Position {
+            x: point.x + dx / distance * radius,
+            y: point.y + dy / distance * radius,
+        }
+    };
+    let start = toward(from, from_radius);
+    let end = toward(to, to_radius);
+    // Point at t = 0.5 on the quadratic Bézier curve
+    let middle = Position {
+        x: 0.25 * start.x + 0.5 * control.x + 0.25 * end.x,
+        y: 0.25 * start.y + 0.5 * control.y + 0.25 * end.y,
+    };
+    Some((
+        format!(
+            "M {:.2} {:.2} Q {:.2} {:.2} {:.2} {:.2}",
+            start.x, start.y, control.x, control.y, end.x, end.y
+        ),
+        middle,
+    ))
+}
+
+impl SvgSnapshot {
+    /// A settled home graph, labelled with article titles and filled with
+    /// category colours
+    pub fn from_home_graph(graph: &HomeGraph, index: &ArticleIndex) -> Self {
+        let nodes = graph
+            .nodes
+            .iter()
+            .zip(&graph.slugs)
+            .map(|(node, slug)| {
+                let article = index.articles.iter().find(|a| &a.slug == slug);
+                SvgNode {
+                    position: node.position,
+                    radius: node.radius,
+                    fill: article
+                        .and_then(|a| a.metadata.category.as_deref())
+                        .map_or(DEFAULT_NODE_FILL, |c| category_palette(c).0)
+                        .to_string(),
+                    label: article.map_or_else(|| slug.clone(), |a| a.title.clone()),
+                }
+            })
+            .collect();
+        let position = |id| graph.nodes.iter().position(|node| node.id == id);
+        let edges = graph
+            .edges
+            .iter()
+            .filter_map(|edge| {
+                Some(SvgEdge {
+                    from: position(edge.from)?,
+                    to: position(edge.to)?,
+                    kind: edge.kind,
+                    strength: edge.strength,
+                    label: graph.edge_labels.get(&edge.key()).cloned(),
+                })
+            })
+            .collect();
+        Self { nodes, edges }
+    }
+
+    /// A home graph placed at its precomputed layout; None if the index has
+    /// no layout
+    pub fn from_layout(index: &ArticleIndex, node_config: &NodeConfig) -> Option<Self> {
+        let layout = index.layout.as_ref()?;
+        let mut graph = HomeGraph::from_index(index, node_config, Position::default());
+        graph.apply_layout(layout, Position::default());
+        Some(Self::from_home_graph(&graph, index))
+    }
+
+    /// Standalone SVG document fitted around the nodes
+    pub fn to_svg(&self, theme: &SvgTheme) -> String {
+        let (min, max) = self.bounds();
+        let (width, height) = (
+            max.x - min.x + 2.0 * SNAPSHOT_MARGIN,
+            max.y - min.y + 2.0 * SNAPSHOT_MARGIN,
+        );
+        let mut out = format!(
+            concat!(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w:.0}\" height=\"{h:.0}\" ",
+                "viewBox=\"{x:.2} {y:.2} {w:.2} {h:.2}\" font-family=\"sans-serif\">\n",
+                "  <rect x=\"{x:.2}\" y=\"{y:.2}\" width=\"{w:.2}\" height=\"{h:.2}\" fill=\"{bg}\"/>\n",
+                "  <defs>\n"
+            ),
+            x = min.x - SNAPSHOT_MARGIN,
+            y = min.y - SNAPSHOT_MARGIN,
+            w = width,
+            h = height,
+            bg = theme.background,
+        );
+        for kind in ARROW_KINDS {
+            out.push_str(&format!(
+                concat!(
+                    "    <marker id=\"arrow-{:?}\" viewBox=\"0 0 10 10\" refX=\"10\" refY=\"5\" ",
+                    "markerWidth=\"5\" markerHeight=\"5\" orient=\"auto-start-reverse\">",
+                    "<path d=\"M 0 0 L 10 5 L 0 10 z\" fill=\"{}\"/></marker>\n"
+                ),
+                kind,
+                (theme.edge_color)(kind)
+            ));
+        }
+        out.push_str("  </defs>\n");
+
+        for edge in &self.edges {
+            let (Some(from), Some(to)) = (self.nodes.get(edge.from), self.nodes.get(edge.to))
+            else {
+                continue;
+            };
+            let curved = self
+                .edges
+                .iter()
+                .any(|other| other.from == edge.to && other.to == edge.from);
+            let Some((d, middle)) =
+                edge_path(from.position, to.position, from.radius, to.radius, curved)
+            else {
+                continue;
+            };
+            let (width, opacity) = edge_stroke(edge.kind, edge.strength);
+            let marker = format!("url(#arrow-{:?})", edge.kind);
+            out.push_str(&format!(
+                "  <path d=\"{d}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{width:.2}\" opacity=\"{opacity:.2}\"{}{} marker-end=\"{marker}\"/>\n",
+                (theme.edge_color)(edge.kind),
+                if edge.kind == ConnectionLineType::Weak {
+                    " stroke-dasharray=\"4 4\""
+                } else {
+                    ""
+                },
+                if edge.kind.is_directed() {
+                    String::new()
+                } else {
+                    format!(" marker-start=\"{marker}\"")
+                },
+            ));
+            if let Some(label) = &edge.label {
+                out.push_str(&format!(
+                    "  <text x=\"{:.2}\" y=\"{:.2}\" fill=\"{}\" font-size=\"11\" text-anchor=\"middle\" dominant-baseline=\"middle\" paint-order=\"stroke\" stroke=\"{}\" stroke-width=\"3\">{}</text>\n",
+                    middle.x,
+                    middle.y,
+                    theme.text,
+                    theme.background,
+                    xml_escape(label)
+                ));
+            }
+        }
+
+        for node in &self.nodes {
+            out.push_str(&format!(
+                "  <circle cx=\"{:.2}\" cy=\"{:.2}\" r=\"{:.2}\" fill=\"{}\"/>\n",
+                node.position.x,
+                node.position.y,
+                node.radius,
+                xml_escape(&node.fill)
+            ));
+            if !node.label.is_empty() {
+                out.push_str(&format!(
+                    "  <text x=\"{:.2}\" y=\"{:.2}\" fill=\"{}\" font-size=\"12\" text-anchor=\"middle\" dominant-baseline=\"middle\">{}</text>\n",
+                    node.position.x,
+                    node.position.y,
+                    theme.text,
+                    xml_escape(&node.label)
+                ));
+            }
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+
+    /// Box around the nodes, radii included
+    fn bounds(&self) -> (Position, Position) {
+        let mut min = Position {
+            x: f32::MAX,
+            y: f32::MAX,
+        };
+        let mut max = Position {
+            x: f32::MIN,
+            y: f32::MIN,
+        };
+        for node in &self.nodes {
+            min.x = min.x.min(node.position.x - node.radius);
+            min.y = min.y.min(node.position.y - node.radius);
+            max.x = max.x.max(node.position.x + node.radius);
+            max.y = max.y.max(node.position.y + node.radius);
+        }
+        if self.nodes.is_empty() {
+            (Position::default(), Position::default())
+        } else {
+            (min, max)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(x: f32, y: f32, label: &str) -> SvgNode {
+        SvgNode {
+            position: Position { x, y },
+            radius: 10.0,
+            fill: DEFAULT_NODE_FILL.to_string(),
+            label: label.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_snapshot_fits_nodes_and_draws_edges() {
+        let snapshot = SvgSnapshot {
+            nodes: vec![node(0.0, 0.0, "A & B"), node(100.0, 50.0, "C's")],
+            edges: vec![
+                SvgEdge {
+                    from: 0,
+                    to: 1,
+                    kind: ConnectionLineType::Strong,
+                    strength: 1.0,
+                    label: Some("Builds on".to_string()),
+                },
+                SvgEdge {
+                    from: 1,
+                    to: 0,
+                    kind: ConnectionLineType::DirectLink,
+                    strength: 1.0,
+                    label: None,
+                },
+            ],
+        };
+        let svg = snapshot.to_svg(&SvgTheme::default());
+        assert!(svg
+            .starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"200\" height=\"150\""));
+        assert!(svg.contains("viewBox=\"-50.00 -50.00 200.00 150.00\""));
+        assert!(svg.contains(">A &amp; B</text>"));
+        assert!(svg.contains(">C&apos;s</text>"));
+        assert!(svg.contains(">Builds on</text>"));
+        // Both directions are drawn, bent apart
+        assert_eq!(svg.matches(" Q ").count(), 2);
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+
+    #[test]
+    fn test_edge_path_stops_at_node_rims() {
+        let from = Position { x: 0.0, y: 0.0 };
+        let to = Position { x: 100.0, y: 0.0 };
+        let (d, middle) = edge_path(from, to, 10.0, 20.0, false).unwrap();
+        assert_eq!(d, "M 10.00 0.00 Q 50.00 0.00 80.00 0.00");
+        assert_eq!(middle, Position { x: 47.5, y: 0.0 });
+        assert!(edge_path(from, to, 60.0, 50.0, false).is_none());
+    }
+}
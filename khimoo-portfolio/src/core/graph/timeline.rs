@@ -0,0 +1,101 @@
+//! Growth of the graph over time
+//!
+//! The timeline steps through the distinct creation dates of the articles.
+//! At each step the graph filter's `until` date hides the articles that did
+//! not exist yet, so replaying the steps shows the graph growing.
+
+use crate::core::model::ArticleIndex;
+
+/// Distinct article creation dates, oldest first
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Timeline {
+    dates: Vec<String>,
+}
+
+impl Timeline {
+    /// Dates of the articles in `index`; the author article is always shown
+    /// and does not count
+    pub fn of(index: &ArticleIndex) -> Self {
+        let mut dates: Vec<String> = index
+            .articles
+            .iter()
+            .filter(|article| article.metadata.author_image.is_none())
+            .filter_map(|article| article.created_on.clone())
+            .collect();
+        dates.sort();
+        dates.dedup();
+        Self { dates }
+    }
+
+    pub fn dates(&self) -> &[String] {
+        &self.dates
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dates.is_empty()
+    }
+
+    /// Step showing the graph as of `date`: the last date not after it
+    pub fn step_of(&self, date: &str) -> usize {
+        self.dates
+            .partition_point(|other| other.as_str() <= date)
+            .saturating_sub(1)
+    }
+
+    /// Next date when replaying from `date`; from the start when there is no
+    /// date, None once the last date is reached
+    pub fn next(&self, date: Option<&str>) -> Option<&str> {
+        match date {
+            None => self.dates.first(),
+            Some(date) => self.dates.iter().find(|other| other.as_str() > date),
+        }
+        .map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::graph::GraphFilter;
+    use crate::core::model::ArticleIndexEntry;
+
+    fn index() -> ArticleIndex {
+        let entry =
+            |slug: &str, created_on: &str| ArticleIndexEntry::for_test(slug).created_on(created_on);
+        let mut index = ArticleIndex::empty();
+        index.articles = vec![
+            entry("about", "2025-01-01").author(),
+            entry("rust", "2023-04-01"),
+            entry("nix", "2024-02-10"),
+            entry("ownership", "2023-04-01"),
+            ArticleIndexEntry::for_test("topology"),
+        ];
+        index
+    }
+
+    #[test]
+    fn test_timeline_steps_through_distinct_dates() {
+        let timeline = Timeline::of(&index());
+        assert_eq!(timeline.dates(), ["2023-04-01", "2024-02-10"]);
+        assert_eq!(timeline.step_of("2023-12-31"), 0);
+        assert_eq!(timeline.step_of("2024-02-10"), 1);
+        assert_eq!(timeline.next(None), Some("2023-04-01"));
+        assert_eq!(timeline.next(Some("2023-04-01")), Some("2024-02-10"));
+        assert_eq!(timeline.next(Some("2024-02-10")), None);
+    }
+
+    #[test]
+    fn test_until_hides_later_articles() {
+        let index = index();
+        let slugs = index.articles.iter().map(|a| a.slug.clone()).collect();
+        let filter = GraphFilter {
+            until: Some("2023-04-01".to_string()),
+            ..GraphFilter::default()
+        };
+        // The author and undated articles stay
+        assert_eq!(
+            filter.apply(&index, slugs),
+            ["about", "rust", "ownership", "topology"]
+        );
+    }
+}
@@ -0,0 +1,81 @@
+//! Fixed sectors per category
+
+use super::{LayoutEngine, LayoutInput};
+use crate::core::graph::{category_or_uncategorized, ring_positions};
+use crate::core::physics::{NodeId, Position};
+use std::collections::{BTreeMap, HashMap};
+
+/// Angle between neighbouring nodes of a sector, in radians
+const SECTOR_STEP: f32 = 0.25;
+
+/// Each category gets an equal sector around the author node, in category
+/// order. Its articles fill the sector in arcs, inner arcs first.
+pub struct CategoryLayout;
+
+impl LayoutEngine for CategoryLayout {
+    fn arrange(&self, input: &LayoutInput) -> HashMap<NodeId, Position> {
+        let mut positions = HashMap::new();
+        let mut sectors: BTreeMap<&str, Vec<NodeId>> = BTreeMap::new();
+        for node in input.nodes {
+            if node.is_author {
+                positions.insert(node.id, input.center);
+            } else {
+                let category = category_or_uncategorized(node.category.as_deref());
+                sectors.entry(category).or_default().push(node.id);
+            }
+        }
+
+        let width = std::f32::consts::TAU / sectors.len().max(1) as f32;
+        // Sector centres, evenly spaced from the top like a ring
+        let directions = ring_positions(Position::default(), sectors.len(), 1.0);
+        for (direction, ids) in directions.into_iter().zip(sectors.into_values()) {
+            let middle = direction.y.atan2(direction.x);
+            // Nodes per arc, so neighbours in an arc stay apart
+            let per_arc = ((width / SECTOR_STEP) as usize).max(1);
+            let count = ids.len();
+            for (i, id) in ids.into_iter().enumerate() {
+                let (arc, slot) = (i / per_arc, i % per_arc);
+                let in_arc = per_arc.min(count - arc * per_arc) as f32;
+                let radius = input.spacing * (arc as f32 + 1.5);
+                // Step the angle so the arc's length, not its angle, stays even
+                let step = (SECTOR_STEP * 1.5 * input.spacing / radius).min(width / in_arc);
+                let angle = middle + (slot as f32 - (in_arc - 1.0) / 2.0) * step;
+                positions.insert(
+                    id,
+                    Position {
+                        x: input.center.x + radius * angle.cos(),
+                        y: input.center.y + radius * angle.sin(),
+                    },
+                );
+            }
+        }
+        positions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::layouts::tests::node;
+    use crate::core::layouts::DEFAULT_SPACING;
+
+    #[test]
+    fn test_categories_keep_to_their_sector() {
+        let mut nodes = vec![node(1), node(2), node(3), node(4)];
+        nodes[0].is_author = true;
+        nodes[1].category = Some("rust".to_string());
+        nodes[2].category = Some("rust".to_string());
+        let input = LayoutInput {
+            nodes: &nodes,
+            edges: &[],
+            center: Position::default(),
+            spacing: DEFAULT_SPACING,
+        };
+        let positions = CategoryLayout.arrange(&input);
+        assert_eq!(positions[&NodeId(1)], Position::default());
+        // "rust" is the first sector (top), "uncategorized" the second (bottom)
+        assert!(positions[&NodeId(2)].y < 0.0 && positions[&NodeId(3)].y < 0.0);
+        assert!(positions[&NodeId(4)].y > 0.0);
+        assert_ne!(positions[&NodeId(2)], positions[&NodeId(3)]);
+    }
+}
@@ -0,0 +1,104 @@
+//! Top-down tree from the home page articles
+
+use super::{hop_distances, levels, LayoutEngine, LayoutInput};
+use crate::core::physics::{NodeId, Position};
+use std::collections::{HashMap, HashSet};
+
+/// Levels by directed hop distance from the roots, top to bottom
+///
+/// The roots are the home page articles no other home page article links
+/// to; if links between them form a cycle, all of them. Nodes out of reach
+/// of the roots go on a last level.
+pub struct HierarchicalLayout;
+
+impl HierarchicalLayout {
+    fn roots(input: &LayoutInput) -> Vec<NodeId> {
+        let home: HashSet<NodeId> = input
+            .nodes
+            .iter()
+            .filter(|node| node.is_root)
+            .map(|node| node.id)
+            .collect();
+        let linked: HashSet<NodeId> = input
+            .edges
+            .iter()
+            .filter(|edge| home.contains(&edge.from) && edge.from != edge.to)
+            .flat_map(|edge| {
+                let back = (!edge.kind.is_directed()).then_some(edge.from);
+                [Some(edge.to), back].into_iter().flatten()
+            })
+            .collect();
+
+        let ordered = |keep: &dyn Fn(NodeId) -> bool| -> Vec<NodeId> {
+            input
+                .nodes
+                .iter()
+                .map(|node| node.id)
+                .filter(|id| keep(*id))
+                .collect()
+        };
+        let roots = ordered(&|id| home.contains(&id) && !linked.contains(&id));
+        if !roots.is_empty() {
+            return roots;
+        }
+        let roots = ordered(&|id| home.contains(&id));
+        if !roots.is_empty() {
+            return roots;
+        }
+        input
+            .nodes
+            .iter()
+            .find(|node| node.is_author)
+            .or(input.nodes.first())
+            .map(|node| vec![node.id])
+            .unwrap_or_default()
+    }
+}
+
+impl LayoutEngine for HierarchicalLayout {
+    fn arrange(&self, input: &LayoutInput) -> HashMap<NodeId, Position> {
+        let distances = hop_distances(input, &Self::roots(input), true);
+        let levels = levels(input.nodes, &distances);
+
+        let mut positions = HashMap::new();
+        let depth = levels.len().saturating_sub(1) as f32;
+        for (level, ids) in levels.into_iter().enumerate() {
+            let y = input.center.y + (level as f32 - depth / 2.0) * input.spacing;
+            let width = ids.len().saturating_sub(1) as f32;
+            for (i, id) in ids.into_iter().enumerate() {
+                let x = input.center.x + (i as f32 - width / 2.0) * input.spacing;
+                positions.insert(id, Position { x, y });
+            }
+        }
+        positions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::layouts::tests::{link, node};
+    use crate::core::layouts::DEFAULT_SPACING;
+
+    #[test]
+    fn test_tree_grows_down_from_unlinked_home_articles() {
+        let mut nodes = vec![node(1), node(2), node(3), node(4)];
+        nodes[0].is_root = true;
+        nodes[1].is_root = true;
+        // 1 links to 2, so only 1 is a root; 4 is out of reach
+        let edges = [link(1, 2), link(2, 3)];
+        let input = LayoutInput {
+            nodes: &nodes,
+            edges: &edges,
+            center: Position::default(),
+            spacing: DEFAULT_SPACING,
+        };
+        assert_eq!(HierarchicalLayout::roots(&input), [NodeId(1)]);
+
+        let positions = HierarchicalLayout.arrange(&input);
+        let y = |id| positions[&NodeId(id)].y;
+        assert!(y(1) < y(2) && y(2) < y(3) && y(3) < y(4));
+        assert!((y(4) - y(1) - 3.0 * DEFAULT_SPACING).abs() < 1e-3);
+        assert!(positions[&NodeId(1)].x.abs() < 1e-4);
+    }
+}
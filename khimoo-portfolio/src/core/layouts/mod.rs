@@ -0,0 +1,201 @@
+//! Alternative arrangements of the node graph
+//!
+//! The force-directed simulation is the default layout. The engines here
+//! compute fixed positions instead; the physics world eases the nodes
+//! towards them, which animates every switch between layouts.
+
+pub mod category;
+pub mod hierarchical;
+pub mod radial;
+pub mod timeline;
+
+pub use category::CategoryLayout;
+pub use hierarchical::HierarchicalLayout;
+pub use radial::RadialLayout;
+pub use timeline::TimelineLayout;
+
+use crate::core::physics::{Edge, NodeId, Position};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Default distance between rings, levels and lanes
+pub const DEFAULT_SPACING: f32 = 120.0;
+
+/// What a layout engine knows about a node
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LayoutNode {
+    pub id: NodeId,
+    pub is_author: bool,
+    /// Shown on the home page; the roots of the hierarchical layout
+    pub is_root: bool,
+    pub category: Option<String>,
+    /// `created_at` from the front matter
+    pub created_at: Option<String>,
+}
+
+/// The graph to arrange
+#[derive(Debug, Clone, Copy)]
+pub struct LayoutInput<'a> {
+    pub nodes: &'a [LayoutNode],
+    pub edges: &'a [Edge],
+    pub center: Position,
+    pub spacing: f32,
+}
+
+/// Computes fixed node positions
+pub trait LayoutEngine {
+    /// Target position of every node in `input`
+    fn arrange(&self, input: &LayoutInput) -> HashMap<NodeId, Position>;
+}
+
+/// Layouts the graph can switch between
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LayoutKind {
+    /// Force-directed simulation
+    #[default]
+    Physics,
+    Radial,
+    Hierarchical,
+    Timeline,
+    Category,
+}
+
+impl LayoutKind {
+    pub const ALL: [LayoutKind; 5] = [
+        LayoutKind::Physics,
+        LayoutKind::Radial,
+        LayoutKind::Hierarchical,
+        LayoutKind::Timeline,
+        LayoutKind::Category,
+    ];
+
+    /// Engine computing fixed positions, or None for the simulation
+    pub fn engine(self) -> Option<Box<dyn LayoutEngine>> {
+        match self {
+            LayoutKind::Physics => None,
+            LayoutKind::Radial => Some(Box::new(RadialLayout)),
+            LayoutKind::Hierarchical => Some(Box::new(HierarchicalLayout)),
+            LayoutKind::Timeline => Some(Box::new(TimelineLayout)),
+            LayoutKind::Category => Some(Box::new(CategoryLayout)),
+        }
+    }
+
+    /// The layout after this one, wrapping around
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|kind| *kind == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// Hop distance of every node reachable from `starts`
+/// With `directed`, only directed edges' forward direction is followed;
+/// bidirectional edges always work both ways.
+pub(crate) fn hop_distances(
+    input: &LayoutInput,
+    starts: &[NodeId],
+    directed: bool,
+) -> HashMap<NodeId, usize> {
+    let mut adjacency: HashMap<NodeId, Vec<NodeId>> = HashMap::new();
+    for edge in input.edges {
+        adjacency.entry(edge.from).or_default().push(edge.to);
+        if !directed || !edge.kind.is_directed() {
+            adjacency.entry(edge.to).or_default().push(edge.from);
+        }
+    }
+    let known: HashSet<NodeId> = input.nodes.iter().map(|node| node.id).collect();
+
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts.iter().filter(|id| known.contains(id)) {
+        if distances.insert(*start, 0).is_none() {
+            queue.push_back(*start);
+        }
+    }
+    while let Some(id) = queue.pop_front() {
+        let distance = distances[&id];
+        for next in adjacency.get(&id).into_iter().flatten() {
+            if known.contains(next) && !distances.contains_key(next) {
+                distances.insert(*next, distance + 1);
+                queue.push_back(*next);
+            }
+        }
+    }
+    distances
+}
+
+/// Group nodes by level, keeping input order within a level; unreached
+/// nodes go one level beyond the deepest
+pub(crate) fn levels(nodes: &[LayoutNode], distances: &HashMap<NodeId, usize>) -> Vec<Vec<NodeId>> {
+    let deepest = distances.values().max().map_or(0, |max| max + 1);
+    let mut levels: Vec<Vec<NodeId>> = Vec::new();
+    for node in nodes {
+        let level = distances.get(&node.id).copied().unwrap_or(deepest);
+        if levels.len() <= level {
+            levels.resize(level + 1, Vec::new());
+        }
+        levels[level].push(node.id);
+    }
+    levels.retain(|level| !level.is_empty());
+    levels
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::physics::ConnectionLineType;
+
+    pub(crate) fn node(id: u32) -> LayoutNode {
+        LayoutNode {
+            id: NodeId(id),
+            ..LayoutNode::default()
+        }
+    }
+
+    pub(crate) fn link(from: u32, to: u32) -> Edge {
+        Edge::new(NodeId(from), NodeId(to), ConnectionLineType::DirectLink)
+    }
+
+    #[test]
+    fn test_hop_distances_follow_edge_direction_when_asked() {
+        let nodes = [node(1), node(2), node(3), node(4)];
+        let edges = [link(1, 2), link(3, 2)];
+        let input = LayoutInput {
+            nodes: &nodes,
+            edges: &edges,
+            center: Position::default(),
+            spacing: DEFAULT_SPACING,
+        };
+        let undirected = hop_distances(&input, &[NodeId(1)], false);
+        assert_eq!(undirected[&NodeId(3)], 2);
+        let directed = hop_distances(&input, &[NodeId(1)], true);
+        assert!(!directed.contains_key(&NodeId(3)));
+
+        assert_eq!(
+            levels(&nodes, &directed),
+            [vec![NodeId(1)], vec![NodeId(2)], vec![NodeId(3), NodeId(4)]]
+        );
+    }
+
+    #[test]
+    fn test_every_engine_places_every_node() {
+        let mut nodes = vec![node(1), node(2), node(3)];
+        nodes[0].is_author = true;
+        nodes[1].created_at = Some("2024-01-01".to_string());
+        let edges = [link(1, 2)];
+        let input = LayoutInput {
+            nodes: &nodes,
+            edges: &edges,
+            center: Position::default(),
+            spacing: DEFAULT_SPACING,
+        };
+        for kind in LayoutKind::ALL {
+            let Some(engine) = kind.engine() else {
+                assert_eq!(kind, LayoutKind::Physics);
+                continue;
+            };
+            assert_eq!(engine.arrange(&input).len(), 3, "{kind:?}");
+        }
+        assert_eq!(LayoutKind::Category.next(), LayoutKind::Physics);
+    }
+}
@@ -0,0 +1,60 @@
+//! Rings by hop distance from the author node
+
+use super::{hop_distances, levels, LayoutEngine, LayoutInput};
+use crate::core::graph::ring_positions;
+use crate::core::physics::{NodeId, Position};
+use std::collections::HashMap;
+
+/// The author node in the centre, every other node on the ring of its hop
+/// distance from it. Without an author the first node is the centre.
+pub struct RadialLayout;
+
+impl LayoutEngine for RadialLayout {
+    fn arrange(&self, input: &LayoutInput) -> HashMap<NodeId, Position> {
+        let center = input
+            .nodes
+            .iter()
+            .find(|node| node.is_author)
+            .or(input.nodes.first())
+            .map(|node| node.id);
+        let distances = hop_distances(input, &center.into_iter().collect::<Vec<_>>(), false);
+
+        let mut positions = HashMap::new();
+        for (ring, ids) in levels(input.nodes, &distances).into_iter().enumerate() {
+            // Ring 0 is the centre node alone
+            let spots = ring_positions(input.center, ids.len(), ring as f32 * input.spacing);
+            positions.extend(ids.into_iter().zip(spots));
+        }
+        positions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::layouts::tests::{link, node};
+    use crate::core::layouts::DEFAULT_SPACING;
+
+    #[test]
+    fn test_rings_follow_hop_distance() {
+        let mut nodes = vec![node(1), node(2), node(3), node(4)];
+        nodes[1].is_author = true;
+        let edges = [link(2, 1), link(1, 3)];
+        let input = LayoutInput {
+            nodes: &nodes,
+            edges: &edges,
+            center: Position { x: 10.0, y: 10.0 },
+            spacing: DEFAULT_SPACING,
+        };
+        let positions = RadialLayout.arrange(&input);
+        let radius = |id| {
+            let pos: Position = positions[&NodeId(id)];
+            ((pos.x - 10.0).powi(2) + (pos.y - 10.0).powi(2)).sqrt()
+        };
+        assert!(radius(2) < 1e-4);
+        assert!((radius(1) - DEFAULT_SPACING).abs() < 1e-3);
+        assert!((radius(3) - 2.0 * DEFAULT_SPACING).abs() < 1e-3);
+        // Unconnected nodes go on the outermost ring
+        assert!((radius(4) - 3.0 * DEFAULT_SPACING).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,96 @@
+//! Articles along a time axis
+
+use super::{LayoutEngine, LayoutInput};
+use crate::core::articles::dates::parse_date;
+use crate::core::physics::{NodeId, Position};
+use chrono::Datelike;
+use std::collections::HashMap;
+
+/// Vertical offsets, in spacings, that neighbouring dates take in turn so
+/// articles written close together do not overlap
+const LANES: [f32; 3] = [0.0, -0.5, 0.5];
+
+/// x by `created_at`, oldest on the left, scaled so the timeline is as wide
+/// as the dated articles would be side by side. Articles without a date
+/// stand in a column to the right of it.
+pub struct TimelineLayout;
+
+/// Day number of a `created_at` value, in any format the front matter accepts
+fn day(created_at: &str) -> Option<i32> {
+    parse_date(created_at).map(|date| date.num_days_from_ce())
+}
+
+impl LayoutEngine for TimelineLayout {
+    fn arrange(&self, input: &LayoutInput) -> HashMap<NodeId, Position> {
+        let mut dated: Vec<(i32, NodeId)> = input
+            .nodes
+            .iter()
+            .filter_map(|node| Some((day(node.created_at.as_deref()?)?, node.id)))
+            .collect();
+        dated.sort_by_key(|(day, _)| *day);
+
+        let width = input.spacing * dated.len().saturating_sub(1).max(1) as f32;
+        let left = input.center.x - width / 2.0;
+        let (first, last) = match (dated.first(), dated.last()) {
+            (Some(first), Some(last)) => (first.0, last.0),
+            _ => (0, 0),
+        };
+
+        let mut positions = HashMap::new();
+        for (i, (day, id)) in dated.iter().enumerate() {
+            let x = if last > first {
+                left + (day - first) as f32 / (last - first) as f32 * width
+            } else {
+                input.center.x
+            };
+            let y = input.center.y + LANES[i % LANES.len()] * input.spacing;
+            positions.insert(*id, Position { x, y });
+        }
+
+        let undated: Vec<NodeId> = input
+            .nodes
+            .iter()
+            .map(|node| node.id)
+            .filter(|id| !positions.contains_key(id))
+            .collect();
+        let height = undated.len().saturating_sub(1) as f32;
+        let x = if dated.is_empty() {
+            input.center.x
+        } else {
+            left + width + input.spacing
+        };
+        for (i, id) in undated.into_iter().enumerate() {
+            let y = input.center.y + (i as f32 - height / 2.0) * input.spacing * 0.5;
+            positions.insert(id, Position { x, y });
+        }
+        positions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::layouts::tests::node;
+    use crate::core::layouts::DEFAULT_SPACING;
+
+    #[test]
+    fn test_dates_run_left_to_right() {
+        let mut nodes = vec![node(1), node(2), node(3), node(4)];
+        nodes[0].created_at = Some("2024-06-01".to_string());
+        nodes[1].created_at = Some("2023-01-15T09:00:00Z".to_string());
+        nodes[2].created_at = Some("not a date".to_string());
+        nodes[3].created_at = Some("2024/01/01".to_string());
+        let input = LayoutInput {
+            nodes: &nodes,
+            edges: &[],
+            center: Position::default(),
+            spacing: DEFAULT_SPACING,
+        };
+        let positions = TimelineLayout.arrange(&input);
+        let x = |id| positions[&NodeId(id)].x;
+        assert!(x(2) < x(4) && x(4) < x(1));
+        assert!((x(1) - x(2) - 2.0 * DEFAULT_SPACING).abs() < 1e-3);
+        // The undated article stands right of the timeline
+        assert!(x(3) > x(1));
+    }
+}
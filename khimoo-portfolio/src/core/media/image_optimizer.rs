@@ -528,8 +528,10 @@ mod tests {
     #[test]
     fn test_extract_image_references() {
         let optimizer = ImageOptimizer::with_defaults();
-        let mut metadata = crate::core::articles::ArticleMetadata::default();
-        metadata.author_image = Some("/articles/img/author_img.png".to_string());
+        let metadata = crate::core::articles::ArticleMetadata {
+            author_image: Some("/articles/img/author_img.png".to_string()),
+            ..Default::default()
+        };
 
         let images = optimizer.extract_image_references(&metadata);
         assert_eq!(images, vec!["author_img.png"]);
@@ -4,9 +4,11 @@
 //! and asset management functionality.
 
 pub mod image_optimizer;
+pub mod og_card;
 
 // Re-export main components
 pub use image_optimizer::{
     CompressedImage, ImageOptimizationConfig, ImageOptimizer, ImageProcessingError,
     OptimizedImageSet, Thumbnail,
 };
+pub use og_card::{OgCardConfig, OgCardGenerator, OgCardInput};
@@ -0,0 +1,506 @@
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+#[cfg(feature = "cli-tools")]
+use ab_glyph::{Font, FontVec, PxScale, ScaleFont};
+#[cfg(feature = "cli-tools")]
+use anyhow::Context;
+#[cfg(feature = "cli-tools")]
+use image::{imageops, Rgba, RgbaImage};
+#[cfg(feature = "cli-tools")]
+use sha2::{Digest, Sha256};
+#[cfg(feature = "cli-tools")]
+use std::fs;
+
+/// Bump when the card layout changes so cached cards are regenerated
+pub const OG_CARD_LAYOUT_VERSION: u32 = 1;
+
+/// Configuration for OpenGraph card generation
+#[derive(Debug, Clone)]
+pub struct OgCardConfig {
+    pub width: u32,
+    pub height: u32,
+    pub site_name: String,
+    pub site_url: String,
+    pub font_path: PathBuf,
+    pub avatar_path: PathBuf,
+    pub background_color: String,
+    pub text_color: String,
+}
+
+impl Default for OgCardConfig {
+    fn default() -> Self {
+        Self {
+            width: 1200,
+            height: 630,
+            site_name: "Khimoo Portfolio".to_string(),
+            site_url: String::new(),
+            font_path: PathBuf::from("../content/assets/fonts/NotoSansJP-Bold.ttf"),
+            avatar_path: PathBuf::from("../content/assets/img/author_img.png"),
+            background_color: "#081D35".to_string(),
+            text_color: "#FFFFFF".to_string(),
+        }
+    }
+}
+
+/// Article data shown on a card
+#[derive(Debug, Clone)]
+pub struct OgCardInput<'a> {
+    pub slug: &'a str,
+    pub title: &'a str,
+    pub category: Option<&'a str>,
+}
+
+/// OpenGraph social card generator
+/// Composes a PNG card per article and caches it by content hash
+pub struct OgCardGenerator {
+    config: OgCardConfig,
+    #[cfg(feature = "cli-tools")]
+    font: FontVec,
+    #[cfg(feature = "cli-tools")]
+    avatar: Option<RgbaImage>,
+    /// Hash of everything shared by all cards (font, avatar, site name, layout)
+    #[cfg(feature = "cli-tools")]
+    fingerprint: String,
+    #[cfg(feature = "cli-tools")]
+    verbose: bool,
+}
+
+#[cfg(feature = "cli-tools")]
+const PADDING: u32 = 80;
+#[cfg(feature = "cli-tools")]
+const ACCENT_BAR_HEIGHT: u32 = 16;
+#[cfg(feature = "cli-tools")]
+const TITLE_FONT_SIZE: f32 = 72.0;
+#[cfg(feature = "cli-tools")]
+const TITLE_LINE_HEIGHT: f32 = 96.0;
+#[cfg(feature = "cli-tools")]
+const TITLE_MAX_LINES: usize = 3;
+#[cfg(feature = "cli-tools")]
+const LABEL_FONT_SIZE: f32 = 32.0;
+#[cfg(feature = "cli-tools")]
+const SITE_NAME_FONT_SIZE: f32 = 36.0;
+#[cfg(feature = "cli-tools")]
+const AVATAR_SIZE: u32 = 96;
+
+impl OgCardGenerator {
+    /// Create a generator, loading the bundled font and the author avatar
+    #[cfg(feature = "cli-tools")]
+    pub fn new(config: OgCardConfig, verbose: bool) -> Result<Self> {
+        let font_bytes = fs::read(&config.font_path)
+            .with_context(|| format!("Failed to read OG card font: {:?}", config.font_path))?;
+
+        let mut hasher = Sha256::new();
+        hasher.update(OG_CARD_LAYOUT_VERSION.to_le_bytes());
+        hasher.update(config.width.to_le_bytes());
+        hasher.update(config.height.to_le_bytes());
+        hasher.update(config.site_name.as_bytes());
+        hasher.update(config.background_color.as_bytes());
+        hasher.update(config.text_color.as_bytes());
+        hasher.update(&font_bytes);
+
+        let font = FontVec::try_from_vec(font_bytes)
+            .map_err(|e| anyhow::anyhow!("Invalid OG card font {:?}: {}", config.font_path, e))?;
+
+        // The avatar is optional: a missing or unreadable image only drops it from the card
+        let avatar = match fs::read(&config.avatar_path) {
+            Ok(bytes) => match image::load_from_memory(&bytes) {
+                Ok(img) => {
+                    hasher.update(&bytes);
+                    Some(Self::circular_avatar(&img.to_rgba8(), AVATAR_SIZE))
+                }
+                Err(e) => {
+                    eprintln!(
+                        "⚠️  Failed to decode avatar {:?}, rendering cards without it: {}",
+                        config.avatar_path, e
+                    );
+                    None
+                }
+            },
+            Err(e) => {
+                eprintln!(
+                    "⚠️  Failed to read avatar {:?}, rendering cards without it: {}",
+                    config.avatar_path, e
+                );
+                None
+            }
+        };
+
+        Ok(Self {
+            config,
+            font,
+            avatar,
+            fingerprint: to_hex(&hasher.finalize()),
+            verbose,
+        })
+    }
+
+    /// Content hash for a card; changes whenever anything drawn on it changes
+    #[cfg(feature = "cli-tools")]
+    pub fn card_hash(&self, input: &OgCardInput) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.fingerprint.as_bytes());
+        hasher.update(input.title.as_bytes());
+        hasher.update([0u8]);
+        hasher.update(input.category.unwrap_or_default().as_bytes());
+        to_hex(&hasher.finalize())[..16].to_string()
+    }
+
+    /// Generate the card for an article unless a card with the same hash exists
+    /// Returns the card file name relative to `output_dir`
+    #[cfg(feature = "cli-tools")]
+    pub fn generate(&self, input: &OgCardInput, output_dir: &Path) -> Result<String> {
+        fs::create_dir_all(output_dir)
+            .with_context(|| format!("Failed to create output directory: {:?}", output_dir))?;
+
+        let hash = self.card_hash(input);
+        let file_name = card_file_name(input.slug, &hash);
+        let card_path = output_dir.join(&file_name);
+
+        self.remove_stale_cards(input.slug, &file_name, output_dir)?;
+
+        if card_path.exists() {
+            if self.verbose {
+                println!("⏭️  OG card up to date: {}", card_path.display());
+            }
+            return Ok(file_name);
+        }
+
+        let card = self.render(input);
+        card.save_with_format(&card_path, image::ImageFormat::Png)
+            .with_context(|| format!("Failed to save OG card: {:?}", card_path))?;
+
+        if self.verbose {
+            println!("🖼️  Generated OG card: {}", card_path.display());
+        }
+
+        Ok(file_name)
+    }
+
+    /// Render a card into an image buffer
+    #[cfg(feature = "cli-tools")]
+    pub fn render(&self, input: &OgCardInput) -> RgbaImage {
+        let (width, height) = (self.config.width, self.config.height);
+        let background = parse_hex_color(&self.config.background_color).unwrap_or([8, 29, 53]);
+        let text_color = parse_hex_color(&self.config.text_color).unwrap_or([255, 255, 255]);
+        let category = input.category.filter(|c| !c.trim().is_empty());
+        let (primary, _) = crate::config::category_palette(category.unwrap_or("default"));
+        let accent = parse_hex_color(primary).unwrap_or([155, 155, 155]);
+
+        let mut card = RgbaImage::from_pixel(
+            width,
+            height,
+            Rgba([background[0], background[1], background[2], 255]),
+        );
+
+        // Category accent bar along the top edge
+        for y in 0..ACCENT_BAR_HEIGHT.min(height) {
+            for x in 0..width {
+                card.put_pixel(x, y, Rgba([accent[0], accent[1], accent[2], 255]));
+            }
+        }
+
+        let mut title_top = PADDING as f32 + ACCENT_BAR_HEIGHT as f32;
+        if let Some(category) = category {
+            self.draw_text(
+                &mut card,
+                category,
+                LABEL_FONT_SIZE,
+                PADDING as f32,
+                title_top + LABEL_FONT_SIZE,
+                accent,
+            );
+            title_top += LABEL_FONT_SIZE * 2.0;
+        }
+
+        // Title, wrapped to the card width
+        let max_title_width = (width - PADDING * 2) as f32;
+        let title_scale = self.font.as_scaled(PxScale::from(TITLE_FONT_SIZE));
+        let lines = wrap_text(input.title, max_title_width, TITLE_MAX_LINES, |c| {
+            title_scale.h_advance(title_scale.glyph_id(c))
+        });
+        for (i, line) in lines.iter().enumerate() {
+            let baseline = title_top + TITLE_FONT_SIZE + i as f32 * TITLE_LINE_HEIGHT;
+            self.draw_text(
+                &mut card,
+                line,
+                TITLE_FONT_SIZE,
+                PADDING as f32,
+                baseline,
+                text_color,
+            );
+        }
+
+        // Footer: avatar and site name
+        let footer_top = height.saturating_sub(PADDING + AVATAR_SIZE);
+        let mut site_name_x = PADDING as f32;
+        if let Some(avatar) = &self.avatar {
+            imageops::overlay(&mut card, avatar, PADDING as i64, footer_top as i64);
+            site_name_x += AVATAR_SIZE as f32 + 24.0;
+        }
+        let site_name_baseline =
+            footer_top as f32 + (AVATAR_SIZE as f32 + SITE_NAME_FONT_SIZE) / 2.0;
+        self.draw_text(
+            &mut card,
+            &self.config.site_name,
+            SITE_NAME_FONT_SIZE,
+            site_name_x,
+            site_name_baseline,
+            text_color,
+        );
+
+        card
+    }
+
+    /// Get card configuration
+    pub fn config(&self) -> &OgCardConfig {
+        &self.config
+    }
+
+    #[cfg(feature = "cli-tools")]
+    fn draw_text(
+        &self,
+        image: &mut RgbaImage,
+        text: &str,
+        size: f32,
+        x: f32,
+        baseline: f32,
+        color: [u8; 3],
+    ) {
+        let scale = PxScale::from(size);
+        let scaled_font = self.font.as_scaled(scale);
+        let mut caret = x;
+        let mut previous = None;
+
+        for c in text.chars() {
+            let glyph_id = scaled_font.glyph_id(c);
+            if let Some(previous) = previous {
+                caret += scaled_font.kern(previous, glyph_id);
+            }
+            let glyph = glyph_id.with_scale_and_position(scale, ab_glyph::point(caret, baseline));
+            caret += scaled_font.h_advance(glyph_id);
+            previous = Some(glyph_id);
+
+            if let Some(outlined) = self.font.outline_glyph(glyph) {
+                let bounds = outlined.px_bounds();
+                outlined.draw(|gx, gy, coverage| {
+                    let px = bounds.min.x as i32 + gx as i32;
+                    let py = bounds.min.y as i32 + gy as i32;
+                    if px < 0 || py < 0 || px >= image.width() as i32 || py >= image.height() as i32
+                    {
+                        return;
+                    }
+                    let pixel = image.get_pixel_mut(px as u32, py as u32);
+                    for channel in 0..3 {
+                        let base = pixel[channel] as f32;
+                        pixel[channel] =
+                            (base + (color[channel] as f32 - base) * coverage).round() as u8;
+                    }
+                });
+            }
+        }
+    }
+
+    /// Crop an image to a circle of the given diameter
+    #[cfg(feature = "cli-tools")]
+    fn circular_avatar(source: &RgbaImage, size: u32) -> RgbaImage {
+        let mut avatar = imageops::resize(
+            &imageops::crop_imm(
+                source,
+                source.width().saturating_sub(source.height()) / 2,
+                source.height().saturating_sub(source.width()) / 2,
+                source.width().min(source.height()),
+                source.width().min(source.height()),
+            )
+            .to_image(),
+            size,
+            size,
+            imageops::FilterType::Lanczos3,
+        );
+
+        let radius = size as f32 / 2.0;
+        for (x, y, pixel) in avatar.enumerate_pixels_mut() {
+            let dx = x as f32 + 0.5 - radius;
+            let dy = y as f32 + 0.5 - radius;
+            // One pixel of anti-aliasing at the edge
+            let coverage = (radius - (dx * dx + dy * dy).sqrt()).clamp(0.0, 1.0);
+            pixel[3] = (pixel[3] as f32 * coverage) as u8;
+        }
+
+        avatar
+    }
+
+    /// Remove cards for this slug that were rendered from older content
+    #[cfg(feature = "cli-tools")]
+    fn remove_stale_cards(&self, slug: &str, current: &str, output_dir: &Path) -> Result<()> {
+        for entry in fs::read_dir(output_dir)? {
+            let path = entry?.path();
+            let Some(file_name) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            if file_name != current && is_card_file_for(slug, file_name) {
+                if self.verbose {
+                    println!("🗑️  Removing stale OG card: {}", path.display());
+                }
+                fs::remove_file(&path)
+                    .with_context(|| format!("Failed to remove stale OG card: {:?}", path))?;
+            }
+        }
+        Ok(())
+    }
+
+    // Stub implementations for when cli-tools feature is not enabled
+    #[cfg(not(feature = "cli-tools"))]
+    pub fn new(_config: OgCardConfig, _verbose: bool) -> Result<Self> {
+        Err(anyhow::anyhow!(
+            "OG card generation requires cli-tools feature"
+        ))
+    }
+
+    #[cfg(not(feature = "cli-tools"))]
+    pub fn generate(&self, _input: &OgCardInput, _output_dir: &Path) -> Result<String> {
+        Err(anyhow::anyhow!(
+            "OG card generation requires cli-tools feature"
+        ))
+    }
+}
+
+/// File name of a card: `<slug>-<hash>.png`
+pub fn card_file_name(slug: &str, hash: &str) -> String {
+    format!("{slug}-{hash}.png")
+}
+
+/// Whether a file name is a card generated for `slug` (any hash)
+pub fn is_card_file_for(slug: &str, file_name: &str) -> bool {
+    file_name
+        .strip_prefix(slug)
+        .and_then(|rest| rest.strip_prefix('-'))
+        .and_then(|rest| rest.strip_suffix(".png"))
+        .is_some_and(|hash| hash.len() == 16 && hash.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Parse a `#RRGGBB` color
+pub fn parse_hex_color(hex: &str) -> Option<[u8; 3]> {
+    let hex = hex.trim().strip_prefix('#')?;
+    if hex.len() != 6 {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+    Some([channel(0)?, channel(2)?, channel(4)?])
+}
+
+/// Greedy line wrapping that works for both spaced and CJK text
+/// Latin words are kept together when possible; CJK text may break between any characters.
+/// The last line is ellipsized when the text does not fit in `max_lines`.
+pub fn wrap_text(
+    text: &str,
+    max_width: f32,
+    max_lines: usize,
+    advance: impl Fn(char) -> f32,
+) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut current_width = 0.0;
+    // Byte index in `current` just after the last space, and the width up to it
+    let mut last_break: Option<(usize, f32)> = None;
+
+    for c in text
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+    {
+        let w = advance(c);
+        if current_width + w > max_width && !current.is_empty() {
+            match last_break {
+                Some((index, width)) if c != ' ' => {
+                    let rest = current.split_off(index);
+                    lines.push(current.trim_end().to_string());
+                    current = rest;
+                    current_width -= width;
+                }
+                _ => {
+                    lines.push(current.trim_end().to_string());
+                    current = String::new();
+                    current_width = 0.0;
+                }
+            }
+            last_break = None;
+            if c == ' ' {
+                continue;
+            }
+        }
+        current.push(c);
+        current_width += w;
+        if c == ' ' {
+            last_break = Some((current.len(), current_width));
+        }
+    }
+    if !current.trim().is_empty() {
+        lines.push(current.trim_end().to_string());
+    }
+
+    if lines.len() > max_lines {
+        lines.truncate(max_lines);
+        if let Some(last) = lines.last_mut() {
+            let ellipsis_width = advance('…');
+            let mut width: f32 = last.chars().map(&advance).sum();
+            while width + ellipsis_width > max_width {
+                match last.pop() {
+                    Some(c) => width -= advance(c),
+                    None => break,
+                }
+            }
+            last.push('…');
+        }
+    }
+
+    lines
+}
+
+#[cfg(feature = "cli-tools")]
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hex_color() {
+        assert_eq!(parse_hex_color("#4A90E2"), Some([0x4a, 0x90, 0xe2]));
+        assert_eq!(parse_hex_color("4A90E2"), None);
+        assert_eq!(parse_hex_color("#4A90"), None);
+        assert_eq!(parse_hex_color("#zz90E2"), None);
+    }
+
+    #[test]
+    fn test_is_card_file_for() {
+        assert!(is_card_file_for("math", "math-0123456789abcdef.png"));
+        assert!(!is_card_file_for("math", "math-notes-0123456789abcdef.png"));
+        assert!(!is_card_file_for("math", "math-0123.png"));
+        assert!(is_card_file_for(
+            "math-notes",
+            &card_file_name("math-notes", "fedcba9876543210")
+        ));
+    }
+
+    #[test]
+    fn test_wrap_text_latin_breaks_at_spaces() {
+        let lines = wrap_text("hello wide world", 60.0, 3, |_| 10.0);
+        assert_eq!(lines, vec!["hello", "wide", "world"]);
+    }
+
+    #[test]
+    fn test_wrap_text_cjk_breaks_anywhere() {
+        let lines = wrap_text("連続体理論入門", 30.0, 3, |_| 10.0);
+        assert_eq!(lines, vec!["連続体", "理論入", "門"]);
+    }
+
+    #[test]
+    fn test_wrap_text_ellipsizes_overflow() {
+        let lines = wrap_text("あいうえおかきくけこ", 30.0, 2, |_| 10.0);
+        assert_eq!(lines, vec!["あいう", "えお…"]);
+    }
+}
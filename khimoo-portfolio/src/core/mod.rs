@@ -1,10 +1,15 @@
 //! Core business logic module
 //!
 //! This module contains UI-independent business logic for article processing,
-//! metadata extraction, link management, and media optimization.
+//! metadata extraction, link management, media optimization, the article graph,
+//! its physics and its alternative layouts.
 
 pub mod articles;
+pub mod graph;
+pub mod layouts;
 pub mod media;
+pub mod model;
+pub mod physics;
 
 // Re-export commonly used items from articles
 pub use articles::{
@@ -0,0 +1,390 @@
+//! Shared data model
+//!
+//! The JSON contract between the CLI (which writes `data/`) and the web app
+//! (which reads it). Both sides use these types, so a field added here is
+//! seen by both.
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+
+use crate::core::articles::links::{ExtractedLink, LinkType, ProcessedArticleRef};
+use crate::core::articles::metadata::ArticleMetadata;
+use crate::core::articles::recommend::Recommendation;
+use crate::core::articles::stats::ContentStats;
+
+/// Version of the data format written by the CLI
+/// Bump when a change would make older web builds misread the data (renamed or
+/// removed fields, changed meaning). Adding a field with a serde default does not
+/// need a bump.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// File name of the article index in the data directory
+pub const INDEX_FILE: &str = "index.json";
+
+/// Subdirectory of the data directory that holds per-article shards
+pub const ARTICLE_SHARDS_DIR: &str = "articles";
+
+/// Whether data written with `version` can be read by this build
+pub fn is_compatible_schema(version: u32) -> bool {
+    version == SCHEMA_VERSION
+}
+
+/// Minimal view of a data file used to check its schema version before full parsing
+/// Files written before versioning have no field and read as version 0.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct SchemaHeader {
+    #[serde(default)]
+    pub schema_version: u32,
+}
+
+/// Full article data, written to `articles/<slug>.json`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "cli-tools", derive(schemars::JsonSchema))]
+pub struct ProcessedArticle {
+    #[serde(default)]
+    pub schema_version: u32,
+    pub slug: String,
+    pub title: String,
+    pub metadata: ArticleMetadata,
+    pub file_path: String,
+    pub outbound_links: Vec<ExtractedLink>,
+    pub inbound_links: Vec<ExtractedLink>,
+    pub processed_at: String,
+    /// OpenGraph card path relative to the data directory
+    #[serde(default)]
+    pub og_image: Option<String>,
+    /// Plain-text summary for list display
+    #[serde(default)]
+    pub summary: Option<String>,
+    #[serde(default)]
+    pub stats: ContentStats,
+    /// Normalised creation date (`YYYY-MM-DD`), with a fallback for articles
+    /// without `created_at`
+    #[serde(default)]
+    pub created_on: Option<String>,
+    /// Similar articles computed at build time, best first
+    #[serde(default)]
+    pub recommendations: Vec<Recommendation>,
+}
+
+impl ProcessedArticle {
+    /// Create from ProcessedArticleRef and file path
+    pub fn from_ref_and_file_path(article_ref: ProcessedArticleRef, file_path: String) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            slug: article_ref.slug,
+            title: article_ref.title,
+            metadata: article_ref.metadata,
+            file_path,
+            outbound_links: article_ref.outbound_links,
+            inbound_links: article_ref.inbound_links,
+            processed_at: Utc::now().to_rfc3339(),
+            og_image: None,
+            summary: article_ref.summary,
+            stats: article_ref.stats,
+            created_on: None,
+            recommendations: Vec::new(),
+        }
+    }
+
+    /// Slugs of internal articles this article links to, without duplicates
+    pub fn internal_link_targets(&self) -> Vec<String> {
+        let mut seen = HashSet::new();
+        self.outbound_links
+            .iter()
+            .filter(|link| link.link_type == LinkType::MarkdownLink)
+            .filter(|link| seen.insert(link.target_slug.as_str()))
+            .map(|link| link.target_slug.clone())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+impl ProcessedArticle {
+    /// Article titled by its slug, filled in with the setters below
+    pub fn for_test(slug: &str) -> Self {
+        let file_path = format!("{slug}.md");
+        Self::from_ref_and_file_path(
+            ProcessedArticleRef {
+                slug: slug.to_string(),
+                title: slug.to_string(),
+                metadata: ArticleMetadata::default(),
+                outbound_links: Vec::new(),
+                inbound_links: Vec::new(),
+                file_path: file_path.clone(),
+                summary: None,
+                stats: ContentStats::default(),
+            },
+            file_path,
+        )
+    }
+
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = title.to_string();
+        self
+    }
+
+    /// Markdown links to the given slugs
+    pub fn links(mut self, targets: &[&str]) -> Self {
+        self.outbound_links = targets
+            .iter()
+            .map(|target| ExtractedLink {
+                target_slug: target.to_string(),
+                link_type: LinkType::MarkdownLink,
+                original_text: format!("[{target}]({target})"),
+                display_text: None,
+            })
+            .collect();
+        self
+    }
+
+    pub fn category(mut self, category: &str) -> Self {
+        self.metadata.category = Some(category.to_string());
+        self
+    }
+
+    pub fn tags(mut self, tags: &[&str]) -> Self {
+        self.metadata.tags = tags.iter().map(|s| s.to_string()).collect();
+        self
+    }
+
+    pub fn created_on(mut self, date: &str) -> Self {
+        self.created_on = Some(date.to_string());
+        self
+    }
+}
+
+/// Slim per-article entry of index.json, used for list display and the node graph
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "cli-tools", derive(schemars::JsonSchema))]
+pub struct ArticleIndexEntry {
+    pub slug: String,
+    pub title: String,
+    pub metadata: ArticleMetadata,
+    pub summary: Option<String>,
+    /// Slugs of internal articles this article links to
+    pub outbound_links: Vec<String>,
+    pub inbound_count: usize,
+    #[serde(default)]
+    pub stats: ContentStats,
+    /// Normalised creation date (`YYYY-MM-DD`)
+    #[serde(default)]
+    pub created_on: Option<String>,
+    /// Link graph metrics, computed by process-articles when
+    /// `[graph] size_by_centrality` is set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<GraphMetrics>,
+}
+
+/// Position of an article in the link graph
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "cli-tools", derive(schemars::JsonSchema))]
+pub struct GraphMetrics {
+    /// PageRank over the links; sums to 1 over all articles
+    pub pagerank: f32,
+    /// PageRank scaled so the highest-ranked article has 1
+    pub centrality: f32,
+    /// Normalised betweenness centrality (0 to 1)
+    pub betweenness: f32,
+    /// Connected component, largest first
+    pub component: usize,
+    /// Community, largest first
+    pub community: usize,
+}
+
+impl From<&ProcessedArticle> for ArticleIndexEntry {
+    fn from(article: &ProcessedArticle) -> Self {
+        Self {
+            slug: article.slug.clone(),
+            title: article.title.clone(),
+            metadata: article.metadata.clone(),
+            summary: article.summary.clone(),
+            outbound_links: article.internal_link_targets(),
+            inbound_count: article.inbound_links.len(),
+            stats: article.stats.clone(),
+            created_on: article.created_on.clone(),
+            metrics: None,
+        }
+    }
+}
+
+#[cfg(test)]
+impl ArticleIndexEntry {
+    /// Bare entry titled by its slug, filled in with the setters below
+    pub fn for_test(slug: &str) -> Self {
+        Self {
+            slug: slug.to_string(),
+            title: slug.to_string(),
+            metadata: ArticleMetadata::default(),
+            summary: None,
+            outbound_links: Vec::new(),
+            inbound_count: 0,
+            stats: ContentStats::default(),
+            created_on: None,
+            metrics: None,
+        }
+    }
+
+    pub fn links(mut self, targets: &[&str]) -> Self {
+        self.outbound_links = targets.iter().map(|s| s.to_string()).collect();
+        self
+    }
+
+    pub fn related(mut self, slugs: &[&str]) -> Self {
+        self.metadata.related_articles = slugs.iter().map(|s| s.to_string()).collect();
+        self
+    }
+
+    pub fn relations(mut self, relations: Vec<crate::core::articles::relations::Relation>) -> Self {
+        self.metadata.relations = relations;
+        self
+    }
+
+    pub fn category(mut self, category: &str) -> Self {
+        self.metadata.category = Some(category.to_string());
+        self
+    }
+
+    pub fn tags(mut self, tags: &[&str]) -> Self {
+        self.metadata.tags = tags.iter().map(|s| s.to_string()).collect();
+        self
+    }
+
+    pub fn created_on(mut self, date: &str) -> Self {
+        self.created_on = Some(date.to_string());
+        self
+    }
+
+    pub fn home(mut self) -> Self {
+        self.metadata.home_display = true;
+        self
+    }
+
+    /// Mark as the author article
+    pub fn author(mut self) -> Self {
+        self.metadata.author_image = Some("author.png".to_string());
+        self
+    }
+}
+
+/// Article index, written to `index.json`
+/// Full article data lives in per-article shards under `articles/`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "cli-tools", derive(schemars::JsonSchema))]
+pub struct ArticleIndex {
+    #[serde(default)]
+    pub schema_version: u32,
+    pub articles: Vec<ArticleIndexEntry>,
+    pub generated_at: String,
+    pub total_count: usize,
+    pub home_articles: Vec<String>,
+    /// Settled home graph layout, if the CLI precomputed one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub layout: Option<GraphLayout>,
+    /// Size nodes by `metrics.centrality` instead of `importance`, set by
+    /// `[graph] size_by_centrality` in project.toml
+    #[serde(default)]
+    pub size_by_centrality: bool,
+}
+
+/// Point in a precomputed layout's unit box
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "cli-tools", derive(schemars::JsonSchema))]
+pub struct LayoutPoint {
+    pub x: f32,
+    pub y: f32,
+}
+
+/// Home graph layout settled by running the physics headlessly
+/// Positions are normalised to the unit box; `extent` restores the simulated
+/// spacing and `center` is where the author node is pulled to.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "cli-tools", derive(schemars::JsonSchema))]
+pub struct GraphLayout {
+    /// Simulation steps run before the positions were taken
+    pub steps: usize,
+    /// Size of the unit box in simulation units
+    pub extent: f32,
+    pub center: LayoutPoint,
+    /// Node positions keyed by article slug
+    pub positions: BTreeMap<String, LayoutPoint>,
+}
+
+impl ArticleIndex {
+    /// Build an index over processed articles
+    pub fn from_articles(articles: &[ProcessedArticle]) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            articles: articles.iter().map(ArticleIndexEntry::from).collect(),
+            generated_at: Utc::now().to_rfc3339(),
+            total_count: articles.len(),
+            home_articles: articles
+                .iter()
+                .filter(|a| a.metadata.home_display)
+                .map(|a| a.slug.clone())
+                .collect(),
+            layout: None,
+            size_by_centrality: false,
+        }
+    }
+
+    /// Empty index used when no data is available
+    pub fn empty() -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            articles: Vec::new(),
+            generated_at: "1970-01-01T00:00:00Z".to_string(),
+            total_count: 0,
+            home_articles: Vec::new(),
+            layout: None,
+            size_by_centrality: false,
+        }
+    }
+}
+
+/// JSON Schemas of the data files, keyed by file name
+#[cfg(feature = "cli-tools")]
+pub fn json_schemas() -> Vec<(&'static str, schemars::Schema)> {
+    vec![
+        ("index.schema.json", schemars::schema_for!(ArticleIndex)),
+        (
+            "article.schema.json",
+            schemars::schema_for!(ProcessedArticle),
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_header_defaults_to_zero() {
+        let header: SchemaHeader = serde_json::from_str(r#"{"articles": []}"#).unwrap();
+        assert_eq!(header.schema_version, 0);
+        assert!(!is_compatible_schema(header.schema_version));
+    }
+
+    #[test]
+    fn test_index_round_trip() {
+        let index = ArticleIndex::empty();
+        let json = serde_json::to_string(&index).unwrap();
+        let header: SchemaHeader = serde_json::from_str(&json).unwrap();
+        assert!(is_compatible_schema(header.schema_version));
+        assert_eq!(serde_json::from_str::<ArticleIndex>(&json).unwrap(), index);
+    }
+
+    /// The checked-in schema files must match the types; regenerate with
+    /// `cargo run --features cli-tools -- schema`
+    #[cfg(feature = "cli-tools")]
+    #[test]
+    fn test_checked_in_schemas_are_current() {
+        let schema_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("schema");
+        for (file_name, schema) in json_schemas() {
+            let expected = serde_json::to_string_pretty(&schema).unwrap() + "\n";
+            let actual = std::fs::read_to_string(schema_dir.join(file_name)).unwrap_or_default();
+            assert_eq!(actual, expected, "schema/{file_name} is out of date");
+        }
+    }
+}
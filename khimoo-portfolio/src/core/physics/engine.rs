@@ -0,0 +1,440 @@
+use super::types::{Edge, ForceSettings, NodeId, PhysicsNode, Position};
+use super::world::PhysicsWorld;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Message from the UI to the simulation
+/// Serialisable so it can be posted to a worker as is.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum PhysicsCommand {
+    /// Replace the whole graph
+    Init {
+        nodes: Vec<PhysicsNode>,
+        edges: Vec<Edge>,
+        settings: ForceSettings,
+        center: Position,
+        /// The nodes are already at rest (e.g. a precomputed layout), so the
+        /// simulation waits for the first interaction instead of starting
+        #[serde(default)]
+        start_settled: bool,
+    },
+    UpdateForceSettings {
+        settings: ForceSettings,
+    },
+    /// Move the point the author node is pulled towards
+    SetCenter {
+        center: Position,
+    },
+    /// A pointer went down on a node; keeps the simulation awake until released
+    Grab {
+        id: NodeId,
+    },
+    /// Drag a grabbed node to a world position
+    MoveTo {
+        id: NodeId,
+        position: Position,
+    },
+    /// The pointer was lifted or cancelled
+    Release {
+        id: NodeId,
+    },
+    SetPinned {
+        id: NodeId,
+        pinned: bool,
+    },
+    /// Add a node to the running simulation; ignored if the id is taken
+    AddNode {
+        node: PhysicsNode,
+    },
+    /// Remove a node and its edges
+    RemoveNode {
+        id: NodeId,
+    },
+    /// Add an edge; ignored if the two nodes are already connected that way
+    AddEdge {
+        edge: Edge,
+    },
+    RemoveEdge {
+        from: NodeId,
+        to: NodeId,
+    },
+    /// Ease the nodes to fixed positions, or hand them back to the
+    /// simulation with None
+    Arrange {
+        targets: Option<Vec<(NodeId, Position)>>,
+    },
+    /// Stop stepping, e.g. while the page is hidden
+    /// Handled by whatever drives the steps; the engine ignores it.
+    Pause,
+    Resume,
+}
+
+impl PhysicsCommand {
+    /// Whether the simulation loop should run after this command
+    pub fn wakes_simulation(&self) -> bool {
+        !matches!(
+            self,
+            PhysicsCommand::Init {
+                start_settled: true,
+                ..
+            } | PhysicsCommand::Pause
+                | PhysicsCommand::Resume
+        )
+    }
+}
+
+/// Node order of the packed position buffer
+///
+/// Starts with the order of `Init`; added nodes go to the end and removed ones
+/// drop out. Both sides apply the same commands, so they agree on the order.
+/// The generation changes with every change to the order, which lets the
+/// receiving side drop buffers that were packed before its latest command.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NodeOrder {
+    ids: Vec<NodeId>,
+    generation: u32,
+}
+
+impl NodeOrder {
+    /// Follow a command; returns whether the order changed
+    pub fn apply(&mut self, command: &PhysicsCommand) -> bool {
+        match command {
+            PhysicsCommand::Init { nodes, .. } => {
+                self.ids = nodes.iter().map(|node| node.id).collect();
+            }
+            PhysicsCommand::AddNode { node } if !self.ids.contains(&node.id) => {
+                self.ids.push(node.id);
+            }
+            PhysicsCommand::RemoveNode { id } if self.ids.contains(id) => {
+                self.ids.retain(|other| other != id);
+            }
+            _ => return false,
+        }
+        self.generation = self.generation.wrapping_add(1);
+        true
+    }
+
+    pub fn ids(&self) -> &[NodeId] {
+        &self.ids
+    }
+
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+}
+
+/// Simulation driven by `PhysicsCommand`s
+///
+/// Positions are exchanged as a packed `[x0, y0, x1, y1, ...]` buffer in the
+/// `NodeOrder` that both sides keep.
+#[derive(Default)]
+pub struct PhysicsEngine {
+    world: Option<PhysicsWorld>,
+    order: NodeOrder,
+    /// Grabbed nodes and whether they have been moved since
+    held: HashMap<NodeId, bool>,
+}
+
+impl PhysicsEngine {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn handle(&mut self, command: PhysicsCommand) {
+        self.order.apply(&command);
+        match command {
+            PhysicsCommand::Init {
+                nodes,
+                edges,
+                settings,
+                center,
+                ..
+            } => {
+                self.held.clear();
+                self.world = Some(PhysicsWorld::new(&nodes, &edges, settings, center));
+            }
+            PhysicsCommand::UpdateForceSettings { settings } => {
+                if let Some(world) = &mut self.world {
+                    let links_changed = settings.link_strength
+                        != world.force_settings().link_strength
+                        || settings.direct_link_damping
+                            != world.force_settings().direct_link_damping;
+                    world.update_force_settings(settings);
+                    if links_changed {
+                        world.update_joint_strengths();
+                    }
+                }
+            }
+            PhysicsCommand::SetCenter { center } => {
+                if let Some(world) = &mut self.world {
+                    world.set_center(center);
+                }
+            }
+            PhysicsCommand::Grab { id } => {
+                self.held.insert(id, false);
+            }
+            PhysicsCommand::MoveTo { id, position } => {
+                let Some(world) = &mut self.world else {
+                    return;
+                };
+                if let Some(moved) = self.held.get_mut(&id) {
+                    if !*moved {
+                        *moved = true;
+                        world.set_node_kinematic(id);
+                    }
+                    world.set_node_position(id, &position);
+                }
+            }
+            PhysicsCommand::Release { id } => {
+                if self.held.remove(&id) == Some(true) {
+                    if let Some(world) = &mut self.world {
+                        world.release_node(id);
+                    }
+                }
+            }
+            PhysicsCommand::SetPinned { id, pinned } => {
+                if let Some(world) = &mut self.world {
+                    world.set_node_pinned(id, pinned);
+                }
+            }
+            PhysicsCommand::AddNode { node } => {
+                if let Some(world) = &mut self.world {
+                    world.add_node(&node);
+                }
+            }
+            PhysicsCommand::RemoveNode { id } => {
+                self.held.remove(&id);
+                if let Some(world) = &mut self.world {
+                    world.remove_node(id);
+                }
+            }
+            PhysicsCommand::AddEdge { edge } => {
+                if let Some(world) = &mut self.world {
+                    world.add_edge(edge);
+                }
+            }
+            PhysicsCommand::RemoveEdge { from, to } => {
+                if let Some(world) = &mut self.world {
+                    world.remove_edge(from, to);
+                }
+            }
+            PhysicsCommand::Arrange { targets } => {
+                if let Some(world) = &mut self.world {
+                    world.set_layout_targets(targets.map(|targets| targets.into_iter().collect()));
+                }
+            }
+            PhysicsCommand::Pause | PhysicsCommand::Resume => {}
+        }
+    }
+
+    /// Run the given number of steps
+    pub fn step(&mut self, steps: usize) {
+        if let Some(world) = &mut self.world {
+            for _ in 0..steps {
+                world.step();
+            }
+        }
+    }
+
+    /// Kinetic energy for settle detection, or None while a node is held so
+    /// the simulation keeps running
+    pub fn energy(&self) -> Option<f32> {
+        if !self.held.is_empty() {
+            return None;
+        }
+        Some(
+            self.world
+                .as_ref()
+                .map_or(0.0, |world| world.kinetic_energy()),
+        )
+    }
+
+    /// Node order of the packed position buffer
+    pub fn order(&self) -> &[NodeId] {
+        self.order.ids()
+    }
+
+    /// Generation of the node order the next buffer is packed in
+    pub fn generation(&self) -> u32 {
+        self.order.generation()
+    }
+
+    /// Write the current positions into `buffer` in node order
+    pub fn pack_positions(&self, buffer: &mut Vec<f32>) {
+        buffer.clear();
+        let Some(world) = &self.world else {
+            return;
+        };
+        for id in self.order.ids() {
+            let pos = world.position(*id).unwrap_or_default();
+            buffer.push(pos.x);
+            buffer.push(pos.y);
+        }
+    }
+}
+
+/// Pair a packed position buffer with the node order it was written in
+pub fn unpack_positions<'a>(
+    order: &'a [NodeId],
+    buffer: &'a [f32],
+) -> impl Iterator<Item = (NodeId, Position)> + 'a {
+    order
+        .iter()
+        .zip(buffer.chunks_exact(2))
+        .map(|(id, xy)| (*id, Position { x: xy[0], y: xy[1] }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::physics::types::ConnectionLineType;
+
+    fn node(id: u32, x: f32, y: f32, category: &str) -> PhysicsNode {
+        PhysicsNode {
+            id: NodeId(id),
+            position: Position { x, y },
+            radius: 30.0,
+            collider_radius: 36.0,
+            category: Some(category.to_string()),
+            is_author: id == 0,
+        }
+    }
+
+    fn link(from: u32, to: u32) -> Edge {
+        Edge::new(NodeId(from), NodeId(to), ConnectionLineType::DirectLink)
+    }
+
+    fn init(nodes: Vec<PhysicsNode>, edges: Vec<Edge>) -> PhysicsEngine {
+        let mut engine = PhysicsEngine::new();
+        engine.handle(PhysicsCommand::Init {
+            nodes,
+            edges,
+            settings: ForceSettings::default(),
+            center: Position { x: 0.0, y: 0.0 },
+            start_settled: false,
+        });
+        engine
+    }
+
+    fn positions(engine: &PhysicsEngine) -> Vec<(NodeId, Position)> {
+        let mut buffer = Vec::new();
+        engine.pack_positions(&mut buffer);
+        unpack_positions(engine.order(), &buffer).collect()
+    }
+
+    #[test]
+    fn test_positions_round_trip_in_init_order() {
+        let engine = init(
+            vec![node(5, 10.0, 20.0, "a"), node(2, -3.0, 4.0, "a")],
+            vec![],
+        );
+        let positions = positions(&engine);
+        assert_eq!(positions[0], (NodeId(5), Position { x: 10.0, y: 20.0 }));
+        assert_eq!(positions[1], (NodeId(2), Position { x: -3.0, y: 4.0 }));
+    }
+
+    #[test]
+    fn test_simulation_is_reproducible() {
+        // A 4x3 grid with nodes far closer than the repulsion range
+        let nodes: Vec<_> = (0..12)
+            .map(|i| node(i, (i % 4) as f32 * 40.0, (i / 4) as f32 * 40.0, "a"))
+            .collect();
+        let edges = vec![link(0, 1), link(1, 2)];
+
+        let mut first = init(nodes.clone(), edges.clone());
+        let mut second = init(nodes, edges);
+        first.step(200);
+        second.step(200);
+
+        let settled = positions(&first);
+        assert_eq!(settled, positions(&second));
+        // Repulsion has pushed the nodes apart
+        let closest = settled
+            .iter()
+            .enumerate()
+            .flat_map(|(i, (_, a))| settled[i + 1..].iter().map(move |(_, b)| (a, b)))
+            .map(|(a, b)| ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt())
+            .fold(f32::MAX, f32::min);
+        assert!(closest > 60.0, "closest pair {}", closest);
+    }
+
+    #[test]
+    fn test_drag_and_pin() {
+        let mut engine = init(
+            vec![node(0, 0.0, 0.0, "a"), node(1, 50.0, 0.0, "a")],
+            vec![],
+        );
+        let target = Position { x: 300.0, y: 300.0 };
+
+        // Moving without a grab is ignored
+        engine.handle(PhysicsCommand::MoveTo {
+            id: NodeId(1),
+            position: target,
+        });
+        assert_ne!(positions(&engine)[1].1, target);
+
+        engine.handle(PhysicsCommand::Grab { id: NodeId(1) });
+        assert_eq!(engine.energy(), None);
+        engine.handle(PhysicsCommand::MoveTo {
+            id: NodeId(1),
+            position: target,
+        });
+        engine.handle(PhysicsCommand::SetPinned {
+            id: NodeId(1),
+            pinned: true,
+        });
+        engine.handle(PhysicsCommand::Release { id: NodeId(1) });
+        assert!(engine.energy().is_some());
+
+        // The pinned node stays where it was dropped
+        engine.step(60);
+        let pinned = positions(&engine)[1].1;
+        assert!((pinned.x - target.x).abs() < 1e-3 && (pinned.y - target.y).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_order_follows_added_and_removed_nodes() {
+        let mut engine = init(
+            vec![node(1, 0.0, 0.0, "a"), node(2, 100.0, 0.0, "a")],
+            vec![link(1, 2)],
+        );
+        let mut mirror = NodeOrder::default();
+        let commands = [
+            PhysicsCommand::AddNode {
+                node: node(3, 0.0, 100.0, "a"),
+            },
+            // Already in the graph, so nothing changes
+            PhysicsCommand::AddNode {
+                node: node(3, 50.0, 50.0, "a"),
+            },
+            PhysicsCommand::AddEdge { edge: link(3, 1) },
+            PhysicsCommand::Grab { id: NodeId(1) },
+            PhysicsCommand::RemoveNode { id: NodeId(1) },
+        ];
+        mirror.apply(&PhysicsCommand::Init {
+            nodes: vec![node(1, 0.0, 0.0, "a"), node(2, 100.0, 0.0, "a")],
+            edges: vec![],
+            settings: ForceSettings::default(),
+            center: Position::default(),
+            start_settled: false,
+        });
+        for command in commands {
+            mirror.apply(&command);
+            engine.handle(command);
+        }
+
+        assert_eq!(engine.order(), [NodeId(2), NodeId(3)]);
+        assert_eq!(mirror.ids(), engine.order());
+        assert_eq!(mirror.generation(), engine.generation());
+        // The removed node's grab no longer keeps the simulation awake
+        assert!(engine.energy().is_some());
+        assert_eq!(positions(&engine)[1].1, Position { x: 0.0, y: 100.0 });
+    }
+
+    #[test]
+    fn test_command_serialises_with_type_tag() {
+        let json = serde_json::to_value(PhysicsCommand::Grab { id: NodeId(3) }).unwrap();
+        assert_eq!(json, serde_json::json!({ "type": "Grab", "id": 3 }));
+    }
+}
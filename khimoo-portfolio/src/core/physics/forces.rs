@@ -0,0 +1,324 @@
+use super::quadtree::{Body, Interaction, QuadTree};
+
+/// Softening added to the distance in the category attraction, so nearby nodes
+/// are not pulled together with unbounded force
+pub const ATTRACTION_SOFTENING: f32 = 50.0;
+
+/// Below this many bodies building the tree costs more than it saves,
+/// so the exact sums are used instead
+pub const EXACT_BELOW: usize = 256;
+
+/// Repulsion pushing overlapping nodes apart
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RepulsionParams {
+    pub strength: f32,
+    /// Gap to keep between node edges; no force acts beyond it
+    pub min_distance: f32,
+}
+
+impl RepulsionParams {
+    /// Force magnitude between nodes of the given radii at a centre distance
+    /// Strongest when the nodes touch, falling linearly to zero at the gap.
+    pub fn magnitude(&self, distance: f32, radius1: f32, radius2: f32) -> f32 {
+        let min_distance = radius1 + radius2 + self.min_distance;
+        if distance < 1.0 || distance >= min_distance {
+            return 0.0;
+        }
+        self.strength * (min_distance - distance) / min_distance
+    }
+
+    fn force_on(&self, body: &Body, other: &Interaction) -> (f32, f32) {
+        let magnitude = self.magnitude(other.distance, body.radius, other.radius) * other.count;
+        if magnitude == 0.0 {
+            return (0.0, 0.0);
+        }
+        (
+            -other.dx / other.distance * magnitude,
+            -other.dy / other.distance * magnitude,
+        )
+    }
+}
+
+/// Attraction between nodes of the same category
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AttractionParams {
+    pub strength: f32,
+    /// Centre distance beyond which nodes do not attract
+    pub range: f32,
+}
+
+impl AttractionParams {
+    /// Force magnitude at a centre distance
+    pub fn magnitude(&self, distance: f32) -> f32 {
+        if distance <= 0.0 || distance >= self.range {
+            return 0.0;
+        }
+        self.strength / (distance + ATTRACTION_SOFTENING)
+    }
+
+    fn force_on(&self, other: &Interaction) -> (f32, f32) {
+        let magnitude = self.magnitude(other.distance) * other.count;
+        if magnitude == 0.0 {
+            return (0.0, 0.0);
+        }
+        (
+            other.dx / other.distance * magnitude,
+            other.dy / other.distance * magnitude,
+        )
+    }
+}
+
+/// Net repulsion on every body, approximated with a Barnes–Hut tree
+/// `theta` trades accuracy for speed; 0 is exact, around 0.5–1.0 is typical.
+pub fn repulsion_forces(bodies: &[Body], params: &RepulsionParams, theta: f32) -> Vec<(f32, f32)> {
+    if bodies.len() < EXACT_BELOW {
+        return repulsion_forces_exact(bodies, params);
+    }
+    let tree = QuadTree::build(bodies);
+    // Nothing acts beyond both radii plus the gap
+    let reach = tree.max_radius();
+    sum_forces(bodies, |index, forces| {
+        let body = &bodies[index];
+        let cutoff = body.radius + reach + params.min_distance;
+        tree.for_each_interaction(index, theta, cutoff, |other| {
+            add(forces, params.force_on(body, &other));
+        });
+    })
+}
+
+/// Net repulsion on every body by summing over all pairs, O(n²)
+/// Reference for `repulsion_forces` in tests and benchmarks.
+pub fn repulsion_forces_exact(bodies: &[Body], params: &RepulsionParams) -> Vec<(f32, f32)> {
+    sum_forces(bodies, |index, forces| {
+        let body = &bodies[index];
+        for_each_pair(bodies, index, |other| {
+            add(forces, params.force_on(body, &other));
+        });
+    })
+}
+
+/// Net category attraction on every body, approximated with a Barnes–Hut tree
+/// All bodies are expected to belong to the same category.
+pub fn attraction_forces(
+    bodies: &[Body],
+    params: &AttractionParams,
+    theta: f32,
+) -> Vec<(f32, f32)> {
+    if bodies.len() < EXACT_BELOW {
+        return attraction_forces_exact(bodies, params);
+    }
+    let tree = QuadTree::build(bodies);
+    sum_forces(bodies, |index, forces| {
+        tree.for_each_interaction(index, theta, params.range, |other| {
+            add(forces, params.force_on(&other));
+        });
+    })
+}
+
+/// Net category attraction on every body by summing over all pairs, O(n²)
+pub fn attraction_forces_exact(bodies: &[Body], params: &AttractionParams) -> Vec<(f32, f32)> {
+    sum_forces(bodies, |index, forces| {
+        for_each_pair(bodies, index, |other| {
+            add(forces, params.force_on(&other));
+        });
+    })
+}
+
+fn sum_forces<F>(bodies: &[Body], mut accumulate: F) -> Vec<(f32, f32)>
+where
+    F: FnMut(usize, &mut (f32, f32)),
+{
+    (0..bodies.len())
+        .map(|index| {
+            let mut forces = (0.0, 0.0);
+            accumulate(index, &mut forces);
+            forces
+        })
+        .collect()
+}
+
+fn for_each_pair<F: FnMut(Interaction)>(bodies: &[Body], index: usize, mut visit: F) {
+    let body = &bodies[index];
+    for (other_index, other) in bodies.iter().enumerate() {
+        if other_index == index {
+            continue;
+        }
+        let dx = other.x - body.x;
+        let dy = other.y - body.y;
+        visit(Interaction {
+            dx,
+            dy,
+            distance: (dx * dx + dy * dy).sqrt(),
+            radius: other.radius,
+            count: 1.0,
+        });
+    }
+}
+
+fn add(forces: &mut (f32, f32), force: (f32, f32)) {
+    forces.0 += force.0;
+    forces.1 += force.1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::physics::types::ForceSettings;
+
+    /// Deterministic scatter of bodies at roughly the density of the home graph
+    fn scatter(count: usize) -> Vec<Body> {
+        let mut state = 0x2545_f491_u32;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as f32 / u32::MAX as f32
+        };
+        let side = (count as f32).sqrt() * 200.0;
+        (0..count)
+            .map(|_| Body {
+                x: next() * side,
+                y: next() * side,
+                radius: 30.0 + next() * 30.0,
+            })
+            .collect()
+    }
+
+    fn total_error(approx: &[(f32, f32)], exact: &[(f32, f32)]) -> (f32, f32) {
+        let error: f32 = approx
+            .iter()
+            .zip(exact)
+            .map(|(a, e)| ((a.0 - e.0).powi(2) + (a.1 - e.1).powi(2)).sqrt())
+            .sum();
+        let magnitude: f32 = exact.iter().map(|e| (e.0 * e.0 + e.1 * e.1).sqrt()).sum();
+        (error, magnitude)
+    }
+
+    #[test]
+    fn test_repulsion_matches_exact_with_zero_theta() {
+        let bodies = scatter(300);
+        let params = RepulsionParams {
+            strength: 1000.0,
+            min_distance: 150.0,
+        };
+        let (error, magnitude) = total_error(
+            &repulsion_forces(&bodies, &params, 0.0),
+            &repulsion_forces_exact(&bodies, &params),
+        );
+        assert!(magnitude > 0.0);
+        assert!(error / magnitude < 1e-4);
+    }
+
+    /// The O(n²) loop the simulation used before the tree: it visited every
+    /// ordered pair, so each pair was pushed apart twice
+    fn baseline_pairwise(bodies: &[Body], strength: f32, min_distance: f32) -> Vec<(f32, f32)> {
+        let mut forces = vec![(0.0, 0.0); bodies.len()];
+        for (i, a) in bodies.iter().enumerate() {
+            for (j, b) in bodies.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+                let (dx, dy) = (b.x - a.x, b.y - a.y);
+                let distance = (dx * dx + dy * dy).sqrt();
+                if distance < 1.0 {
+                    continue;
+                }
+                let gap = a.radius + b.radius + min_distance;
+                if distance < gap {
+                    let magnitude = strength * (gap - distance) / gap;
+                    let (fx, fy) = (dx / distance * magnitude, dy / distance * magnitude);
+                    forces[i].0 -= fx;
+                    forces[i].1 -= fy;
+                    forces[j].0 += fx;
+                    forces[j].1 += fy;
+                }
+            }
+        }
+        forces
+    }
+
+    #[test]
+    fn test_default_repulsion_matches_baseline_pairwise_loop() {
+        // Default strength before the tree, when each pair was applied twice
+        const BASELINE_STRENGTH: f32 = 68000.0;
+        let settings = ForceSettings::default();
+        let bodies = scatter(200);
+        let params = RepulsionParams {
+            strength: settings.repulsion_strength,
+            min_distance: settings.repulsion_min_distance,
+        };
+        let (error, magnitude) = total_error(
+            &repulsion_forces_exact(&bodies, &params),
+            &baseline_pairwise(&bodies, BASELINE_STRENGTH, settings.repulsion_min_distance),
+        );
+        assert!(magnitude > 0.0);
+        assert!(error / magnitude < 1e-4, "error {}", error / magnitude);
+    }
+
+    #[test]
+    fn test_approximation_error_is_small() {
+        let bodies = scatter(1000);
+        let repulsion = RepulsionParams {
+            strength: 1000.0,
+            min_distance: 150.0,
+        };
+        let (error, magnitude) = total_error(
+            &repulsion_forces(&bodies, &repulsion, 0.7),
+            &repulsion_forces_exact(&bodies, &repulsion),
+        );
+        assert!(
+            error / magnitude < 0.1,
+            "repulsion error {}",
+            error / magnitude
+        );
+
+        let attraction = AttractionParams {
+            strength: 1500.0,
+            range: 300.0,
+        };
+        let (error, magnitude) = total_error(
+            &attraction_forces(&bodies, &attraction, 0.7),
+            &attraction_forces_exact(&bodies, &attraction),
+        );
+        assert!(
+            error / magnitude < 0.1,
+            "attraction error {}",
+            error / magnitude
+        );
+    }
+
+    #[test]
+    fn test_force_directions() {
+        let bodies = vec![
+            Body {
+                x: 0.0,
+                y: 0.0,
+                radius: 10.0,
+            },
+            Body {
+                x: 50.0,
+                y: 0.0,
+                radius: 10.0,
+            },
+        ];
+        let repulsion = repulsion_forces(
+            &bodies,
+            &RepulsionParams {
+                strength: 100.0,
+                min_distance: 100.0,
+            },
+            0.7,
+        );
+        assert!(repulsion[0].0 < 0.0 && repulsion[1].0 > 0.0);
+
+        let attraction = attraction_forces(
+            &bodies,
+            &AttractionParams {
+                strength: 100.0,
+                range: 100.0,
+            },
+            0.7,
+        );
+        assert!(attraction[0].0 > 0.0 && attraction[1].0 < 0.0);
+    }
+}
@@ -0,0 +1,373 @@
+//! Home graph construction and headless layout
+//!
+//! The web app and the CLI build the home graph the same way, so a layout
+//! settled by the CLI at build time lines up with what the browser simulates.
+
+use super::types::{Edge, ForceSettings, NodeId, PhysicsNode, Position};
+use super::world::PhysicsWorld;
+use crate::config::NodeConfig;
+use crate::core::graph::{article_edges, neighbourhood, resolve_edges, EdgeLabels};
+use crate::core::model::{ArticleIndex, GraphLayout, LayoutPoint};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Steps the CLI runs by default; ten seconds at the browser's step rate
+pub const DEFAULT_LAYOUT_STEPS: usize = 1200;
+
+/// Largest offset of the initial scatter around the centre
+pub const SCATTER_RADIUS: f32 = 80.0;
+
+/// Small pseudo-random generator (linear congruential)
+/// Deterministic so the same articles always start from the same scatter.
+pub struct SimpleRng {
+    seed: u32,
+}
+
+impl SimpleRng {
+    pub fn new(seed: u32) -> Self {
+        Self { seed }
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        self.seed = self.seed.wrapping_mul(1103515245).wrapping_add(12345);
+        self.seed
+    }
+
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u32() as f32) / (u32::MAX as f32)
+    }
+
+    pub fn next_range(&mut self, min: f32, max: f32) -> f32 {
+        min + self.next_f32() * (max - min)
+    }
+}
+
+/// Node graph shown on the home page
+/// Holds the articles with `home_display`; the one with an author image is
+/// the author node. Node ids are assigned from 1 in index order. Links going
+/// both ways are merged into one bidirectional edge; typed relations are
+/// labelled edges. The article page shows the same kind of graph around one
+/// article.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HomeGraph {
+    /// Article slug of each node, in the same order as `nodes`
+    pub slugs: Vec<String>,
+    pub nodes: Vec<PhysicsNode>,
+    pub edges: Vec<Edge>,
+    /// Relation labels of the labelled edges
+    pub edge_labels: EdgeLabels,
+}
+
+impl HomeGraph {
+    /// Build the graph with nodes scattered around `center`
+    pub fn from_index(index: &ArticleIndex, node_config: &NodeConfig, center: Position) -> Self {
+        let slugs: Vec<String> = index
+            .articles
+            .iter()
+            .filter(|article| article.metadata.home_display)
+            .map(|article| article.slug.clone())
+            .collect();
+        Self::of_articles(index, slugs, None, node_config, center)
+    }
+
+    /// The articles at most `depth` hops from `slug`, for the article page
+    /// The article itself takes the author's place at the centre; the author
+    /// keeps its size but is not anchored. Related articles are joined to it
+    /// by weak edges.
+    pub fn neighbourhood(
+        index: &ArticleIndex,
+        slug: &str,
+        depth: usize,
+        node_config: &NodeConfig,
+        center: Position,
+    ) -> Self {
+        let slugs = neighbourhood(index, slug, depth);
+        Self::of_articles(index, slugs, Some(slug), node_config, center)
+    }
+
+    // Nodes for `slugs` in index order; the centre is `focus` if given, else
+    // the author
+    fn of_articles(
+        index: &ArticleIndex,
+        slugs: Vec<String>,
+        focus: Option<&str>,
+        node_config: &NodeConfig,
+        center: Position,
+    ) -> Self {
+        let shown: HashSet<&str> = slugs.iter().map(String::as_str).collect();
+        let articles: Vec<_> = index
+            .articles
+            .iter()
+            .filter(|article| shown.contains(article.slug.as_str()))
+            .collect();
+
+        // Seeded with the article count so the scatter is reproducible
+        let mut rng = SimpleRng::new(articles.len() as u32 * 42);
+        let mut slugs = Vec::with_capacity(articles.len());
+        let mut nodes = Vec::with_capacity(articles.len());
+        let mut slug_to_id = HashMap::new();
+
+        for (index, article) in articles.iter().enumerate() {
+            let id = NodeId(index as u32 + 1);
+            let importance = Some(article.metadata.importance);
+            let has_author_image = article.metadata.author_image.is_some();
+            let is_centre = match focus {
+                Some(focus) => article.slug == focus,
+                None => has_author_image,
+            };
+            let radius = if has_author_image {
+                node_config.author_node_radius
+            } else {
+                node_config.entry_node_radius(article)
+            };
+
+            // The centre node starts at the centre, the others slightly around it
+            let position = if is_centre {
+                center
+            } else {
+                Position {
+                    x: center.x + rng.next_range(-SCATTER_RADIUS, SCATTER_RADIUS),
+                    y: center.y + rng.next_range(-SCATTER_RADIUS, SCATTER_RADIUS),
+                }
+            };
+
+            nodes.push(PhysicsNode {
+                id,
+                position,
+                radius: radius as f32,
+                collider_radius: node_config.physics_radius(radius, importance),
+                // The home graph does not cluster by category
+                category: None,
+                is_author: is_centre,
+            });
+            slugs.push(article.slug.clone());
+            slug_to_id.insert(article.slug.as_str(), id);
+        }
+
+        let expanded: Vec<String> = focus.map(str::to_string).into_iter().collect();
+        let (edges, edge_labels) =
+            resolve_edges(&article_edges(index, &slugs, &expanded), |slug| {
+                slug_to_id.get(slug).copied()
+            });
+
+        Self {
+            slugs,
+            nodes,
+            edges,
+            edge_labels,
+        }
+    }
+
+    /// Place nodes at their precomputed positions, with the layout's centre
+    /// at `center`. Returns whether every node had a position.
+    pub fn apply_layout(&mut self, layout: &GraphLayout, center: Position) -> bool {
+        let mut complete = true;
+        for (slug, node) in self.slugs.iter().zip(&mut self.nodes) {
+            match layout.positions.get(slug) {
+                Some(point) => {
+                    node.position = Position {
+                        x: center.x + (point.x - layout.center.x) * layout.extent,
+                        y: center.y + (point.y - layout.center.y) * layout.extent,
+                    };
+                }
+                None => complete = false,
+            }
+        }
+        complete
+    }
+
+    /// Run the simulation for `steps` steps and return the settled layout
+    pub fn settle(&self, settings: ForceSettings, steps: usize) -> GraphLayout {
+        let center = Position::default();
+        let mut world = PhysicsWorld::new(&self.nodes, &self.edges, settings, center);
+        for _ in 0..steps {
+            world.step();
+        }
+
+        let positions: Vec<Position> = world.positions().map(|(_, pos)| pos).collect();
+        let unit_box = UnitBox::fit(&positions);
+        GraphLayout {
+            steps,
+            extent: unit_box.extent,
+            center: unit_box.map(center),
+            positions: self
+                .slugs
+                .iter()
+                .cloned()
+                .zip(positions.into_iter().map(|pos| unit_box.map(pos)))
+                .collect::<BTreeMap<_, _>>(),
+        }
+    }
+}
+
+/// Uniform scaling that fits points into the unit box, centring the shorter side
+struct UnitBox {
+    min: Position,
+    extent: f32,
+    offset: Position,
+}
+
+impl UnitBox {
+    fn fit(points: &[Position]) -> Self {
+        let Some(first) = points.first() else {
+            return Self {
+                min: Position::default(),
+                extent: 1.0,
+                offset: Position { x: 0.5, y: 0.5 },
+            };
+        };
+        let (min, max) = points.iter().fold((*first, *first), |(min, max), p| {
+            (
+                Position {
+                    x: min.x.min(p.x),
+                    y: min.y.min(p.y),
+                },
+                Position {
+                    x: max.x.max(p.x),
+                    y: max.y.max(p.y),
+                },
+            )
+        });
+        let width = max.x - min.x;
+        let height = max.y - min.y;
+        let extent = width.max(height).max(1.0);
+        Self {
+            min,
+            extent,
+            offset: Position {
+                x: (1.0 - width / extent) / 2.0,
+                y: (1.0 - height / extent) / 2.0,
+            },
+        }
+    }
+
+    fn map(&self, p: Position) -> LayoutPoint {
+        LayoutPoint {
+            x: (p.x - self.min.x) / self.extent + self.offset.x,
+            y: (p.y - self.min.y) / self.extent + self.offset.y,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::graph::GraphAnalysis;
+    use crate::core::model::ArticleIndexEntry;
+    use crate::core::physics::ConnectionLineType;
+
+    fn index() -> ArticleIndex {
+        let entry =
+            |slug: &str, links: &[&str]| ArticleIndexEntry::for_test(slug).home().links(links);
+        let mut index = ArticleIndex::empty();
+        index.articles = vec![
+            entry("about", &["rust", "nix"]).author(),
+            entry("rust", &["nix", "missing"]),
+            entry("nix", &["rust"]),
+            entry("topology", &["about"]),
+        ];
+        index
+    }
+
+    #[test]
+    fn test_home_graph_matches_index() {
+        let graph = HomeGraph::from_index(&index(), &NodeConfig::default(), Position::default());
+        assert_eq!(graph.slugs, ["about", "rust", "nix", "topology"]);
+        assert!(graph.nodes[0].is_author);
+        assert_eq!(graph.nodes[0].position, Position::default());
+        // Links to articles outside the graph are dropped and links going
+        // both ways are merged
+        let edge = |from, to, kind| Edge::new(NodeId(from), NodeId(to), kind);
+        assert_eq!(
+            graph.edges,
+            [
+                edge(1, 2, ConnectionLineType::AuthorToArticle),
+                edge(1, 3, ConnectionLineType::AuthorToArticle),
+                edge(2, 3, ConnectionLineType::Bidirectional),
+                edge(4, 1, ConnectionLineType::DirectLink),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_nodes_are_sized_by_centrality_only_when_the_index_opts_in() {
+        let mut index = index();
+        GraphAnalysis::of(&index).apply(&mut index);
+        let radius = |index: &ArticleIndex, slug: &str| {
+            let config = NodeConfig::default().for_index(index);
+            let graph = HomeGraph::from_index(index, &config, Position::default());
+            let at = graph.slugs.iter().position(|s| s == slug).unwrap();
+            graph.nodes[at].radius
+        };
+        // Every article has the default importance, so without the switch
+        // the metrics leave the sizes alone
+        assert_eq!(radius(&index, "rust"), radius(&index, "topology"));
+
+        index.size_by_centrality = true;
+        assert!(radius(&index, "rust") > radius(&index, "topology"));
+    }
+
+    #[test]
+    fn test_neighbourhood_graph_centres_the_article() {
+        let graph = HomeGraph::neighbourhood(
+            &index(),
+            "rust",
+            1,
+            &NodeConfig::default(),
+            Position::default(),
+        );
+        assert_eq!(graph.slugs, ["about", "rust", "nix"]);
+        let centres: Vec<bool> = graph.nodes.iter().map(|node| node.is_author).collect();
+        assert_eq!(centres, [false, true, false]);
+        assert_eq!(graph.nodes[1].position, Position::default());
+        assert_eq!(graph.edges.len(), 3);
+    }
+
+    #[test]
+    fn test_settled_layout_is_deterministic_and_normalised() {
+        let graph = HomeGraph::from_index(&index(), &NodeConfig::default(), Position::default());
+        let layout = graph.settle(ForceSettings::default(), 300);
+        assert_eq!(layout, graph.settle(ForceSettings::default(), 300));
+
+        assert_eq!(layout.positions.len(), 4);
+        for point in layout.positions.values().chain([&layout.center]) {
+            assert!((0.0..=1.0).contains(&point.x) && (0.0..=1.0).contains(&point.y));
+        }
+        // The longer side spans the whole box
+        let xs = layout.positions.values().map(|p| p.x);
+        let ys = layout.positions.values().map(|p| p.y);
+        let span = |values: Vec<f32>| {
+            values.iter().cloned().fold(f32::MIN, f32::max)
+                - values.iter().cloned().fold(f32::MAX, f32::min)
+        };
+        let longest = span(xs.collect()).max(span(ys.collect()));
+        assert!((longest - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_apply_layout_restores_settled_positions() {
+        let graph = HomeGraph::from_index(&index(), &NodeConfig::default(), Position::default());
+        let layout = graph.settle(ForceSettings::default(), 300);
+
+        let mut world = PhysicsWorld::new(
+            &graph.nodes,
+            &graph.edges,
+            ForceSettings::default(),
+            Position::default(),
+        );
+        for _ in 0..300 {
+            world.step();
+        }
+
+        let center = Position { x: 400.0, y: 300.0 };
+        let mut placed = graph.clone();
+        assert!(placed.apply_layout(&layout, center));
+        for ((_, settled), node) in world.positions().zip(&placed.nodes) {
+            assert!((settled.x + center.x - node.position.x).abs() < 0.1);
+            assert!((settled.y + center.y - node.position.y).abs() < 0.1);
+        }
+
+        let mut partial = layout.clone();
+        partial.positions.remove("nix");
+        assert!(!graph.clone().apply_layout(&partial, center));
+    }
+}
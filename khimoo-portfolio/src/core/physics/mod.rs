@@ -0,0 +1,27 @@
+//! Physics module
+//!
+//! Platform-independent simulation of the node graph: force calculations,
+//! the Rapier world and the command-driven engine the web app runs in a
+//! worker. Nothing here depends on the browser, so it can be tested and
+//! benchmarked natively.
+
+pub mod engine;
+pub mod forces;
+pub mod layout;
+pub mod quadtree;
+pub mod stepper;
+pub mod types;
+pub mod world;
+
+pub use engine::{unpack_positions, NodeOrder, PhysicsCommand, PhysicsEngine};
+pub use forces::{
+    attraction_forces, attraction_forces_exact, repulsion_forces, repulsion_forces_exact,
+    AttractionParams, RepulsionParams,
+};
+pub use layout::{HomeGraph, SimpleRng, DEFAULT_LAYOUT_STEPS};
+pub use quadtree::{Body, Interaction, QuadTree};
+pub use stepper::{FixedTimestep, SettleDetector};
+pub use types::{
+    merge_reciprocal, ConnectionLineType, Edge, ForceSettings, NodeId, PhysicsNode, Position,
+};
+pub use world::PhysicsWorld;
@@ -0,0 +1,278 @@
+/// Bodies per leaf before the leaf is split
+const LEAF_CAPACITY: usize = 4;
+/// Depth limit; stops splitting when many bodies share the same point
+const MAX_DEPTH: usize = 16;
+
+/// A node of the force simulation as seen by the tree
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Body {
+    pub x: f32,
+    pub y: f32,
+    pub radius: f32,
+}
+
+/// One body, or an aggregate of distant bodies, acting on a body
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interaction {
+    /// Offset from the body to the other body (or centre of mass)
+    pub dx: f32,
+    pub dy: f32,
+    pub distance: f32,
+    /// Radius of the other body; the mean radius for an aggregate
+    pub radius: f32,
+    /// Number of bodies this interaction stands for
+    pub count: f32,
+}
+
+#[derive(Debug, Clone)]
+struct Cell {
+    min_x: f32,
+    min_y: f32,
+    size: f32,
+    count: usize,
+    sum_x: f32,
+    sum_y: f32,
+    sum_radius: f32,
+    /// Index of the first of four consecutive children; None for leaves
+    children: Option<usize>,
+    bodies: Vec<usize>,
+}
+
+impl Cell {
+    fn new(min_x: f32, min_y: f32, size: f32) -> Self {
+        Self {
+            min_x,
+            min_y,
+            size,
+            count: 0,
+            sum_x: 0.0,
+            sum_y: 0.0,
+            sum_radius: 0.0,
+            children: None,
+            bodies: Vec::new(),
+        }
+    }
+
+    fn quadrant(&self, body: &Body) -> usize {
+        let half = self.size / 2.0;
+        let right = body.x >= self.min_x + half;
+        let bottom = body.y >= self.min_y + half;
+        (right as usize) | ((bottom as usize) << 1)
+    }
+
+    fn contains(&self, x: f32, y: f32) -> bool {
+        x >= self.min_x
+            && x <= self.min_x + self.size
+            && y >= self.min_y
+            && y <= self.min_y + self.size
+    }
+
+    /// Distance from a point to the cell's square (0 inside)
+    fn distance_to(&self, x: f32, y: f32) -> f32 {
+        let dx = (self.min_x - x).max(x - (self.min_x + self.size)).max(0.0);
+        let dy = (self.min_y - y).max(y - (self.min_y + self.size)).max(0.0);
+        (dx * dx + dy * dy).sqrt()
+    }
+}
+
+/// Barnes–Hut quadtree over a set of bodies
+/// Distant groups of bodies are treated as a single body at their centre of mass,
+/// which brings force evaluation from O(n²) down to about O(n log n).
+pub struct QuadTree<'a> {
+    bodies: &'a [Body],
+    cells: Vec<Cell>,
+    max_radius: f32,
+}
+
+impl<'a> QuadTree<'a> {
+    /// Build a tree over the bodies
+    pub fn build(bodies: &'a [Body]) -> Self {
+        let (mut min_x, mut min_y) = (f32::INFINITY, f32::INFINITY);
+        let (mut max_x, mut max_y) = (f32::NEG_INFINITY, f32::NEG_INFINITY);
+        let mut max_radius: f32 = 0.0;
+        for body in bodies {
+            min_x = min_x.min(body.x);
+            min_y = min_y.min(body.y);
+            max_x = max_x.max(body.x);
+            max_y = max_y.max(body.y);
+            max_radius = max_radius.max(body.radius);
+        }
+        let root = if bodies.is_empty() {
+            Cell::new(0.0, 0.0, 1.0)
+        } else {
+            Cell::new(min_x, min_y, (max_x - min_x).max(max_y - min_y).max(1.0))
+        };
+
+        let mut tree = Self {
+            bodies,
+            cells: vec![root],
+            max_radius,
+        };
+        for index in 0..bodies.len() {
+            tree.insert(0, index, 0);
+        }
+        tree
+    }
+
+    /// Largest body radius in the tree
+    pub fn max_radius(&self) -> f32 {
+        self.max_radius
+    }
+
+    fn insert(&mut self, cell_index: usize, body_index: usize, depth: usize) {
+        let body = self.bodies[body_index];
+        let cell = &mut self.cells[cell_index];
+        cell.count += 1;
+        cell.sum_x += body.x;
+        cell.sum_y += body.y;
+        cell.sum_radius += body.radius;
+
+        if let Some(first_child) = cell.children {
+            let child = first_child + cell.quadrant(&body);
+            self.insert(child, body_index, depth + 1);
+            return;
+        }
+
+        cell.bodies.push(body_index);
+        if cell.bodies.len() > LEAF_CAPACITY && depth < MAX_DEPTH {
+            self.split(cell_index, depth);
+        }
+    }
+
+    fn split(&mut self, cell_index: usize, depth: usize) {
+        let first_child = self.cells.len();
+        let (min_x, min_y, half) = {
+            let cell = &self.cells[cell_index];
+            (cell.min_x, cell.min_y, cell.size / 2.0)
+        };
+        for quadrant in 0..4 {
+            let x = min_x + if quadrant & 1 == 1 { half } else { 0.0 };
+            let y = min_y + if quadrant & 2 == 2 { half } else { 0.0 };
+            self.cells.push(Cell::new(x, y, half));
+        }
+
+        let cell = &mut self.cells[cell_index];
+        cell.children = Some(first_child);
+        let bodies = std::mem::take(&mut cell.bodies);
+        for body_index in bodies {
+            let child = first_child + self.cells[cell_index].quadrant(&self.bodies[body_index]);
+            self.insert(child, body_index, depth + 1);
+        }
+    }
+
+    /// Call `visit` for everything acting on body `index` within `cutoff`
+    /// A cell whose size seen from the body is below `theta` is visited as one
+    /// aggregate; `theta = 0` visits every body individually (exact).
+    pub fn for_each_interaction<F>(&self, index: usize, theta: f32, cutoff: f32, mut visit: F)
+    where
+        F: FnMut(Interaction),
+    {
+        let Some(body) = self.bodies.get(index) else {
+            return;
+        };
+        let mut stack = vec![0usize];
+
+        while let Some(cell_index) = stack.pop() {
+            let cell = &self.cells[cell_index];
+            if cell.count == 0 || cell.distance_to(body.x, body.y) > cutoff {
+                continue;
+            }
+
+            match cell.children {
+                None => {
+                    for &other_index in &cell.bodies {
+                        if other_index == index {
+                            continue;
+                        }
+                        let other = &self.bodies[other_index];
+                        let dx = other.x - body.x;
+                        let dy = other.y - body.y;
+                        let distance = (dx * dx + dy * dy).sqrt();
+                        if distance > cutoff {
+                            continue;
+                        }
+                        visit(Interaction {
+                            dx,
+                            dy,
+                            distance,
+                            radius: other.radius,
+                            count: 1.0,
+                        });
+                    }
+                }
+                Some(first_child) => {
+                    let count = cell.count as f32;
+                    let dx = cell.sum_x / count - body.x;
+                    let dy = cell.sum_y / count - body.y;
+                    let distance = (dx * dx + dy * dy).sqrt();
+
+                    // A cell containing the body is never aggregated, so a body
+                    // does not act on itself
+                    if !cell.contains(body.x, body.y) && cell.size < theta * distance {
+                        visit(Interaction {
+                            dx,
+                            dy,
+                            distance,
+                            radius: cell.sum_radius / count,
+                            count,
+                        });
+                    } else {
+                        stack.extend(first_child..first_child + 4);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn body(x: f32, y: f32) -> Body {
+        Body { x, y, radius: 1.0 }
+    }
+
+    #[test]
+    fn test_exact_traversal_visits_every_other_body() {
+        let bodies: Vec<Body> = (0..50)
+            .map(|i| body((i % 7) as f32 * 13.0, (i / 7) as f32 * 11.0))
+            .collect();
+        let tree = QuadTree::build(&bodies);
+
+        let mut visited = 0.0;
+        tree.for_each_interaction(3, 0.0, f32::INFINITY, |interaction| {
+            visited += interaction.count;
+        });
+        assert_eq!(visited, 49.0);
+    }
+
+    #[test]
+    fn test_aggregate_far_cluster() {
+        let mut bodies = vec![body(0.0, 0.0)];
+        bodies.extend((0..20).map(|i| body(1000.0 + (i % 5) as f32, 1000.0 + (i / 5) as f32)));
+        let tree = QuadTree::build(&bodies);
+
+        let mut interactions = Vec::new();
+        tree.for_each_interaction(0, 0.5, f32::INFINITY, |i| interactions.push(i));
+        let total: f32 = interactions.iter().map(|i| i.count).sum();
+        assert_eq!(total, 20.0);
+        // The distant cluster is seen as far fewer interactions than bodies
+        assert!(interactions.len() < 20);
+    }
+
+    #[test]
+    fn test_coincident_bodies_and_cutoff() {
+        let bodies = vec![body(5.0, 5.0); 100];
+        let tree = QuadTree::build(&bodies);
+        let mut visited = 0;
+        tree.for_each_interaction(0, 0.7, 10.0, |_| visited += 1);
+        assert_eq!(visited, 99);
+
+        let far = vec![body(0.0, 0.0), body(500.0, 0.0)];
+        let tree = QuadTree::build(&far);
+        let mut visited = 0;
+        tree.for_each_interaction(0, 0.7, 100.0, |_| visited += 1);
+        assert_eq!(visited, 0);
+    }
+}
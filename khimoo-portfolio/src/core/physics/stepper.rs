@@ -0,0 +1,121 @@
+/// Most simulation steps run in one frame; time beyond this is dropped so a
+/// slow frame or a long pause does not trigger a burst of catch-up steps
+pub const MAX_STEPS_PER_FRAME: usize = 8;
+
+/// Fixed-timestep accumulator
+/// Frames arrive at whatever rate the display runs at; this turns elapsed
+/// frame time into a whole number of fixed simulation steps.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FixedTimestep {
+    step_ms: f64,
+    max_steps: usize,
+    accumulator: f64,
+}
+
+impl FixedTimestep {
+    /// Create an accumulator for the given simulation rate
+    pub fn new(steps_per_second: u32) -> Self {
+        Self {
+            step_ms: 1000.0 / steps_per_second.max(1) as f64,
+            max_steps: MAX_STEPS_PER_FRAME,
+            accumulator: 0.0,
+        }
+    }
+
+    /// Length of one step in milliseconds
+    pub fn step_ms(&self) -> f64 {
+        self.step_ms
+    }
+
+    /// Add elapsed time and return the number of steps to run
+    pub fn advance(&mut self, elapsed_ms: f64) -> usize {
+        self.accumulator += elapsed_ms.max(0.0);
+        let steps = (self.accumulator / self.step_ms) as usize;
+        if steps > self.max_steps {
+            self.accumulator = 0.0;
+            return self.max_steps;
+        }
+        self.accumulator -= steps as f64 * self.step_ms;
+        steps
+    }
+
+    /// Discard leftover time, e.g. when resuming after a pause
+    pub fn reset(&mut self) {
+        self.accumulator = 0.0;
+    }
+}
+
+/// Detects when a simulation has come to rest
+/// The simulation counts as settled once its kinetic energy has stayed below
+/// the threshold for a number of consecutive observations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SettleDetector {
+    threshold: f32,
+    required: usize,
+    quiet: usize,
+}
+
+impl SettleDetector {
+    pub fn new(threshold: f32, required: usize) -> Self {
+        Self {
+            threshold,
+            required: required.max(1),
+            quiet: 0,
+        }
+    }
+
+    /// Record the current kinetic energy; returns whether the simulation is settled
+    pub fn observe(&mut self, energy: f32) -> bool {
+        if energy < self.threshold {
+            self.quiet += 1;
+        } else {
+            self.quiet = 0;
+        }
+        self.is_settled()
+    }
+
+    pub fn is_settled(&self) -> bool {
+        self.quiet >= self.required
+    }
+
+    /// Forget past observations, e.g. after the simulation was disturbed
+    pub fn reset(&mut self) {
+        self.quiet = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_timestep_carries_remainder() {
+        let mut timestep = FixedTimestep::new(100);
+        assert_eq!(timestep.advance(16.0), 1);
+        assert_eq!(timestep.advance(16.0), 2);
+        assert_eq!(timestep.advance(8.0), 1);
+        assert_eq!(timestep.advance(0.0), 0);
+    }
+
+    #[test]
+    fn test_fixed_timestep_clamps_long_pause() {
+        let mut timestep = FixedTimestep::new(120);
+        assert_eq!(timestep.advance(5000.0), MAX_STEPS_PER_FRAME);
+        // The dropped time does not carry over
+        assert_eq!(timestep.advance(1.0), 0);
+    }
+
+    #[test]
+    fn test_settle_detector_needs_consecutive_quiet_frames() {
+        let mut detector = SettleDetector::new(1.0, 3);
+        assert!(!detector.observe(0.5));
+        assert!(!detector.observe(0.5));
+        assert!(!detector.observe(2.0));
+        assert!(!detector.observe(0.5));
+        assert!(!detector.observe(0.5));
+        assert!(detector.observe(0.5));
+
+        detector.reset();
+        assert!(!detector.is_settled());
+    }
+}
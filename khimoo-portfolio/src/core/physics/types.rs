@@ -0,0 +1,189 @@
+use serde::{Deserialize, Serialize};
+
+/// Identifier of a node in the graph
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct NodeId(pub u32);
+
+/// Point in world coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Position {
+    pub x: f32,
+    pub y: f32,
+}
+
+/// Tunable forces of the graph simulation
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ForceSettings {
+    /// Applied once per pair; twice the value the old all-ordered-pairs loop
+    /// used, since that loop pushed every pair apart twice
+    pub repulsion_strength: f32,
+    pub repulsion_min_distance: f32,
+    pub author_repulsion_min_distance: f32,
+    pub link_strength: f32,
+    pub center_strength: f32,
+    pub center_damping: f32,
+    pub direct_link_strength: f32,
+    pub direct_link_damping: f32,
+    pub debug_mode: bool,
+    pub show_connection_lines: bool,
+    pub category_attraction_strength: f32,
+    pub category_attraction_range: f32,
+    pub enable_category_clustering: bool,
+    /// Barnes–Hut accuracy; 0 is exact, larger is faster and coarser
+    pub barnes_hut_theta: f32,
+}
+
+impl Default for ForceSettings {
+    fn default() -> Self {
+        Self {
+            repulsion_strength: 136000.0,
+            repulsion_min_distance: 150.0,
+            author_repulsion_min_distance: 150.0,
+            link_strength: 5000.0,
+            center_strength: 6000.0,
+            center_damping: 5.0,
+            direct_link_strength: 8000.0,
+            direct_link_damping: 300.0,
+            debug_mode: false,
+            show_connection_lines: true,
+            category_attraction_strength: 1500.0,
+            category_attraction_range: 300.0,
+            enable_category_clustering: true,
+            barnes_hut_theta: 0.7,
+        }
+    }
+}
+
+/// How two nodes are connected
+/// Decides both the spring strength and how the edge is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum ConnectionLineType {
+    /// A link from one article to another
+    #[default]
+    DirectLink,
+    /// Articles linking to each other
+    Bidirectional,
+    AuthorToArticle,
+    Strong,
+    Medium,
+    /// Looser association such as a related article
+    Weak,
+}
+
+impl ConnectionLineType {
+    /// Multiplier of the link strength and line width
+    pub fn weight(self) -> f32 {
+        match self {
+            ConnectionLineType::DirectLink => 1.0,
+            ConnectionLineType::Bidirectional => 1.5,
+            ConnectionLineType::AuthorToArticle => 1.0,
+            ConnectionLineType::Strong => 1.25,
+            ConnectionLineType::Medium => 0.75,
+            ConnectionLineType::Weak => 0.4,
+        }
+    }
+
+    /// Whether the edge points from one node to the other
+    pub fn is_directed(self) -> bool {
+        self != ConnectionLineType::Bidirectional
+    }
+}
+
+/// Edge of the node graph
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Edge {
+    pub from: NodeId,
+    pub to: NodeId,
+    pub kind: ConnectionLineType,
+    /// Scales the kind's weight, usually in 0..=1
+    pub strength: f32,
+}
+
+impl Edge {
+    pub fn new(from: NodeId, to: NodeId, kind: ConnectionLineType) -> Self {
+        Self {
+            from,
+            to,
+            kind,
+            strength: 1.0,
+        }
+    }
+
+    pub fn with_strength(self, strength: f32) -> Self {
+        Self { strength, ..self }
+    }
+
+    pub fn key(&self) -> (NodeId, NodeId) {
+        (self.from, self.to)
+    }
+
+    /// Multiplier of the link strength
+    pub fn weight(&self) -> f32 {
+        self.kind.weight() * self.strength
+    }
+}
+
+/// Drop duplicate edges and merge links going both ways into one
+/// `Bidirectional` edge, kept where the first of the two appeared
+pub fn merge_reciprocal(edges: impl IntoIterator<Item = Edge>) -> Vec<Edge> {
+    let mut merged: Vec<Edge> = Vec::new();
+    for edge in edges {
+        if edge.from == edge.to || merged.iter().any(|other| other.key() == edge.key()) {
+            continue;
+        }
+        match merged
+            .iter_mut()
+            .find(|other| other.key() == (edge.to, edge.from))
+        {
+            Some(other) => {
+                other.kind = ConnectionLineType::Bidirectional;
+                other.strength = other.strength.max(edge.strength);
+            }
+            None => merged.push(edge),
+        }
+    }
+    merged
+}
+
+/// A node as seen by the simulation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhysicsNode {
+    pub id: NodeId,
+    pub position: Position,
+    /// Drawn radius, used for the repulsion range
+    pub radius: f32,
+    /// Radius of the collider, usually somewhat larger than the drawn one
+    pub collider_radius: f32,
+    pub category: Option<String>,
+    /// The author node is pulled to the centre and never clusters
+    pub is_author: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn link(from: u32, to: u32) -> Edge {
+        Edge::new(NodeId(from), NodeId(to), ConnectionLineType::DirectLink)
+    }
+
+    #[test]
+    fn test_merge_reciprocal_links() {
+        let merged = merge_reciprocal([
+            link(1, 2),
+            link(2, 3),
+            link(1, 2),
+            link(2, 1).with_strength(2.0),
+            link(3, 3),
+        ]);
+        assert_eq!(
+            merged,
+            [
+                Edge::new(NodeId(1), NodeId(2), ConnectionLineType::Bidirectional)
+                    .with_strength(2.0),
+                link(2, 3),
+            ]
+        );
+        assert!(!merged[0].kind.is_directed());
+    }
+}
@@ -0,0 +1,745 @@
+use super::forces::{attraction_forces, repulsion_forces, AttractionParams, RepulsionParams};
+use super::quadtree::Body;
+use super::types::{Edge, ForceSettings, NodeId, PhysicsNode, Position};
+use rapier2d::prelude::*;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Simulated time per step in seconds
+const STEP_DT: f32 = 1.0 / 12.0;
+
+/// Share of the remaining distance to its layout target a node covers per step
+const LAYOUT_EASING: f32 = 0.2;
+
+/// What the force pass needs to know about a node besides its body
+#[derive(Debug, Clone)]
+struct NodeInfo {
+    id: NodeId,
+    radius: f32,
+    category: Option<String>,
+}
+
+/// Rapier world of the node graph
+///
+/// Owns the node positions; callers read them back after each `step` instead
+/// of sharing state with the UI, so the world can run natively, on the main
+/// thread or in a worker. Nodes are kept in insertion order, which makes runs
+/// with the same input reproducible. Nodes and edges can be added and removed
+/// while the simulation runs; everything else keeps its position and velocity.
+pub struct PhysicsWorld {
+    gravity: Vector<f32>,
+    integration_parameters: IntegrationParameters,
+    island_manager: IslandManager,
+    broad_phase: DefaultBroadPhase,
+    narrow_phase: NarrowPhase,
+    bodies: RigidBodySet,
+    colliders: ColliderSet,
+    impulse_joints: ImpulseJointSet,
+    multibody_joints: MultibodyJointSet,
+    ccd_solver: CCDSolver,
+    nodes: Vec<NodeInfo>,
+    body_map: HashMap<NodeId, RigidBodyHandle>,
+    author: Option<NodeId>,
+    /// Edges in insertion order, without duplicates
+    edges: Vec<Edge>,
+    /// Spring of each edge whose nodes are both in the world
+    edge_joint_handles: HashMap<(NodeId, NodeId), ImpulseJointHandle>,
+    pinned: HashSet<NodeId>,
+    force_settings: ForceSettings,
+    /// Point the author node is pulled towards
+    center: Position,
+    /// Fixed layout the nodes ease towards instead of being simulated
+    layout_targets: Option<HashMap<NodeId, Position>>,
+    /// Kinetic energy per unit mass of the last eased step
+    layout_energy: f32,
+}
+
+impl PhysicsWorld {
+    pub fn new(
+        nodes: &[PhysicsNode],
+        edges: &[Edge],
+        force_settings: ForceSettings,
+        center: Position,
+    ) -> Self {
+        let mut world = Self {
+            gravity: vector![0.0, 0.0],
+            integration_parameters: IntegrationParameters {
+                dt: STEP_DT,
+                ..IntegrationParameters::default()
+            },
+            island_manager: IslandManager::new(),
+            broad_phase: DefaultBroadPhase::new(),
+            narrow_phase: NarrowPhase::new(),
+            bodies: RigidBodySet::new(),
+            colliders: ColliderSet::new(),
+            impulse_joints: ImpulseJointSet::new(),
+            multibody_joints: MultibodyJointSet::new(),
+            ccd_solver: CCDSolver::new(),
+            nodes: Vec::with_capacity(nodes.len()),
+            body_map: HashMap::with_capacity(nodes.len()),
+            author: None,
+            edges: Vec::with_capacity(edges.len()),
+            edge_joint_handles: HashMap::with_capacity(edges.len()),
+            pinned: HashSet::new(),
+            force_settings,
+            center,
+            layout_targets: None,
+            layout_energy: 0.0,
+        };
+
+        for node in nodes {
+            world.insert_body(node);
+        }
+        for edge in edges {
+            if !world.edges.iter().any(|other| other.key() == edge.key()) {
+                world.edges.push(*edge);
+            }
+        }
+
+        world.update_joint_strengths();
+        world
+    }
+
+    /// Add a node; returns false if a node with the same id already exists
+    /// Edges that were added before the node get their springs now.
+    pub fn add_node(&mut self, node: &PhysicsNode) -> bool {
+        if self.body_map.contains_key(&node.id) {
+            return false;
+        }
+        self.insert_body(node);
+        let pending: Vec<_> = self
+            .edges
+            .iter()
+            .copied()
+            .filter(|edge| edge.from == node.id || edge.to == node.id)
+            .collect();
+        for edge in pending {
+            self.insert_joint(&edge);
+        }
+        true
+    }
+
+    /// Remove a node together with its edges; returns false if it did not exist
+    pub fn remove_node(&mut self, id: NodeId) -> bool {
+        let Some(handle) = self.body_map.remove(&id) else {
+            return false;
+        };
+
+        // Removing the body also drops its collider and every joint attached to it
+        self.bodies.remove(
+            handle,
+            &mut self.island_manager,
+            &mut self.colliders,
+            &mut self.impulse_joints,
+            &mut self.multibody_joints,
+            true,
+        );
+        self.edges.retain(|edge| edge.from != id && edge.to != id);
+        self.edge_joint_handles
+            .retain(|&(from, to), _| from != id && to != id);
+        self.nodes.retain(|node| node.id != id);
+        self.pinned.remove(&id);
+        if self.author == Some(id) {
+            self.author = None;
+        }
+        true
+    }
+
+    /// Add an edge; returns false if one between the same nodes already exists
+    /// The spring is created once both nodes are in the world.
+    pub fn add_edge(&mut self, edge: Edge) -> bool {
+        if self.edges.iter().any(|other| other.key() == edge.key()) {
+            return false;
+        }
+        self.edges.push(edge);
+        self.insert_joint(&edge);
+        true
+    }
+
+    /// Remove an edge and its spring; returns false if it did not exist
+    pub fn remove_edge(&mut self, from: NodeId, to: NodeId) -> bool {
+        let Some(index) = self.edges.iter().position(|edge| edge.key() == (from, to)) else {
+            return false;
+        };
+        self.edges.remove(index);
+        if let Some(handle) = self.edge_joint_handles.remove(&(from, to)) {
+            self.impulse_joints.remove(handle, true);
+        }
+        true
+    }
+
+    pub fn contains_node(&self, id: NodeId) -> bool {
+        self.body_map.contains_key(&id)
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Edges in insertion order
+    pub fn edges(&self) -> &[Edge] {
+        &self.edges
+    }
+
+    pub fn force_settings(&self) -> &ForceSettings {
+        &self.force_settings
+    }
+
+    pub fn update_force_settings(&mut self, new_settings: ForceSettings) {
+        self.force_settings = new_settings;
+    }
+
+    /// Move the point the author node is pulled towards
+    pub fn set_center(&mut self, center: Position) {
+        self.center = center;
+    }
+
+    /// Current position of a node
+    pub fn position(&self, id: NodeId) -> Option<Position> {
+        let body = self.bodies.get(*self.body_map.get(&id)?)?;
+        let translation = body.position().translation;
+        Some(Position {
+            x: translation.x,
+            y: translation.y,
+        })
+    }
+
+    /// Positions of all nodes in insertion order
+    pub fn positions(&self) -> impl Iterator<Item = (NodeId, Position)> + '_ {
+        self.nodes
+            .iter()
+            .filter_map(|node| Some((node.id, self.position(node.id)?)))
+    }
+
+    /// Switch to a fixed layout, or back to the simulation with None
+    /// Nodes without a target stay where they are until they get one.
+    pub fn set_layout_targets(&mut self, targets: Option<HashMap<NodeId, Position>>) {
+        if targets.is_none() {
+            // Start the simulation from rest rather than with the easing speed
+            for handle in self.body_map.values() {
+                if let Some(body) = self.bodies.get_mut(*handle) {
+                    body.set_linvel(vector![0.0, 0.0], true);
+                }
+            }
+        }
+        self.layout_targets = targets;
+        self.layout_energy = 0.0;
+    }
+
+    pub fn has_layout_targets(&self) -> bool {
+        self.layout_targets.is_some()
+    }
+
+    /// Advance the simulation by one step
+    pub fn step(&mut self) {
+        if self.layout_targets.is_some() {
+            self.step_towards_targets();
+            return;
+        }
+        let physics_hooks = ();
+        let event_handler = ();
+
+        // Only the author node is pulled to the centre
+        self.apply_center_force();
+        self.apply_repulsion_forces();
+        self.apply_category_attraction_forces();
+
+        let mut pipeline = PhysicsPipeline::new();
+        pipeline.step(
+            &self.gravity,
+            &self.integration_parameters,
+            &mut self.island_manager,
+            &mut self.broad_phase,
+            &mut self.narrow_phase,
+            &mut self.bodies,
+            &mut self.colliders,
+            &mut self.impulse_joints,
+            &mut self.multibody_joints,
+            &mut self.ccd_solver,
+            None,
+            &physics_hooks,
+            &event_handler,
+        );
+    }
+
+    /// Kinetic energy per unit mass of the dynamic bodies, for settle detection
+    /// Dragged and pinned nodes are not included.
+    pub fn kinetic_energy(&self) -> f32 {
+        if self.layout_targets.is_some() {
+            return self.layout_energy;
+        }
+        let (energy, mass) = self
+            .body_map
+            .values()
+            .filter_map(|handle| self.bodies.get(*handle))
+            .filter(|body| body.is_dynamic())
+            .fold((0.0, 0.0), |(energy, mass), body| {
+                let m = body.mass();
+                (energy + 0.5 * m * body.linvel().norm_squared(), mass + m)
+            });
+        if mass > 0.0 {
+            energy / mass
+        } else {
+            0.0
+        }
+    }
+
+    /// Move a node to a position in world coordinates
+    pub fn set_node_position(&mut self, id: NodeId, pos: &Position) {
+        if let Some(body) = self.body_mut(id) {
+            body.set_position(Isometry::new(vector![pos.x, pos.y], 0.0), true);
+        }
+    }
+
+    pub fn set_node_kinematic(&mut self, id: NodeId) {
+        if let Some(body) = self.body_mut(id) {
+            body.set_body_type(RigidBodyType::KinematicPositionBased, true);
+        }
+    }
+
+    pub fn set_node_dynamic(&mut self, id: NodeId) {
+        if let Some(body) = self.body_mut(id) {
+            body.set_body_type(RigidBodyType::Dynamic, true);
+        }
+    }
+
+    /// Hold a node where it is, or let it move freely again
+    pub fn set_node_pinned(&mut self, id: NodeId, pinned: bool) {
+        if pinned {
+            self.pinned.insert(id);
+            self.set_node_kinematic(id);
+        } else {
+            self.pinned.remove(&id);
+            self.set_node_dynamic(id);
+        }
+    }
+
+    pub fn is_pinned(&self, id: NodeId) -> bool {
+        self.pinned.contains(&id)
+    }
+
+    /// End a drag; pinned nodes stay where they were dropped
+    pub fn release_node(&mut self, id: NodeId) {
+        if !self.is_pinned(id) {
+            self.set_node_dynamic(id);
+        }
+    }
+
+    /// Rebuild the edge springs with the current link settings
+    pub fn update_joint_strengths(&mut self) {
+        // Walk the edge list rather than the map so runs stay reproducible
+        let edges = self.edges.clone();
+        for edge in &edges {
+            if let Some(handle) = self.edge_joint_handles.remove(&edge.key()) {
+                self.impulse_joints.remove(handle, true);
+            }
+        }
+        for edge in &edges {
+            self.insert_joint(edge);
+        }
+    }
+
+    pub fn set_category_clustering_enabled(&mut self, enabled: bool) {
+        self.force_settings.enable_category_clustering = enabled;
+    }
+
+    /// Resize a node, replacing its collider
+    pub fn set_node_radius(&mut self, id: NodeId, radius: f32, collider_radius: f32) {
+        if let Some(node) = self.nodes.iter_mut().find(|node| node.id == id) {
+            node.radius = radius;
+        }
+        let Some(&body_handle) = self.body_map.get(&id) else {
+            return;
+        };
+
+        let old_colliders: Vec<ColliderHandle> = self
+            .colliders
+            .iter()
+            .filter(|(_, collider)| collider.parent() == Some(body_handle))
+            .map(|(handle, _)| handle)
+            .collect();
+        for handle in old_colliders {
+            self.colliders
+                .remove(handle, &mut self.island_manager, &mut self.bodies, true);
+        }
+
+        let restitution = if self.author == Some(id) { 0.3 } else { 0.7 };
+        let collider = ColliderBuilder::ball(collider_radius)
+            .restitution(restitution)
+            .build();
+        self.colliders
+            .insert_with_parent(collider, body_handle, &mut self.bodies);
+    }
+
+    // Every node starts as a dynamic body
+    fn insert_body(&mut self, node: &PhysicsNode) {
+        let rigid_body = RigidBodyBuilder::dynamic()
+            .linear_damping(3.0)
+            .angular_damping(6.0)
+            .position(Isometry::new(
+                vector![node.position.x, node.position.y],
+                0.0,
+            ))
+            .build();
+        let handle = self.bodies.insert(rigid_body);
+        let collider = ColliderBuilder::ball(node.collider_radius)
+            .restitution(0.7)
+            .build();
+        self.colliders
+            .insert_with_parent(collider, handle, &mut self.bodies);
+
+        self.body_map.insert(node.id, handle);
+        self.nodes.push(NodeInfo {
+            id: node.id,
+            radius: node.radius,
+            category: node.category.clone(),
+        });
+        if node.is_author {
+            self.author = Some(node.id);
+        }
+    }
+
+    // Spring of an edge, if both of its nodes are in the world; stiffer for
+    // heavier edges
+    fn insert_joint(&mut self, edge: &Edge) {
+        let (Some(&a), Some(&b)) = (self.body_map.get(&edge.from), self.body_map.get(&edge.to))
+        else {
+            return;
+        };
+        let joint_params = SpringJointBuilder::new(
+            0.0, // rest length
+            self.force_settings.link_strength * edge.weight(),
+            self.force_settings.direct_link_damping,
+        )
+        .local_anchor1(point![0.0, 0.0])
+        .local_anchor2(point![0.0, 0.0])
+        .build();
+        let handle = self.impulse_joints.insert(a, b, joint_params, true);
+        self.edge_joint_handles.insert(edge.key(), handle);
+    }
+
+    // Move every free node part of the way to its layout target; dragged and
+    // pinned nodes stay where they are held
+    fn step_towards_targets(&mut self) {
+        let Some(targets) = &self.layout_targets else {
+            return;
+        };
+        let (mut energy, mut moved) = (0.0, 0);
+        for node in &self.nodes {
+            let (Some(target), Some(handle)) = (targets.get(&node.id), self.body_map.get(&node.id))
+            else {
+                continue;
+            };
+            let Some(body) = self.bodies.get_mut(*handle) else {
+                continue;
+            };
+            if !body.is_dynamic() {
+                continue;
+            }
+            let current = body.translation();
+            let step = vector![target.x - current.x, target.y - current.y] * LAYOUT_EASING;
+            body.set_translation(current + step, true);
+            body.set_linvel(vector![0.0, 0.0], true);
+            energy += 0.5 * (step.norm() / STEP_DT).powi(2);
+            moved += 1;
+        }
+        self.layout_energy = if moved > 0 {
+            energy / moved as f32
+        } else {
+            0.0
+        };
+    }
+
+    fn body_mut(&mut self, id: NodeId) -> Option<&mut RigidBody> {
+        let handle = *self.body_map.get(&id)?;
+        self.bodies.get_mut(handle)
+    }
+
+    fn apply_impulse(&mut self, id: NodeId, fx: f32, fy: f32) {
+        if let Some(body) = self.body_mut(id) {
+            body.apply_impulse(vector![fx, fy], true);
+        }
+    }
+
+    // Spring-damper pulling the author node to the centre
+    fn apply_center_force(&mut self) {
+        let Some(author) = self.author else {
+            return;
+        };
+        let Some(current) = self.position(author) else {
+            return;
+        };
+        let center = self.center;
+        let settings = self.force_settings;
+        let dt = self.integration_parameters.dt;
+        if let Some(body) = self.body_mut(author) {
+            let v = *body.linvel();
+            let fx =
+                settings.center_strength * (center.x - current.x) - settings.center_damping * v.x;
+            let fy =
+                settings.center_strength * (center.y - current.y) - settings.center_damping * v.y;
+            body.apply_impulse(vector![fx * dt, fy * dt], true);
+        }
+    }
+
+    // Repulsion between nodes, approximated with a Barnes–Hut tree; the
+    // author node has its own minimum distance and is handled exactly
+    fn apply_repulsion_forces(&mut self) {
+        let mut ids = Vec::with_capacity(self.nodes.len());
+        let mut bodies = Vec::with_capacity(self.nodes.len());
+        let mut author_body = None;
+        for node in &self.nodes {
+            let Some(pos) = self.position(node.id) else {
+                continue;
+            };
+            let body = Body {
+                x: pos.x,
+                y: pos.y,
+                radius: node.radius,
+            };
+            if Some(node.id) == self.author {
+                author_body = Some(body);
+            } else {
+                ids.push(node.id);
+                bodies.push(body);
+            }
+        }
+
+        let params = RepulsionParams {
+            strength: self.force_settings.repulsion_strength,
+            min_distance: self.force_settings.repulsion_min_distance,
+        };
+        let mut forces = repulsion_forces(&bodies, &params, self.force_settings.barnes_hut_theta);
+
+        if let (Some(author), Some(author_body)) = (self.author, author_body) {
+            let author_params = RepulsionParams {
+                strength: self.force_settings.repulsion_strength,
+                min_distance: self.force_settings.author_repulsion_min_distance,
+            };
+            let mut author_force = (0.0, 0.0);
+            for (body, force) in bodies.iter().zip(forces.iter_mut()) {
+                let dx = body.x - author_body.x;
+                let dy = body.y - author_body.y;
+                let distance = (dx * dx + dy * dy).sqrt();
+                let magnitude = author_params.magnitude(distance, author_body.radius, body.radius);
+                if magnitude > 0.0 {
+                    let (fx, fy) = (dx / distance * magnitude, dy / distance * magnitude);
+                    force.0 += fx;
+                    force.1 += fy;
+                    author_force.0 -= fx;
+                    author_force.1 -= fy;
+                }
+            }
+            ids.push(author);
+            forces.push(author_force);
+        }
+
+        for (id, (fx, fy)) in ids.into_iter().zip(forces) {
+            self.apply_impulse(id, fx, fy);
+        }
+    }
+
+    // Attraction within each category, one Barnes–Hut tree per category
+    fn apply_category_attraction_forces(&mut self) {
+        if !self.force_settings.enable_category_clustering {
+            return;
+        }
+
+        let dt = self.integration_parameters.dt;
+        let params = AttractionParams {
+            strength: self.force_settings.category_attraction_strength,
+            range: self.force_settings.category_attraction_range,
+        };
+
+        let mut categories: BTreeMap<&str, (Vec<NodeId>, Vec<Body>)> = BTreeMap::new();
+        for node in &self.nodes {
+            if Some(node.id) == self.author {
+                continue;
+            }
+            let (Some(category), Some(pos)) = (node.category.as_deref(), self.position(node.id))
+            else {
+                continue;
+            };
+            let (ids, bodies) = categories.entry(category).or_default();
+            ids.push(node.id);
+            bodies.push(Body {
+                x: pos.x,
+                y: pos.y,
+                radius: 0.0,
+            });
+        }
+
+        let mut impulses = Vec::new();
+        for (ids, bodies) in categories.into_values() {
+            let forces = attraction_forces(&bodies, &params, self.force_settings.barnes_hut_theta);
+            impulses.extend(ids.into_iter().zip(forces));
+        }
+
+        for (id, (fx, fy)) in impulses {
+            self.apply_impulse(id, fx * dt, fy * dt);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::physics::types::ConnectionLineType;
+
+    fn link(from: u32, to: u32) -> Edge {
+        Edge::new(NodeId(from), NodeId(to), ConnectionLineType::DirectLink)
+    }
+
+    fn keys(world: &PhysicsWorld) -> Vec<(NodeId, NodeId)> {
+        world.edges().iter().map(Edge::key).collect()
+    }
+
+    fn node(id: u32, x: f32, y: f32) -> PhysicsNode {
+        PhysicsNode {
+            id: NodeId(id),
+            position: Position { x, y },
+            radius: 20.0,
+            collider_radius: 24.0,
+            category: None,
+            is_author: id == 1,
+        }
+    }
+
+    fn world() -> PhysicsWorld {
+        PhysicsWorld::new(
+            &[node(1, 0.0, 0.0), node(2, 100.0, 0.0), node(3, 0.0, 100.0)],
+            &[link(1, 2), link(1, 3), link(1, 2)],
+            ForceSettings::default(),
+            Position::default(),
+        )
+    }
+
+    #[test]
+    fn test_duplicate_edges_share_one_spring() {
+        let world = world();
+        assert_eq!(
+            keys(&world),
+            [(NodeId(1), NodeId(2)), (NodeId(1), NodeId(3))]
+        );
+        assert_eq!(world.impulse_joints.len(), 2);
+    }
+
+    #[test]
+    fn test_remove_node_drops_its_edges_and_body() {
+        let mut world = world();
+        world.set_node_pinned(NodeId(2), true);
+        assert!(world.remove_node(NodeId(2)));
+        assert!(!world.remove_node(NodeId(2)));
+
+        assert!(!world.contains_node(NodeId(2)));
+        assert!(!world.is_pinned(NodeId(2)));
+        assert_eq!(keys(&world), [(NodeId(1), NodeId(3))]);
+        assert_eq!(world.edge_joint_handles.len(), 1);
+        assert_eq!(world.impulse_joints.len(), 1);
+        assert_eq!(world.bodies.len(), 2);
+        assert_eq!(world.colliders.len(), 2);
+
+        // Removing the author stops the centre pull without breaking the step
+        assert!(world.remove_node(NodeId(1)));
+        assert_eq!(world.author, None);
+        assert_eq!(world.impulse_joints.len(), 0);
+        world.step();
+        assert_eq!(world.positions().count(), 1);
+    }
+
+    #[test]
+    fn test_edges_wait_for_their_nodes() {
+        let mut world = world();
+        assert!(world.add_edge(link(3, 4)));
+        assert!(!world.add_edge(link(3, 4).with_strength(0.5)));
+        assert_eq!(world.impulse_joints.len(), 2);
+
+        assert!(world.add_node(&node(4, 50.0, 50.0)));
+        assert!(!world.add_node(&node(4, 0.0, 0.0)));
+        assert_eq!(world.impulse_joints.len(), 3);
+        assert_eq!(
+            world.position(NodeId(4)),
+            Some(Position { x: 50.0, y: 50.0 })
+        );
+
+        assert!(world.remove_edge(NodeId(3), NodeId(4)));
+        assert!(!world.remove_edge(NodeId(3), NodeId(4)));
+        assert_eq!(world.impulse_joints.len(), 2);
+        assert_eq!(world.node_count(), 4);
+    }
+
+    #[test]
+    fn test_other_nodes_keep_moving_after_a_removal() {
+        let mut world = world();
+        for _ in 0..10 {
+            world.step();
+        }
+        let before = world.position(NodeId(3)).unwrap();
+        world.remove_node(NodeId(2));
+        assert_eq!(world.position(NodeId(3)), Some(before));
+        world.step();
+        assert_ne!(world.position(NodeId(3)), Some(before));
+    }
+
+    #[test]
+    fn test_spring_stiffness_follows_the_edge_weight() {
+        let mut world = world();
+        let weak = Edge::new(NodeId(2), NodeId(3), ConnectionLineType::Weak).with_strength(0.5);
+        world.add_edge(weak);
+
+        let stiffness = |world: &PhysicsWorld, key| {
+            let handle = world.edge_joint_handles[&key];
+            let joint = world.impulse_joints.get(handle).unwrap();
+            joint.data.motor(JointAxis::LinX).unwrap().stiffness
+        };
+        let link_strength = world.force_settings.link_strength;
+        assert_eq!(stiffness(&world, (NodeId(1), NodeId(2))), link_strength);
+        assert_eq!(
+            stiffness(&world, weak.key()),
+            link_strength * ConnectionLineType::Weak.weight() * 0.5
+        );
+
+        // Rebuilding the springs for new settings keeps the weights
+        world.force_settings.link_strength *= 2.0;
+        world.update_joint_strengths();
+        assert_eq!(
+            stiffness(&world, weak.key()),
+            2.0 * link_strength * ConnectionLineType::Weak.weight() * 0.5
+        );
+    }
+
+    #[test]
+    fn test_nodes_ease_towards_layout_targets() {
+        let mut world = world();
+        let target = Position {
+            x: 300.0,
+            y: -200.0,
+        };
+        world.set_node_pinned(NodeId(3), true);
+        world.set_layout_targets(Some(HashMap::from([
+            (NodeId(2), target),
+            (NodeId(3), target),
+        ])));
+
+        world.step();
+        let first = world.kinetic_energy();
+        let moved = world.position(NodeId(2)).unwrap();
+        assert!((moved.x - 140.0).abs() < 1e-3 && (moved.y + 40.0).abs() < 1e-3);
+        for _ in 0..60 {
+            world.step();
+        }
+        let settled = world.position(NodeId(2)).unwrap();
+        assert!((settled.x - target.x).abs() < 0.1 && (settled.y - target.y).abs() < 0.1);
+        assert!(world.kinetic_energy() < first * 1e-6);
+        // Pinned nodes and nodes without a target stay put
+        assert_eq!(
+            world.position(NodeId(3)),
+            Some(Position { x: 0.0, y: 100.0 })
+        );
+        assert_eq!(world.position(NodeId(1)), Some(Position::default()));
+
+        world.set_layout_targets(None);
+        assert!(!world.has_layout_targets());
+        world.step();
+        assert_ne!(world.position(NodeId(2)), Some(settled));
+    }
+}
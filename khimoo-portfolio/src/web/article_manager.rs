@@ -2,7 +2,7 @@ use std::collections::HashMap;
 use yew::prelude::*;
 
 use crate::web::data_loader::{
-    ArticlesData, DataLoadError, DataLoader, LightweightArticle, ProcessedArticle,
+    ArticleIndex, DataLoadError, DataLoader, ArticleIndexEntry, ProcessedArticle,
 };
 use crate::web::types::data_types::NodeRegistry;
 use crate::web::types::node_types::{NodeId, AUTHOR_NODE_ID};
@@ -15,7 +15,7 @@ pub struct ArticleManager {
     /// Full article data (loaded on demand for performance)
     articles: HashMap<String, ProcessedArticle>,
     /// Lightweight article data (always loaded for performance)
-    lightweight_articles: HashMap<String, LightweightArticle>,
+    lightweight_articles: HashMap<String, ArticleIndexEntry>,
     /// Articles to display on home screen
     home_articles: Vec<String>,
     /// Link graph for navigation (slug -> connected slugs)
@@ -48,49 +48,16 @@ impl ArticleManager {
         }
     }
 
-    /// Load data from ArticlesData (from core module via CLI)
+    /// Load data from the article index (from core module via CLI)
+    /// Full articles are fetched per slug on demand (see `use_lazy_article_loader`)
     /// Requirements: 3.3 - Unified management of article and node data
-    pub fn load_from_data(&mut self, articles_data: ArticlesData) {
-        // Clear existing data
-        self.clear_data();
-
-        // Build a set of all article slugs for link validation
-        let all_slugs: std::collections::HashSet<String> = articles_data
-            .articles
-            .iter()
-            .map(|a| a.slug.clone())
-            .collect();
-
-        // Load articles and create lightweight versions
-        for article in articles_data.articles {
-            let lightweight = LightweightArticle::from(article.clone());
-            self.lightweight_articles
-                .insert(article.slug.clone(), lightweight);
-
-            // Only keep full articles for home articles in memory initially (performance optimization)
-            if articles_data.home_articles.contains(&article.slug) {
-                self.articles.insert(article.slug.clone(), article.clone());
-            }
-
-            // Build link graph from outbound_links
-            let connections: Vec<String> = article
-                .outbound_links
-                .iter()
-                .filter(|link| all_slugs.contains(&link.target_slug))
-                .map(|link| link.target_slug.clone())
-                .collect();
-            if !connections.is_empty() {
-                self.link_graph.insert(article.slug.clone(), connections);
-            }
-        }
-
-        self.home_articles = articles_data.home_articles;
-        self.assign_node_ids();
+    pub fn load_from_index(&mut self, index: ArticleIndex) {
+        self.load_lightweight_data(index.articles);
     }
 
     /// Load lightweight data only (for initial page load performance optimization)
     /// Requirements: 3.3 - Performance optimization implementation
-    pub fn load_lightweight_data(&mut self, lightweight_articles: Vec<LightweightArticle>) {
+    pub fn load_lightweight_data(&mut self, lightweight_articles: Vec<ArticleIndexEntry>) {
         self.clear_data();
 
         // Build a set of all article slugs for link validation
@@ -105,17 +72,15 @@ impl ArticleManager {
                 self.home_articles.push(article.slug.clone());
             }
 
-            // Build link graph from inbound_links (pre-calculated for performance)
-            if !article.inbound_links.is_empty() {
-                let connections: Vec<String> = article
-                    .inbound_links
-                    .iter()
-                    .filter(|slug| all_slugs.contains(slug.as_str()))
-                    .cloned()
-                    .collect();
-                if !connections.is_empty() {
-                    self.link_graph.insert(article.slug.clone(), connections);
-                }
+            // Build link graph from outbound_links
+            let connections: Vec<String> = article
+                .outbound_links
+                .iter()
+                .filter(|slug| all_slugs.contains(slug.as_str()))
+                .cloned()
+                .collect();
+            if !connections.is_empty() {
+                self.link_graph.insert(article.slug.clone(), connections);
             }
 
             self.lightweight_articles
@@ -155,7 +120,7 @@ impl ArticleManager {
     }
 
     /// Get home articles as lightweight data
-    pub fn get_home_articles_lightweight(&self) -> Vec<&LightweightArticle> {
+    pub fn get_home_articles_lightweight(&self) -> Vec<&ArticleIndexEntry> {
         self.home_articles
             .iter()
             .filter_map(|slug| self.lightweight_articles.get(slug))
@@ -190,7 +155,7 @@ impl ArticleManager {
     }
 
     /// Get related articles as lightweight data
-    pub fn get_related_articles_lightweight(&self, slug: &str) -> Vec<&LightweightArticle> {
+    pub fn get_related_articles_lightweight(&self, slug: &str) -> Vec<&ArticleIndexEntry> {
         let mut related = Vec::new();
 
         // Get directly linked articles from link graph
@@ -222,7 +187,7 @@ impl ArticleManager {
     }
 
     /// Get lightweight article by slug
-    pub fn get_lightweight_article(&self, slug: &str) -> Option<&LightweightArticle> {
+    pub fn get_lightweight_article(&self, slug: &str) -> Option<&ArticleIndexEntry> {
         self.lightweight_articles.get(slug)
     }
 
@@ -252,7 +217,7 @@ impl ArticleManager {
     }
 
     /// Get all lightweight articles
-    pub fn get_all_lightweight_articles(&self) -> Vec<&LightweightArticle> {
+    pub fn get_all_lightweight_articles(&self) -> Vec<&ArticleIndexEntry> {
         self.lightweight_articles.values().collect()
     }
 
@@ -290,7 +255,7 @@ impl ArticleManager {
                     let new_radius = registry.calculate_dynamic_radius(
                         *node_id,
                         Some(article.metadata.importance),
-                        article.inbound_links.len(),
+                        article.inbound_count,
                     );
                     registry.update_node_radius(*node_id, new_radius);
                 }
@@ -299,7 +264,7 @@ impl ArticleManager {
     }
 
     /// Get lightweight articles by category
-    pub fn get_lightweight_articles_by_category(&self, category: &str) -> Vec<&LightweightArticle> {
+    pub fn get_lightweight_articles_by_category(&self, category: &str) -> Vec<&ArticleIndexEntry> {
         self.lightweight_articles
             .values()
             .filter(|article| {
@@ -340,7 +305,7 @@ impl ArticleManager {
     }
 
     /// Get lightweight articles by tag
-    pub fn get_lightweight_articles_by_tag(&self, tag: &str) -> Vec<&LightweightArticle> {
+    pub fn get_lightweight_articles_by_tag(&self, tag: &str) -> Vec<&ArticleIndexEntry> {
         self.lightweight_articles
             .values()
             .filter(|article| article.metadata.tags.contains(&tag.to_string()))
@@ -369,7 +334,7 @@ impl ArticleManager {
     }
 
     /// Search lightweight articles by title
-    pub fn search_lightweight_articles(&self, query: &str) -> Vec<&LightweightArticle> {
+    pub fn search_lightweight_articles(&self, query: &str) -> Vec<&ArticleIndexEntry> {
         let query_lower = query.to_lowercase();
         self.lightweight_articles
             .values()
@@ -447,6 +412,7 @@ pub struct ArticleStats {
 }
 
 /// Hook for using ArticleManager with data loading
+/// Loads only the article index; full articles are loaded lazily per slug
 /// Requirements: 3.3 - Unified management integration
 #[hook]
 pub fn use_article_manager() -> (
@@ -470,80 +436,14 @@ pub fn use_article_manager() -> (
 
             wasm_bindgen_futures::spawn_local(async move {
                 let loader = DataLoader::new();
-                match loader.load_articles().await {
-                    Ok(articles_data) => {
-                        let mut article_manager = ArticleManager::new();
-                        article_manager.load_from_data(articles_data);
-
-                        // Also load node registry for unified management
-                        match loader.build_node_registry().await {
-                            Ok(node_registry) => {
-                                article_manager.set_node_registry(node_registry);
-                            }
-                            Err(e) => {
-                                web_sys::console::warn_1(
-                                    &format!("Failed to load node registry: {}", e).into(),
-                                );
-                            }
-                        }
-
-                        manager.set(Some(article_manager));
-                        error.set(None);
-                    }
-                    Err(e) => {
-                        error.set(Some(e));
-                    }
-                }
-                loading.set(false);
-            });
-
-            || {}
-        });
-    }
-
-    (manager, loading, error)
-}
-
-/// Hook for using ArticleManager with lightweight data loading (faster initial load)
-/// Requirements: 3.3 - Performance optimization implementation
-#[hook]
-pub fn use_lightweight_article_manager() -> (
-    UseStateHandle<Option<ArticleManager>>,
-    UseStateHandle<bool>,
-    UseStateHandle<Option<DataLoadError>>,
-) {
-    let manager = use_state(|| None);
-    let loading = use_state(|| true);
-    let error = use_state(|| None);
-
-    {
-        let manager = manager.clone();
-        let loading = loading.clone();
-        let error = error.clone();
-
-        use_effect_with((), move |_| {
-            let manager = manager.clone();
-            let loading = loading.clone();
-            let error = error.clone();
-
-            wasm_bindgen_futures::spawn_local(async move {
-                let loader = DataLoader::new();
-                match loader.load_lightweight_articles().await {
-                    Ok(lightweight_articles) => {
+                match loader.load_index().await {
+                    Ok(index) => {
                         let mut article_manager = ArticleManager::new();
-                        article_manager.load_lightweight_data(lightweight_articles);
 
-                        // Also load node registry for unified management
-                        match loader.build_node_registry().await {
-                            Ok(node_registry) => {
-                                article_manager.set_node_registry(node_registry);
-                            }
-                            Err(e) => {
-                                web_sys::console::warn_1(
-                                    &format!("Failed to load node registry: {}", e).into(),
-                                );
-                            }
-                        }
+                        // Build node registry from the same index for unified management
+                        let node_registry = loader.build_node_registry_from_index(&index);
+                        article_manager.load_from_index(index);
+                        article_manager.set_node_registry(node_registry);
 
                         manager.set(Some(article_manager));
                         error.set(None);
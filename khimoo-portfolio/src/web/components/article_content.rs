@@ -1,3 +1,4 @@
+use crate::core::articles::relations::{group_relations, relation_label};
 use crate::web::data_loader::ProcessedArticle;
 use crate::web::routes::Route;
 use pulldown_cmark::{html, Parser};
@@ -23,7 +24,9 @@ pub fn article_content(props: &ArticleContentProps) -> Html {
             <div class="markdown-body">
                 {rendered}
             </div>
+            {render_relations(&props.article)}
             {render_related_articles(&props.article)}
+            {render_recommendations(&props.article)}
         </>
     }
 }
@@ -37,6 +40,43 @@ fn process_markdown_content(content: &str) -> String {
     html_output
 }
 
+/// 型付きの関係を種類ごとにまとめて表示
+fn render_relations(article: &ProcessedArticle) -> Html {
+    let groups = group_relations(&article.metadata.relations);
+    if groups.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <section style="margin-top: 48px; padding-top: 24px; border-top: 1px solid #444;">
+            <h3 style="color: #e0e0e0;">{"Relations"}</h3>
+            {
+                groups.into_iter().map(|(kind, relations)| html! {
+                    <div key={kind.to_string()} style="margin-bottom: 16px;">
+                        <h4 style="color: #aaa; margin: 0 0 8px 0; font-size: 0.9em;">
+                            {relation_label(kind)}
+                        </h4>
+                        <ul style="list-style: none; padding: 0; margin: 0;">
+                            {
+                                relations.into_iter().map(|relation| html! {
+                                    <li key={relation.to.clone()} style="margin-bottom: 8px;">
+                                        <Link<Route> to={Route::ArticleShow { slug: relation.to.clone() }}>
+                                            {&relation.to}
+                                        </Link<Route>>
+                                        if let Some(note) = &relation.note {
+                                            <span style="color: #aaa; margin-left: 8px;">{format!("— {}", note)}</span>
+                                        }
+                                    </li>
+                                }).collect::<Html>()
+                            }
+                        </ul>
+                    </div>
+                }).collect::<Html>()
+            }
+        </section>
+    }
+}
+
 fn render_related_articles(article: &ProcessedArticle) -> Html {
     if !article.outbound_links.is_empty() {
         html! {
@@ -62,6 +102,34 @@ fn render_related_articles(article: &ProcessedArticle) -> Html {
     }
 }
 
+/// ビルド時に計算した似ている記事（明示的な関連リンクの下に表示）
+fn render_recommendations(article: &ProcessedArticle) -> Html {
+    if article.recommendations.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <section style="margin-top: 32px;">
+            <h3 style="color: #e0e0e0;">{"You might also like"}</h3>
+            <ul style="list-style: none; padding: 0;">
+                {
+                    article.recommendations.iter().map(|recommendation| html! {
+                        <li
+                            key={recommendation.slug.clone()}
+                            style="margin-bottom: 8px;"
+                            title={format!("類似度 {:.0}%", recommendation.score * 100.0)}
+                        >
+                            <Link<Route> to={Route::ArticleShow { slug: recommendation.slug.clone() }}>
+                                {&recommendation.title}
+                            </Link<Route>>
+                        </li>
+                    }).collect::<Html>()
+                }
+            </ul>
+        </section>
+    }
+}
+
 /// コンテンツ用のCSS（WikiLinkスタイル削除）
 fn content_styles() -> String {
     r#"
@@ -21,6 +21,7 @@ pub fn article_header(props: &ArticleHeaderProps) -> Html {
                     {render_category(&article.metadata.category)}
                     {render_importance(Some(article.metadata.importance))}
                     {render_inbound_links_count(article.inbound_links.len())}
+                    {render_reading_time(article.stats.reading_time_minutes, article.stats.word_count)}
                     {render_tags(&article.metadata.tags)}
                 </div>
             </div>
@@ -55,6 +56,18 @@ fn render_inbound_links_count(count: usize) -> Html {
     }
 }
 
+fn render_reading_time(minutes: u32, word_count: usize) -> Html {
+    if minutes > 0 {
+        html! {
+            <span title={format!("{} words", word_count)}>
+                {"Reading time: "}<strong>{minutes}{" min"}</strong>
+            </span>
+        }
+    } else {
+        html! {}
+    }
+}
+
 fn render_tags(tags: &[String]) -> Html {
     if !tags.is_empty() {
         html! {
@@ -0,0 +1,37 @@
+use crate::config::category_palette;
+use crate::web::data_loader::ArticleIndexEntry;
+use crate::web::styles::{LegendStyles, PreviewCardStyles};
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct ArticlePreviewCardProps {
+    pub article: ArticleIndexEntry,
+}
+
+/// グラフのノードにホバー・フォーカスしたときの記事の概要
+#[function_component(ArticlePreviewCard)]
+pub fn article_preview_card(props: &ArticlePreviewCardProps) -> Html {
+    let article = &props.article;
+    let minutes = article.stats.reading_time_minutes;
+
+    html! {
+        <>
+            <h3 style={PreviewCardStyles::title()}>{&article.title}</h3>
+            if let Some(summary) = &article.summary {
+                <p style={PreviewCardStyles::summary()}>{summary}</p>
+            }
+            <div style={PreviewCardStyles::meta()}>
+                if let Some(category) = &article.metadata.category {
+                    <span style={LegendStyles::swatch(category_palette(category).0)}></span>
+                    <span>{category}</span>
+                }
+                if minutes > 0 {
+                    <span>{format!("約{}分", minutes)}</span>
+                }
+                {for article.metadata.tags.iter().map(|tag| html! {
+                    <span key={tag.clone()}>{format!("#{}", tag)}</span>
+                })}
+            </div>
+        </>
+    }
+}
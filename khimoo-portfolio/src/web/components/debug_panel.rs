@@ -36,6 +36,7 @@ pub fn debug_panel(props: &DebugPanelProps) -> Html {
                 "link_strength" => new_settings.link_strength = value,
                 "center_strength" => new_settings.center_strength = value,
                 "center_damping" => new_settings.center_damping = value,
+                "barnes_hut_theta" => new_settings.barnes_hut_theta = value,
                 _ => {}
             }
             on_settings_change.emit(new_settings);
@@ -51,8 +52,8 @@ pub fn debug_panel(props: &DebugPanelProps) -> Html {
                 <input
                     type="range"
                     min="0"
-                    max="200000"
-                    step="1000"
+                    max="400000"
+                    step="2000"
                     value={props.force_settings.repulsion_strength.to_string()}
                     onchange={create_slider_callback("repulsion_strength")}
                     style="width: 200px;"
@@ -123,6 +124,19 @@ pub fn debug_panel(props: &DebugPanelProps) -> Html {
                     style="width: 200px;"
                 />
             </div>
+
+            <div style="margin-bottom: 15px;">
+                <label>{"近似精度 θ: "}{format!("{:.2}", props.force_settings.barnes_hut_theta)}</label><br/>
+                <input
+                    type="range"
+                    min="0"
+                    max="1.5"
+                    step="0.05"
+                    value={props.force_settings.barnes_hut_theta.to_string()}
+                    onchange={create_slider_callback("barnes_hut_theta")}
+                    style="width: 200px;"
+                />
+            </div>
         </div>
     }
 }
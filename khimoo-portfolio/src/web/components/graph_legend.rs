@@ -0,0 +1,69 @@
+use crate::config::category_palette;
+use crate::core::graph::{GraphFilter, Legend, UNCATEGORIZED};
+use crate::web::styles::{LegendStyles, NodeStyles};
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct GraphLegendProps {
+    pub legend: Legend,
+    pub filter: GraphFilter,
+    pub on_toggle_category: Callback<String>,
+    pub on_toggle_tag: Callback<String>,
+}
+
+/// カテゴリの色と記事数の凡例。項目を押すと表示・非表示を切り替える
+#[function_component(GraphLegend)]
+pub fn graph_legend(props: &GraphLegendProps) -> Html {
+    if props.legend.categories.is_empty() {
+        return html! {};
+    }
+
+    let categories = props.legend.categories.iter().map(|(category, count)| {
+        let hidden = props.filter.hidden_categories.contains(category);
+        // 未分類のノードは既定の色で描かれる
+        let (color, label) = if category == UNCATEGORIZED {
+            (NodeStyles::DEFAULT_NODE_COLOR, "未分類")
+        } else {
+            (category_palette(category).0, category.as_str())
+        };
+        let onclick = {
+            let on_toggle_category = props.on_toggle_category.clone();
+            let category = category.clone();
+            Callback::from(move |_: MouseEvent| on_toggle_category.emit(category.clone()))
+        };
+        html! {
+            <button key={category.clone()} style={LegendStyles::item(hidden)} {onclick}>
+                <span style={LegendStyles::swatch(color)}></span>
+                <span>{label}</span>
+                <span style={LegendStyles::count()}>{count}</span>
+            </button>
+        }
+    });
+
+    let tags = props.legend.tags.iter().map(|(tag, count)| {
+        let hidden = props.filter.hidden_tags.contains(tag);
+        let onclick = {
+            let on_toggle_tag = props.on_toggle_tag.clone();
+            let tag = tag.clone();
+            Callback::from(move |_: MouseEvent| on_toggle_tag.emit(tag.clone()))
+        };
+        html! {
+            <button key={tag.clone()} style={LegendStyles::tag(hidden)} {onclick}>
+                {format!("#{} {}", tag, count)}
+            </button>
+        }
+    });
+
+    html! {
+        <div style={LegendStyles::panel()}>
+            <div style={LegendStyles::section_title()}>{"カテゴリ"}</div>
+            {for categories}
+            if !props.legend.tags.is_empty() {
+                <div style={LegendStyles::section_title()}>{"タグ"}</div>
+                <div style="display: flex; flex-wrap: wrap; gap: 4px;">
+                    {for tags}
+                </div>
+            }
+        </div>
+    }
+}
@@ -0,0 +1,156 @@
+use crate::config::get_config;
+use crate::core::model::ArticleIndex;
+use crate::core::physics::{HomeGraph, PhysicsWorld};
+use crate::web::components::{NodeDataManager, NodeRenderer};
+use crate::web::config::PhysicsConfig;
+use crate::web::data_loader::use_article_index;
+use crate::web::routes::Route;
+use crate::web::styles::LocalGraphStyles;
+use crate::web::types::*;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+/// サイドバーの描画領域の一辺（px）
+const LOCAL_GRAPH_SIZE: f32 = 260.0;
+/// 描画領域の縁の余白（px）
+const LOCAL_GRAPH_PADDING: f32 = 8.0;
+/// 表示前に進めるシミュレーションのステップ数
+const LOCAL_GRAPH_STEPS: usize = 400;
+
+#[derive(Properties, PartialEq)]
+pub struct LocalGraphProps {
+    pub slug: String,
+    /// 表示する近傍の深さ（ホップ数）
+    #[prop_or(get_config().local_graph_depth)]
+    pub depth: usize,
+}
+
+/// 近傍グラフの描画内容
+struct LocalGraphView {
+    registry: Rc<RefCell<NodeRegistry>>,
+    id_to_slug: HashMap<NodeId, String>,
+    /// 描画領域に収める縮小率
+    scale: f32,
+    width: f32,
+    height: f32,
+}
+
+/// 記事ページのサイドバーに置く小さなグラフ
+/// 現在の記事と、そのリンク先・リンク元・関連記事を指定の深さまで表示する。
+/// 配置はホームと同じ物理シミュレーションを先に落ち着かせてから縮小して描く。
+#[function_component(LocalGraph)]
+pub fn local_graph(props: &LocalGraphProps) -> Html {
+    let (index, _, _) = use_article_index();
+    let focused = use_state(|| None::<NodeId>);
+    let navigator = use_navigator();
+
+    let view = {
+        let index = index.clone();
+        use_memo(
+            (index.is_some(), props.slug.clone(), props.depth),
+            move |(_, slug, depth)| {
+                (*index)
+                    .as_ref()
+                    .and_then(|index| build_view(index, slug, *depth))
+            },
+        )
+    };
+
+    let Some(view) = view.as_ref() else {
+        return html! {};
+    };
+
+    // 隣の記事をクリックするとその記事へ移動する
+    let on_pointer_down = {
+        let id_to_slug = view.id_to_slug.clone();
+        let current = props.slug.clone();
+        Callback::from(move |(id, _): (NodeId, PointerEvent)| {
+            let (Some(navigator), Some(slug)) = (navigator.as_ref(), id_to_slug.get(&id)) else {
+                return;
+            };
+            if *slug != current {
+                navigator.push(&Route::ArticleShow { slug: slug.clone() });
+            }
+        })
+    };
+    let on_focus_change = {
+        let focused = focused.clone();
+        Callback::from(move |id: Option<NodeId>| focused.set(id))
+    };
+
+    html! {
+        <aside style={LocalGraphStyles::panel()}>
+            <h3 style={LocalGraphStyles::title()}>{"近くの記事"}</h3>
+            <div style={LocalGraphStyles::viewport(LOCAL_GRAPH_SIZE)}>
+                <div style={LocalGraphStyles::canvas(view.width, view.height, view.scale, LOCAL_GRAPH_PADDING)}>
+                    <NodeRenderer
+                        node_registry={view.registry.clone()}
+                        {on_pointer_down}
+                        on_context_menu={Callback::noop()}
+                        focused={*focused}
+                        {on_focus_change}
+                    />
+                </div>
+            </div>
+        </aside>
+    }
+}
+
+/// 近傍のノードを配置して縮小率を決める。近傍に他の記事がなければNone
+fn build_view(index: &ArticleIndex, slug: &str, depth: usize) -> Option<LocalGraphView> {
+    let node_config = get_config().node_config.for_index(index);
+    let graph = HomeGraph::neighbourhood(index, slug, depth, &node_config, Position::default());
+    if graph.slugs.len() < 2 {
+        return None;
+    }
+
+    let mut world = PhysicsWorld::new(
+        &graph.nodes,
+        &graph.edges,
+        PhysicsConfig::default_force_settings(),
+        Position::default(),
+    );
+    for _ in 0..LOCAL_GRAPH_STEPS {
+        world.step();
+    }
+
+    let mut registry = NodeRegistry::new_with_config(node_config);
+    let mut id_to_slug = HashMap::new();
+    for (node, slug) in graph.nodes.iter().zip(&graph.slugs) {
+        let article = index
+            .articles
+            .iter()
+            .find(|article| &article.slug == slug)?;
+        let position = world.position(node.id).unwrap_or(node.position);
+        NodeDataManager::add_article_node(&mut registry, node.id, position, article);
+        // 現在の記事は展開中の輪で示す
+        if node.is_author {
+            registry.expanded_nodes.insert(node.id);
+        }
+        id_to_slug.insert(node.id, slug.clone());
+    }
+    for edge in &graph.edges {
+        registry.add_typed_edge(*edge, graph.edge_labels.get(&edge.key()).cloned());
+    }
+
+    // 左上の余白を詰め、全体が描画領域に収まるよう縮小する
+    let (min, max) = registry.bounds()?;
+    for position in registry.positions.values_mut() {
+        position.x -= min.x;
+        position.y -= min.y;
+    }
+    let (width, height) = (max.x - min.x, max.y - min.y);
+    let available = LOCAL_GRAPH_SIZE - 2.0 * LOCAL_GRAPH_PADDING;
+    let scale = (available / width.max(height).max(1.0)).min(1.0);
+
+    Some(LocalGraphView {
+        registry: Rc::new(RefCell::new(registry)),
+        id_to_slug,
+        scale,
+        width,
+        height,
+    })
+}
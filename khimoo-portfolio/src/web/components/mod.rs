@@ -5,19 +5,27 @@
 
 pub mod article_content;
 pub mod article_header;
+pub mod article_preview_card;
 pub mod article_state_renderer;
 pub mod debug_panel;
+pub mod graph_legend;
+pub mod local_graph;
 pub mod node_data_manager;
 pub mod node_graph_container;
 pub mod node_renderer;
 pub mod physics_renderer;
+pub mod timeline_slider;
 
 // Re-export commonly used components
 pub use article_content::*;
 pub use article_header::*;
+pub use article_preview_card::*;
 pub use article_state_renderer::*;
 pub use debug_panel::*;
+pub use graph_legend::*;
+pub use local_graph::*;
 pub use node_data_manager::*;
 pub use node_graph_container::*;
 pub use node_renderer::*;
 pub use physics_renderer::*;
+pub use timeline_slider::*;
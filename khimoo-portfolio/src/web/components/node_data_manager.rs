@@ -1,38 +1,21 @@
 use crate::config::get_config;
-use crate::web::data_loader::{ArticlesData, ProcessedArticle};
+use crate::core::graph::{neighbours, resolve_edges, ring_positions, Expansion, GraphFilter};
+use crate::core::layouts::{LayoutInput, LayoutKind, LayoutNode, DEFAULT_SPACING};
+use crate::core::physics::HomeGraph;
+use crate::web::data_loader::{ArticleIndex, ArticleIndexEntry};
+use crate::web::graph_controller::GraphController;
 use crate::web::types::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-/// 簡単な疑似乱数生成器（線形合同法）
-struct SimpleRng {
-    seed: u32,
-}
-
-impl SimpleRng {
-    fn new(seed: u32) -> Self {
-        Self { seed }
-    }
-
-    fn next(&mut self) -> u32 {
-        self.seed = self.seed.wrapping_mul(1103515245).wrapping_add(12345);
-        self.seed
-    }
-
-    fn next_f32(&mut self) -> f32 {
-        (self.next() as f32) / (u32::MAX as f32)
-    }
-
-    fn next_range(&mut self, min: f32, max: f32) -> f32 {
-        min + self.next_f32() * (max - min)
-    }
-}
+/// 展開で現れたノードを置く、展開元ノードの中心からの距離（半径に加える分）
+const EXPANSION_SPAWN_GAP: f32 = 60.0;
 
 /// データ処理を担当するモジュール
 pub struct NodeDataManager;
 
 impl NodeDataManager {
     /// 記事の内容に基づいてNodeContentを決定
-    pub fn determine_node_content(article: &ProcessedArticle) -> NodeContent {
+    pub fn determine_node_content(article: &ArticleIndexEntry) -> NodeContent {
         if let Some(image_url) = &article.metadata.author_image {
             #[cfg(target_arch = "wasm32")]
             web_sys::console::log_1(
@@ -70,15 +53,16 @@ impl NodeDataManager {
         }
     }
 
-    /// ArticlesDataからNodeRegistryを生成
+    /// 記事インデックスからNodeRegistryを生成
     pub fn create_node_registry_from_articles(
-        articles_data: &ArticlesData,
+        articles_data: &ArticleIndex,
         container_bound: &ContainerBound,
     ) -> (NodeRegistry, HashMap<NodeId, String>) {
-        let mut reg = NodeRegistry::new_with_config(get_config().node_config.clone());
-        let mut slug_to_id = HashMap::new();
+        let mut reg =
+            NodeRegistry::new_with_config(get_config().node_config.for_index(articles_data));
+        // ホームグラフはカテゴリでまとめない（CLIで事前計算したレイアウトと揃える）
+        reg.cluster_by_category = false;
         let mut id_to_slug = HashMap::new();
-        let mut next_id = 1u32;
 
         // コンテナの中心を計算
         let center_x = container_bound.width / 2.0;
@@ -123,7 +107,7 @@ impl NodeDataManager {
             );
 
             reg.add_node(
-                NodeId(next_id),
+                NodeId(1),
                 Position {
                     x: center_x,
                     y: center_y,
@@ -131,92 +115,232 @@ impl NodeDataManager {
                 get_config().node_config.default_node_radius,
                 NodeContent::Text("Author".to_string()),
             );
-            slug_to_id.insert("author".to_string(), NodeId(next_id));
-            id_to_slug.insert(NodeId(next_id), "author".to_string());
+            id_to_slug.insert(NodeId(1), "author".to_string());
             return (reg, id_to_slug);
         }
 
-        // 疑似乱数生成器を初期化（記事数をシードに使用して再現性を保つ）
-        let mut rng = SimpleRng::new(home_articles.len() as u32 * 42);
-        let scatter_radius = 80.0; // 中心からの最大散らばり距離
-
-        // 円形配置の計算を削除し、疑似乱数で少しバラけさせて配置
-        for (index, article) in home_articles.iter().enumerate() {
-            let node_id = NodeId(next_id);
-            let content = Self::determine_node_content(article);
-
-            // 疑似乱数で中心からの位置をずらす
-            let (position, base_radius) = if article.metadata.author_image.is_some() {
-                #[cfg(target_arch = "wasm32")]
-                web_sys::console::log_1(
-                    &format!("Placing author article '{}' at center", article.title).into(),
-                );
-                // 作者ノードは中心に配置
-                (
-                    Position {
-                        x: center_x,
-                        y: center_y,
-                    },
-                    get_config().node_config.author_node_radius,
-                )
-            } else {
-                // 記事ノードは中心から少しずらして配置
-                let offset_x = rng.next_range(-scatter_radius, scatter_radius);
-                let offset_y = rng.next_range(-scatter_radius, scatter_radius);
-                (
-                    Position {
-                        x: center_x + offset_x,
-                        y: center_y + offset_y,
-                    },
-                    get_config().node_config.default_node_radius,
-                )
-            };
+        // ノードの配置とサイズはCLIの事前計算と共通の組み立てを使う
+        let center = Position {
+            x: center_x,
+            y: center_y,
+        };
+        let mut graph = HomeGraph::from_index(articles_data, &reg.node_config, center);
 
-            reg.add_node(node_id, position, base_radius, content);
-            reg.set_node_importance(node_id, article.metadata.importance);
-            reg.set_node_inbound_count(node_id, article.inbound_links.len());
-            
-            // 重要度とインバウンドリンク数に基づいて動的にサイズを計算・更新
-            let dynamic_radius = reg.calculate_dynamic_radius(
-                node_id,
-                Some(article.metadata.importance),
-                article.inbound_links.len(),
+        // ビルド時に計算済みのレイアウトがあれば、散らばった初期配置の代わりに使う
+        if let Some(layout) = &articles_data.layout {
+            reg.layout_precomputed = graph.apply_layout(layout, center);
+            #[cfg(target_arch = "wasm32")]
+            web_sys::console::log_1(
+                &format!(
+                    "Using precomputed layout ({} steps, complete: {})",
+                    layout.steps, reg.layout_precomputed
+                )
+                .into(),
             );
-            reg.update_node_radius(node_id, dynamic_radius);
+        }
+
+        for ((node, slug), article) in graph.nodes.iter().zip(&graph.slugs).zip(&home_articles) {
+            Self::add_article_node(&mut reg, node.id, node.position, article);
 
             #[cfg(target_arch = "wasm32")]
             web_sys::console::log_1(
                 &format!(
-                    "Node '{}': importance={}, inbound_links={}, base_radius={}, dynamic_radius={}",
-                    article.title, article.metadata.importance, article.inbound_links.len(), base_radius, dynamic_radius
+                    "Node '{}': importance={}, inbound_links={}, radius={}",
+                    article.title, article.metadata.importance, article.inbound_count, node.radius
                 )
                 .into(),
             );
 
-            slug_to_id.insert(article.slug.clone(), node_id);
-            id_to_slug.insert(node_id, article.slug.clone());
-            next_id += 1;
+            id_to_slug.insert(node.id, slug.clone());
         }
 
         // 記事間のリンクを追加
-        for article in &home_articles {
-            if let Some(&from_id) = slug_to_id.get(&article.slug) {
-                for link in &article.outbound_links {
-                    if let Some(&to_id) = slug_to_id.get(&link.target_slug) {
-                        #[cfg(target_arch = "wasm32")]
-                        web_sys::console::log_1(
-                            &format!(
-                                "Adding edge: {} -> {} (IDs: {} -> {})",
-                                article.slug, link.target_slug, from_id.0, to_id.0
-                            )
-                            .into(),
-                        );
-                        reg.add_edge(from_id, to_id);
-                    }
+        for edge in &graph.edges {
+            reg.add_typed_edge(*edge, graph.edge_labels.get(&edge.key()).cloned());
+        }
+
+        (reg, id_to_slug)
+    }
+
+    /// 記事ノードをレジストリに登録（内容・半径・重要度・被リンク数・カテゴリ）
+    pub fn add_article_node(
+        reg: &mut NodeRegistry,
+        id: NodeId,
+        pos: Position,
+        article: &ArticleIndexEntry,
+    ) {
+        let content = Self::determine_node_content(article);
+        let radius = if article.metadata.author_image.is_some() {
+            reg.node_config.author_node_radius
+        } else {
+            reg.node_config.entry_node_radius(article)
+        };
+        reg.add_node(id, pos, radius, content);
+        reg.set_node_importance(id, article.metadata.importance);
+        reg.set_node_inbound_count(id, article.inbound_count);
+        if let Some(category) = &article.metadata.category {
+            reg.set_node_category(id, category.clone());
+        }
+    }
+
+    /// 展開状態と絞り込みに合わせて表示中のノードとエッジを増減する
+    /// 新しく現れたノードは、既に表示されている隣接ノードの周りに並べる（なければ`center`の周り）。
+    /// ノードIDは記事ごとに一度だけ割り当て、`id_to_slug`に追加する。
+    pub fn sync_graph(
+        graph: &GraphController,
+        expansion: &Expansion,
+        filter: &GraphFilter,
+        articles_data: &ArticleIndex,
+        id_to_slug: &mut HashMap<NodeId, String>,
+        center: Position,
+    ) {
+        let visible = filter.apply(articles_data, expansion.visible(articles_data));
+        let visible_set: HashSet<&str> = visible.iter().map(String::as_str).collect();
+        let mut slug_to_id: HashMap<String, NodeId> = id_to_slug
+            .iter()
+            .map(|(id, slug)| (slug.clone(), *id))
+            .collect();
+
+        // 見えなくなったノードを退場させる
+        let shown = graph.shown_nodes();
+        for id in &shown {
+            if id_to_slug
+                .get(id)
+                .is_some_and(|slug| !visible_set.contains(slug.as_str()))
+            {
+                graph.remove_node(*id);
+            }
+        }
+
+        // 新しく見えるノードを、隣接する表示中ノードごとにまとめる
+        let shown: HashSet<NodeId> = graph.shown_nodes().into_iter().collect();
+        let mut groups: Vec<(Option<NodeId>, Vec<&ArticleIndexEntry>)> = Vec::new();
+        for article in articles_data
+            .articles
+            .iter()
+            .filter(|article| visible_set.contains(article.slug.as_str()))
+        {
+            if slug_to_id
+                .get(&article.slug)
+                .is_some_and(|id| shown.contains(id))
+            {
+                continue;
+            }
+            let anchor = neighbours(articles_data, &article.slug)
+                .into_iter()
+                .filter_map(|slug| slug_to_id.get(slug))
+                .find(|id| shown.contains(id))
+                .copied();
+            match groups.iter_mut().find(|(group, _)| *group == anchor) {
+                Some((_, articles)) => articles.push(article),
+                None => groups.push((anchor, vec![article])),
+            }
+        }
+
+        let mut next_id = id_to_slug.keys().map(|id| id.0).max().unwrap_or(0) + 1;
+        for (anchor, articles) in groups {
+            let (origin, distance) = {
+                let registry = graph.registry();
+                let registry = registry.borrow();
+                match anchor
+                    .and_then(|id| Some((*registry.positions.get(&id)?, registry.radii.get(&id)?)))
+                {
+                    Some((pos, radius)) => (pos, *radius as f32 + EXPANSION_SPAWN_GAP),
+                    None => (center, EXPANSION_SPAWN_GAP),
                 }
+            };
+            let positions = ring_positions(origin, articles.len(), distance);
+            for (article, pos) in articles.into_iter().zip(positions) {
+                let id = *slug_to_id.entry(article.slug.clone()).or_insert_with(|| {
+                    let id = NodeId(next_id);
+                    next_id += 1;
+                    id
+                });
+                id_to_slug.insert(id, article.slug.clone());
+                graph.add_node(id, |reg| Self::add_article_node(reg, id, pos, article));
             }
         }
 
-        (reg, id_to_slug)
+        // 展開中の印を付け直す
+        {
+            let registry = graph.registry();
+            let mut registry = registry.borrow_mut();
+            registry.expanded_nodes = expansion
+                .expanded()
+                .iter()
+                .filter_map(|slug| slug_to_id.get(slug).copied())
+                .collect();
+        }
+        graph.notify();
+
+        // エッジを展開状態に合わせる（相互リンクは1本にまとめ、関係にはラベルを付ける）
+        let (wanted, mut labels) =
+            resolve_edges(&expansion.edges(articles_data, &visible), |slug| {
+                slug_to_id.get(slug).copied()
+            });
+        let current = graph.registry().borrow().physics_edges();
+        for edge in &current {
+            if !wanted.contains(edge) {
+                graph.remove_edge(edge.from, edge.to);
+            }
+        }
+        for edge in wanted {
+            graph.add_edge(edge, labels.remove(&edge.key()));
+        }
+    }
+
+    /// 固定レイアウトでの表示中ノードの目標位置（物理シミュレーションならNone）
+    /// ノードはID順に並べるので、同じグラフからは同じ配置になる
+    pub fn layout_targets(
+        graph: &GraphController,
+        kind: LayoutKind,
+        articles_data: &ArticleIndex,
+        id_to_slug: &HashMap<NodeId, String>,
+        center: Position,
+    ) -> Option<Vec<(NodeId, Position)>> {
+        let engine = kind.engine()?;
+        let registry = graph.registry();
+        let registry = registry.borrow();
+        let mut ids: Vec<NodeId> = registry
+            .positions
+            .keys()
+            .copied()
+            .filter(|id| !registry.is_exiting(*id))
+            .collect();
+        ids.sort_by_key(|id| id.0);
+
+        let nodes: Vec<LayoutNode> = ids
+            .into_iter()
+            .map(|id| {
+                let article = id_to_slug.get(&id).and_then(|slug| {
+                    articles_data
+                        .articles
+                        .iter()
+                        .find(|article| &article.slug == slug)
+                });
+                LayoutNode {
+                    id,
+                    is_author: registry.get_node_type(id) == Some(&NodeType::Author),
+                    is_root: article.is_some_and(|article| article.metadata.home_display),
+                    category: registry.get_node_category(id).cloned(),
+                    created_at: article.and_then(|article| {
+                        article
+                            .created_on
+                            .clone()
+                            .or_else(|| article.metadata.created_at.clone())
+                    }),
+                }
+            })
+            .collect();
+        let edges = registry.physics_edges();
+        let input = LayoutInput {
+            nodes: &nodes,
+            edges: &edges,
+            center,
+            spacing: DEFAULT_SPACING,
+        };
+        let mut targets: Vec<_> = engine.arrange(&input).into_iter().collect();
+        targets.sort_by_key(|(id, _)| id.0);
+        Some(targets)
     }
 }
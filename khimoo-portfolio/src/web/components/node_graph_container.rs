@@ -1,12 +1,17 @@
 use crate::config::get_config;
+use crate::core::graph::{Expansion, ExpansionView, FilterQuery, GraphFilter, Legend, Timeline};
+use crate::core::layouts::LayoutKind;
+use crate::web::components::article_preview_card::ArticlePreviewCard;
+use crate::web::components::graph_legend::GraphLegend;
 use crate::web::components::node_data_manager::NodeDataManager;
 use crate::web::components::physics_renderer::PhysicsRenderer;
-use crate::web::data_loader::use_articles_data;
-use crate::web::physics_sim::{PhysicsWorld, Viewport};
+use crate::web::components::timeline_slider::TimelineSlider;
+use crate::web::data_loader::{use_article_index, DataLoader};
+use crate::web::graph_controller::GraphController;
 use crate::web::routes::Route;
-use crate::web::styles::{ErrorStyles, LoadingStyles};
+use crate::web::styles::{ButtonStyles, ErrorStyles, LayoutStyles, LoadingStyles};
+use crate::web::svg_export;
 use crate::web::types::*;
-use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
 use yew::prelude::*;
@@ -22,24 +27,32 @@ pub struct NodeGraphContainerProps {
 
 #[function_component(NodeGraphContainer)]
 pub fn node_graph_container(props: &NodeGraphContainerProps) -> Html {
-    let force_settings = use_state(ForceSettings::default);
-    let viewport = use_state(Viewport::default);
-
     // データローダーを使用して記事データを取得
-    let (articles_data, loading, error) = use_articles_data();
-
-    // 記事データが読み込まれたらノードレジストリと物理世界を一度だけ初期化
-    let node_registry = use_state(|| Rc::new(RefCell::new(NodeRegistry::new_with_config(get_config().node_config.clone()))));
-    let node_slug_mapping = use_state(|| HashMap::<NodeId, String>::new());
-    let physics_world = use_state(|| {
-        let empty_registry = Rc::new(RefCell::new(NodeRegistry::new_with_config(get_config().node_config.clone())));
-        let default_bound = ContainerBound::default();
-        Rc::new(RefCell::new(PhysicsWorld::new(
-            empty_registry,
-            &viewport,
-            *force_settings,
-            default_bound,
-        )))
+    let (articles_data, loading, error) = use_article_index();
+
+    // 記事データが読み込まれたらノードレジストリを一度だけ初期化
+    // 以降の構造の変更はGraphController経由で行い、作り直さない
+    let graph = use_state(|| {
+        GraphController::new(NodeRegistry::new_with_config(
+            get_config().node_config.clone(),
+        ))
+    });
+    // 展開で現れたノードも含めたノードIDと記事の対応（展開のたびに追加される）
+    let node_slug_mapping = use_mut_ref(HashMap::<NodeId, String>::new);
+    // 近傍の展開状態。ホームに表示する記事がない場合は展開しない
+    let expansion = use_mut_ref(|| None::<Expansion>);
+    let expansion_view = use_state(ExpansionView::default);
+    // ノードの並べ方。物理シミュレーション以外は目標位置へアニメーションで移動する
+    let layout_kind = use_state(LayoutKind::default);
+    // タップで記事を開く代わりに展開する（右クリックできないタッチ操作向け）
+    let expand_on_tap = use_state(|| false);
+    // カテゴリ・タグによる絞り込み。URLのクエリから復元し、変更したらクエリに書き戻す
+    let location = use_location();
+    let filter = use_state(|| {
+        location
+            .and_then(|location| location.query::<FilterQuery>().ok())
+            .map(|query| GraphFilter::from(&query))
+            .unwrap_or_default()
     });
 
     // 記事データが初回読み込まれた時のみ初期化
@@ -57,29 +70,206 @@ pub fn node_graph_container(props: &NodeGraphContainerProps) -> Html {
 
             let (new_registry, slug_mapping) =
                 NodeDataManager::create_node_registry_from_articles(data, &props.container_bound);
-            let registry_rc = Rc::new(RefCell::new(new_registry));
-            node_registry.set(Rc::clone(&registry_rc));
-            node_slug_mapping.set(slug_mapping);
-
-            let new_physics_world = PhysicsWorld::new(
-                registry_rc,
-                &viewport,
-                *force_settings,
-                props.container_bound.clone(),
-            );
-            physics_world.set(Rc::new(RefCell::new(new_physics_world)));
+            let home_slugs: Vec<String> = data
+                .articles
+                .iter()
+                .filter(|article| article.metadata.home_display)
+                .map(|article| article.slug.clone())
+                .collect();
+            let new_expansion = (!home_slugs.is_empty()).then(|| {
+                let mut expansion = Expansion::new(home_slugs);
+                expansion.set_view(*expansion_view);
+                expansion
+            });
+            let new_graph = GraphController::new(new_registry);
+            let mut slug_mapping = slug_mapping;
+            // 共有されたURLの絞り込みは、シミュレーションの起動前にそのまま反映する
+            if let Some(new_expansion) = &new_expansion {
+                if !filter.is_empty() {
+                    NodeDataManager::sync_graph(
+                        &new_graph,
+                        new_expansion,
+                        &filter,
+                        data,
+                        &mut slug_mapping,
+                        props.container_bound.center(),
+                    );
+                }
+            }
+            *expansion.borrow_mut() = new_expansion;
+            graph.set(new_graph);
+            *node_slug_mapping.borrow_mut() = slug_mapping;
             initialized.set(true);
         }
     }
 
+    // 表示中のノードを指定のレイアウトで並べ直す
+    let apply_layout = {
+        let graph = graph.clone();
+        let node_slug_mapping = node_slug_mapping.clone();
+        let articles_data = articles_data.clone();
+        let center = props.container_bound.center();
+
+        Rc::new(move |kind: LayoutKind| {
+            let Some(data) = articles_data.as_ref() else {
+                return;
+            };
+            let targets = NodeDataManager::layout_targets(
+                &graph,
+                kind,
+                data,
+                &node_slug_mapping.borrow(),
+                center,
+            );
+            graph.arrange(targets);
+        })
+    };
+
+    // 展開状態・絞り込みを変更し、表示中のグラフに反映する
+    // 固定レイアウト中は、増減したノードも含めて並べ直す
+    let update_graph = {
+        let graph = graph.clone();
+        let expansion = expansion.clone();
+        let node_slug_mapping = node_slug_mapping.clone();
+        let articles_data = articles_data.clone();
+        let center = props.container_bound.center();
+        let apply_layout = apply_layout.clone();
+        let layout_kind = *layout_kind;
+
+        Rc::new(
+            move |update: &dyn Fn(&mut Expansion, &HashMap<NodeId, String>),
+                  filter: &GraphFilter| {
+                let Some(data) = articles_data.as_ref() else {
+                    return;
+                };
+                {
+                    let mut expansion = expansion.borrow_mut();
+                    let Some(expansion) = expansion.as_mut() else {
+                        return;
+                    };
+                    let mut mapping = node_slug_mapping.borrow_mut();
+                    update(expansion, &mapping);
+                    NodeDataManager::sync_graph(
+                        &graph,
+                        expansion,
+                        filter,
+                        data,
+                        &mut mapping,
+                        center,
+                    );
+                }
+                if layout_kind != LayoutKind::Physics {
+                    apply_layout(layout_kind);
+                }
+            },
+        )
+    };
+
+    // ノードの副操作: 近傍を展開、展開済みなら折りたたむ
+    let on_node_expand = {
+        let update_graph = update_graph.clone();
+        let filter = (*filter).clone();
+        Callback::from(move |node_id: NodeId| {
+            update_graph(
+                &|expansion, mapping| {
+                    if let Some(slug) = mapping.get(&node_id) {
+                        expansion.toggle(slug);
+                    }
+                },
+                &filter,
+            );
+        })
+    };
+
+    // 凡例の項目で絞り込みを切り替え、URLに反映する
+    let change_filter = {
+        let update_graph = update_graph.clone();
+        let filter = filter.clone();
+        let navigator = use_navigator();
+        Rc::new(move |change: &dyn Fn(&mut GraphFilter)| {
+            let mut new_filter = (*filter).clone();
+            change(&mut new_filter);
+            update_graph(&|_, _| {}, &new_filter);
+            if let Some(navigator) = &navigator {
+                let result = if new_filter.is_empty() {
+                    navigator.replace(&Route::Home);
+                    Ok(())
+                } else {
+                    navigator.replace_with_query(&Route::Home, &FilterQuery::from(&new_filter))
+                };
+                if let Err(_err) = result {
+                    #[cfg(target_arch = "wasm32")]
+                    web_sys::console::warn_1(
+                        &format!("Failed to update the filter query: {}", _err).into(),
+                    );
+                }
+            }
+            filter.set(new_filter);
+        })
+    };
+    let on_toggle_category = {
+        let change_filter = change_filter.clone();
+        Callback::from(move |category: String| {
+            change_filter(&|filter| filter.toggle_category(&category))
+        })
+    };
+    let on_toggle_tag = {
+        let change_filter = change_filter.clone();
+        Callback::from(move |tag: String| change_filter(&|filter| filter.toggle_tag(&tag)))
+    };
+    // タイムラインの時点を変えると、その日までに書かれた記事だけを表示する
+    let on_timeline_change = {
+        let change_filter = change_filter.clone();
+        Callback::from(move |until: Option<String>| {
+            change_filter(&|filter| filter.until = until.clone())
+        })
+    };
+
+    // ホバー中の記事を先読みし、クリックしたらすぐに開けるようにする
+    let on_node_hover = {
+        let node_slug_mapping = node_slug_mapping.clone();
+        Callback::from(move |node_id: Option<NodeId>| {
+            let mapping = node_slug_mapping.borrow();
+            if let Some(slug) = node_id.and_then(|id| mapping.get(&id)) {
+                if slug != "author" {
+                    DataLoader::prefetch_article(slug);
+                }
+            }
+        })
+    };
+    let node_preview = {
+        let node_slug_mapping = node_slug_mapping.clone();
+        let articles_data = articles_data.clone();
+        Callback::from(move |node_id: NodeId| {
+            let mapping = node_slug_mapping.borrow();
+            let article = mapping.get(&node_id).and_then(|slug| {
+                articles_data
+                    .as_ref()?
+                    .articles
+                    .iter()
+                    .find(|article| &article.slug == slug)
+            });
+            match article {
+                Some(article) => html! { <ArticlePreviewCard article={article.clone()} /> },
+                None => html! {},
+            }
+        })
+    };
+
     // ノードクリック時のナビゲーション処理
     let navigator = use_navigator().unwrap();
     let on_node_click = {
         let navigator = navigator.clone();
         let node_slug_mapping = node_slug_mapping.clone();
+        let expand_on_tap = *expand_on_tap;
+        let on_node_expand = on_node_expand.clone();
 
         Callback::from(move |node_id: NodeId| {
-            if let Some(slug) = node_slug_mapping.get(&node_id) {
+            if expand_on_tap {
+                on_node_expand.emit(node_id);
+                return;
+            }
+            if let Some(slug) = node_slug_mapping.borrow().get(&node_id) {
                 // フォールバック作者ノードの場合はホームに留まる
                 if slug == "author" {
                     #[cfg(target_arch = "wasm32")]
@@ -123,13 +313,130 @@ pub fn node_graph_container(props: &NodeGraphContainerProps) -> Html {
         };
     }
 
+    // 展開の操作パネル
+    let on_toggle_expand_on_tap = {
+        let expand_on_tap = expand_on_tap.clone();
+        Callback::from(move |_: MouseEvent| expand_on_tap.set(!*expand_on_tap))
+    };
+    let on_toggle_view = {
+        let update_graph = update_graph.clone();
+        let filter = (*filter).clone();
+        let expansion_view = expansion_view.clone();
+        Callback::from(move |_: MouseEvent| {
+            let view = match *expansion_view {
+                ExpansionView::KeepGraph => ExpansionView::FrontierOnly,
+                ExpansionView::FrontierOnly => ExpansionView::KeepGraph,
+            };
+            update_graph(&|expansion, _| expansion.set_view(view), &filter);
+            expansion_view.set(view);
+        })
+    };
+    let on_next_layout = {
+        let layout_kind = layout_kind.clone();
+        Callback::from(move |_: MouseEvent| {
+            let kind = layout_kind.next();
+            apply_layout(kind);
+            layout_kind.set(kind);
+        })
+    };
+    let on_collapse_all = {
+        let update_graph = update_graph.clone();
+        let filter = (*filter).clone();
+        Callback::from(move |_: MouseEvent| {
+            update_graph(&|expansion, _| expansion.collapse_all(), &filter);
+        })
+    };
+    let on_export_svg = {
+        let registry = graph.registry();
+        Callback::from(move |_: MouseEvent| {
+            let svg = svg_export::snapshot(&registry.borrow()).to_svg(&svg_export::svg_theme());
+            svg_export::download(&svg);
+        })
+    };
+    // 凡例は絞り込み前の記事で数える（非表示にした項目も戻せるように）
+    let timeline = articles_data.as_ref().map(Timeline::of).unwrap_or_default();
+    let (expanded_count, legend) = match (expansion.borrow().as_ref(), articles_data.as_ref()) {
+        (Some(expansion), Some(data)) => {
+            let visible = expansion.visible(data);
+            let legend = Legend::of(
+                data.articles
+                    .iter()
+                    .filter(|article| visible.contains(&article.slug)),
+            );
+            (Some(expansion.expanded().len()), legend)
+        }
+        _ => (None, Legend::default()),
+    };
+    let controls = match expanded_count {
+        Some(expanded_count) => html! {
+            <>
+                <GraphLegend
+                    legend={legend}
+                    filter={(*filter).clone()}
+                    on_toggle_category={on_toggle_category}
+                    on_toggle_tag={on_toggle_tag}
+                />
+                <div style={LayoutStyles::graph_controls()}>
+                <button
+                    style={ButtonStyles::secondary()}
+                    title="右クリックでも展開・折りたたみできます"
+                    onclick={on_toggle_expand_on_tap}
+                >
+                    { if *expand_on_tap { "タップ: 展開" } else { "タップ: 記事を開く" } }
+                </button>
+                <button style={ButtonStyles::secondary()} onclick={on_toggle_view}>
+                    { match *expansion_view {
+                        ExpansionView::KeepGraph => "表示: グラフ全体",
+                        ExpansionView::FrontierOnly => "表示: 展開先のみ",
+                    } }
+                </button>
+                <button
+                    style={ButtonStyles::secondary()}
+                    title="ノードの並べ方を切り替えます"
+                    onclick={on_next_layout}
+                >
+                    { match *layout_kind {
+                        LayoutKind::Physics => "レイアウト: 物理",
+                        LayoutKind::Radial => "レイアウト: 放射状",
+                        LayoutKind::Hierarchical => "レイアウト: 階層",
+                        LayoutKind::Timeline => "レイアウト: 時系列",
+                        LayoutKind::Category => "レイアウト: カテゴリ",
+                    } }
+                </button>
+                <button
+                    style={ButtonStyles::secondary()}
+                    title="表示中のグラフをSVGファイルとして保存します"
+                    onclick={on_export_svg}
+                >
+                    {"SVGを書き出す"}
+                </button>
+                <TimelineSlider
+                    timeline={timeline}
+                    until={filter.until.clone()}
+                    on_change={on_timeline_change}
+                />
+                if expanded_count > 0 {
+                    <button style={ButtonStyles::secondary()} onclick={on_collapse_all}>
+                        {format!("すべて折りたたむ ({})", expanded_count)}
+                    </button>
+                }
+                </div>
+            </>
+        },
+        None => html! {},
+    };
+
     html! {
         <PhysicsRenderer
-            node_registry={(*node_registry).clone()}
-            physics_world={(*physics_world).clone()}
+            graph={(*graph).clone()}
             container_bound={props.container_bound.clone()}
             container_ref={props.container_ref.clone()}
             on_node_click={on_node_click}
-        />
+            on_node_expand={on_node_expand}
+            on_node_hover={on_node_hover}
+            node_preview={node_preview}
+        >
+            {controls}
+        </PhysicsRenderer>
     }
 }
@@ -1,37 +1,120 @@
-use crate::web::styles::NodeStyles;
+use crate::core::graph::svg::{edge_path, ARROW_KINDS};
+use crate::web::styles::{AnimationStyles, NodeStyles, DARK_THEME};
 use crate::web::types::*;
 use std::cell::RefCell;
 use std::rc::Rc;
 use yew::prelude::*;
 
+/// 矢印のマーカーID（Noneは強調表示用）
+fn arrow_id(kind: Option<ConnectionLineType>) -> String {
+    match kind {
+        Some(kind) => format!("edge-arrow-{:?}", kind),
+        None => "edge-arrow-highlighted".to_string(),
+    }
+}
+
+fn arrow_marker(id: &str, color: &str) -> Html {
+    html! {
+        <marker
+            id={id.to_string()}
+            viewBox="0 0 10 10"
+            refX="10"
+            refY="5"
+            markerWidth="5"
+            markerHeight="5"
+            orient="auto-start-reverse"
+        >
+            <path d="M 0 0 L 10 5 L 0 10 z" fill={color.to_string()} />
+        </marker>
+    }
+}
+
 #[derive(Properties, PartialEq)]
 pub struct NodeRendererProps {
     pub node_registry: Rc<RefCell<NodeRegistry>>,
-    pub on_mouse_down: Callback<(NodeId, MouseEvent)>,
+    pub on_pointer_down: Callback<(NodeId, PointerEvent)>,
+    pub on_context_menu: Callback<(NodeId, MouseEvent)>,
+    /// ホバー・フォーカス中のノード
+    pub focused: Option<NodeId>,
+    /// ホバー・フォーカスの開始（Some）と終了（None）
+    pub on_focus_change: Callback<Option<NodeId>>,
 }
 
 #[function_component(NodeRenderer)]
 pub fn node_renderer(props: &NodeRendererProps) -> Html {
     let registry = props.node_registry.borrow();
+    // 注目中のノードとその隣接ノード以外を薄くする
+    let neighbours = props.focused.map(|id| registry.neighbours(id));
+    let dimmed = |id: NodeId| match (&neighbours, props.focused) {
+        (Some(neighbours), Some(focused)) => id != focused && !neighbours.contains(&id),
+        _ => false,
+    };
 
     html! {
         <>
-            // 背景のエッジ描画
-            <svg style="position: absolute; left: 0; top: 0; width: 100%; height: 100%; z-index: 1; pointer-events: none;">
+            // 背景のエッジ描画（種類ごとの色と太さ、向きは矢印で示す）
+            <svg style="position: absolute; left: 0; top: 0; width: 100%; height: 100%; z-index: 1; pointer-events: none; overflow: visible;">
+                <defs>
+                    { for ARROW_KINDS.iter().map(|kind| arrow_marker(&arrow_id(Some(*kind)), NodeStyles::connection_color(*kind))) }
+                    { arrow_marker(&arrow_id(None), DARK_THEME.accent_blue) }
+                </defs>
                 {
-                    registry.iter_edges().filter_map(|(a, b)| {
-                        let p1 = registry.positions.get(a)?;
-                        let p2 = registry.positions.get(b)?;
+                    registry.connection_lines.iter().filter(|line| line.visible).filter_map(|line| {
+                        let (from, to) = (line.from, line.to);
+                        let radius = |id| registry.radii.get(&id).map(|r| *r as f32);
+                        // 逆向きのエッジもあれば、重ならないよう曲げる
+                        let curved = registry.has_edge(to, from);
+                        let (d, middle) = edge_path(
+                            *registry.positions.get(&from)?,
+                            *registry.positions.get(&to)?,
+                            radius(from)?,
+                            radius(to)?,
+                            curved,
+                        )?;
+                        let kind = line.connection_type;
+                        let highlighted = props.focused.is_some_and(|id| id == from || id == to);
+                        let style = match props.focused {
+                            Some(_) if highlighted => NodeStyles::connection_line_highlighted(),
+                            Some(_) => format!(
+                                "{} {}",
+                                NodeStyles::connection_line(kind, line.strength),
+                                NodeStyles::faded()
+                            ),
+                            None => NodeStyles::connection_line(kind, line.strength),
+                        };
+                        let marker = format!(
+                            "url(#{})",
+                            arrow_id(if highlighted { None } else { Some(kind) })
+                        );
+                        // 双方向のエッジは両端に矢印を付ける
+                        let marker_start = (!kind.is_directed()).then(|| marker.clone());
+                        // 型付きの関係は種類をエッジの中ほどに書く
+                        let label = line.label.as_ref().map(|label| {
+                            let faded = props.focused.is_some() && !highlighted;
+                            html! {
+                                <text
+                                    x={format!("{:.2}", middle.x)}
+                                    y={format!("{:.2}", middle.y)}
+                                    style={format!(
+                                        "{} {}",
+                                        NodeStyles::edge_label(),
+                                        if faded { NodeStyles::faded() } else { "" }
+                                    )}
+                                >
+                                    {label}
+                                </text>
+                            }
+                        });
                         Some(html!{
-                            <line
-                                x1={format!("{:.2}", p1.x)}
-                                y1={format!("{:.2}", p1.y)}
-                                x2={format!("{:.2}", p2.x)}
-                                y2={format!("{:.2}", p2.y)}
-                                stroke="#8a8a8a"
-                                stroke-width="1.5"
-                                style={NodeStyles::connection_line()}
-                            />
+                            <>
+                                <path
+                                    {d}
+                                    {style}
+                                    marker-end={marker}
+                                    marker-start={marker_start}
+                                />
+                                {label}
+                            </>
                         })
                     }).collect::<Html>()
                 }
@@ -43,15 +126,41 @@ pub fn node_renderer(props: &NodeRendererProps) -> Html {
                     let importance = registry.get_node_importance(*id);
                     let inbound_count = registry.get_node_inbound_count(*id);
 
-                    let on_mouse_down = {
-                        let on_mouse_down = props.on_mouse_down.clone();
+                    let pinned = registry.is_pinned(*id);
+                    let exiting = registry.is_exiting(*id);
+                    let expanded = registry.expanded_nodes.contains(id);
+                    let color = registry
+                        .get_node_category(*id)
+                        .map(|category| registry.get_category_color(category).primary.clone());
+
+                    let on_pointer_down = {
+                        let on_pointer_down = props.on_pointer_down.clone();
+                        let id = *id;
+                        Callback::from(move |e: PointerEvent| {
+                            e.stop_propagation();
+                            on_pointer_down.emit((id, e));
+                        })
+                    };
+
+                    let on_context_menu = {
+                        let on_context_menu = props.on_context_menu.clone();
                         let id = *id;
                         Callback::from(move |e: MouseEvent| {
+                            e.prevent_default();
                             e.stop_propagation();
-                            on_mouse_down.emit((id, e));
+                            on_context_menu.emit((id, e));
                         })
                     };
 
+                    let on_focus_change = props.on_focus_change.clone();
+                    let on_focus = {
+                        let on_focus_change = on_focus_change.clone();
+                        let id = *id;
+                        Callback::from(move |_| on_focus_change.emit(Some(id)))
+                    };
+                    let on_blur = Callback::from(move |_| on_focus_change.emit(None));
+                    let dimmed = dimmed(*id);
+
                     html!{
                         <NodeComponent
                             key={id.0}
@@ -61,7 +170,15 @@ pub fn node_renderer(props: &NodeRendererProps) -> Html {
                             content={content.clone()}
                             {importance}
                             {inbound_count}
-                            {on_mouse_down}
+                            {pinned}
+                            {exiting}
+                            {expanded}
+                            {color}
+                            {dimmed}
+                            {on_pointer_down}
+                            {on_context_menu}
+                            {on_focus}
+                            {on_blur}
                         />
                     }
                 }).collect::<Html>()
@@ -76,9 +193,22 @@ pub struct NodeProps {
     pub pos: Position,
     pub radius: i32,
     pub content: NodeContent,
-    pub on_mouse_down: Callback<MouseEvent>,
+    pub on_pointer_down: Callback<PointerEvent>,
+    pub on_context_menu: Callback<MouseEvent>,
+    /// ホバー・フォーカスの開始と終了
+    pub on_focus: Callback<()>,
+    pub on_blur: Callback<()>,
     pub importance: Option<u8>,
     pub inbound_count: usize,
+    pub pinned: bool,
+    /// 退場アニメーション中
+    pub exiting: bool,
+    /// 近傍を展開中
+    pub expanded: bool,
+    /// カテゴリの色（なければ既定の色）
+    pub color: Option<String>,
+    /// 注目中のノードと関係がない
+    pub dimmed: bool,
 }
 
 #[function_component(NodeComponent)]
@@ -91,15 +221,32 @@ pub fn node_component(props: &NodeProps) -> Html {
         _ => "max-width: 80%; max-height: 80%; overflow: hidden; pointer-events: none;",
     };
 
+    let on_enter = props.on_focus.reform(|_: PointerEvent| ());
+    let on_leave = props.on_blur.reform(|_: PointerEvent| ());
+    let on_focus = props.on_focus.reform(|_: FocusEvent| ());
+    let on_blur = props.on_blur.reform(|_: FocusEvent| ());
+
     html! {
         <div
             key={props.id.0.to_string()}
-            onmousedown={props.on_mouse_down.clone()}
+            tabindex="0"
+            onpointerdown={props.on_pointer_down.clone()}
+            oncontextmenu={props.on_context_menu.clone()}
+            onpointerenter={on_enter}
+            onpointerleave={on_leave}
+            onfocus={on_focus}
+            onblur={on_blur}
             style={format!(
-                "{} left: {}px; top: {}px; box-shadow: 0 4px 8px rgba(0,0,0,0.2); z-index: 10; display: flex; justify-content: center; align-items: center; position: absolute; cursor: pointer; transition: transform 0.2s ease-in-out; user-select: none;",
+                "{} left: {}px; top: {}px; box-shadow: 0 4px 8px rgba(0,0,0,0.2); z-index: 10; display: flex; justify-content: center; align-items: center; position: absolute; cursor: pointer; transition: transform 0.2s ease-in-out, opacity 0.2s ease-in-out; user-select: none; touch-action: none; {} {} {} {} {}",
                 NodeStyles::node_circle(props.radius as f64 * 2.0),
                 props.pos.x,
-                props.pos.y
+                props.pos.y,
+                if props.pinned { NodeStyles::pinned_outline() } else { String::new() },
+                if props.expanded { NodeStyles::expanded_ring() } else { String::new() },
+                props.color.as_deref().map(NodeStyles::category_fill).unwrap_or_default(),
+                if props.dimmed { NodeStyles::faded() } else { "" },
+                // 追加されたノードはマウント時に登場アニメーション
+                if props.exiting { AnimationStyles::node_exit() } else { AnimationStyles::node_enter() }
             )}
         >
             <div style={content_container_style}>
@@ -1,160 +1,532 @@
+use crate::core::physics::{unpack_positions, PhysicsCommand};
 use crate::web::components::debug_panel::DebugPanel;
 use crate::web::components::node_renderer::NodeRenderer;
-use crate::web::physics_sim::{PhysicsWorld, Viewport};
-use crate::web::styles::{AnimationStyles, LayoutStyles};
+use crate::web::config::PhysicsConfig;
+use crate::web::graph_controller::GraphController;
+use crate::web::physics_client::PhysicsClient;
+use crate::web::physics_sim::Viewport;
+use crate::web::simulation_loop::now_ms;
+use crate::web::styles::{AnimationStyles, ButtonStyles, LayoutStyles, PreviewCardStyles};
 use crate::web::types::*;
-use std::cell::RefCell;
+use std::collections::HashMap;
 use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
 use yew::prelude::*;
-use yew_hooks::{use_effect_update_with_deps, use_interval, use_window_scroll};
+use yew_hooks::use_effect_update_with_deps;
+
+/// ホイール1pxあたりのズーム量
+const WHEEL_ZOOM_SPEED: f32 = 0.0015;
+/// ズームボタン1回あたりの倍率
+const BUTTON_ZOOM_FACTOR: f32 = 1.25;
+/// タップとドラッグを区別する移動量（px）。指はマウスより揺れるので大きめ
+const MOUSE_DRAG_THRESHOLD: f32 = 5.0;
+const TOUCH_DRAG_THRESHOLD: f32 = 10.0;
+/// 長押しでノードを固定するまでの時間（ms）
+const LONG_PRESS_MS: i32 = 500;
+/// プレビューカードとノードの縁との間隔（px）
+const PREVIEW_GAP: f32 = 8.0;
+
+/// ポインタごとのノード操作状態
+#[derive(Clone, Debug)]
+struct NodeDrag {
+    node: NodeId,
+    start_x: i32,
+    start_y: i32,
+    threshold: f32,
+    pressed_at: f64,
+    dragging: bool,
+    long_pressed: bool,
+}
+
+/// ピンチ開始時の状態
+#[derive(Clone, Copy, PartialEq)]
+struct PinchStart {
+    distance: f32,
+    midpoint: Position,
+    viewport: Viewport,
+}
+
+/// クライアント座標をコンテナ左上基準の座標に変換
+fn local_position(container_ref: &NodeRef, client_x: f32, client_y: f32) -> Position {
+    match container_ref.cast::<web_sys::Element>() {
+        Some(element) => {
+            let rect = element.get_bounding_client_rect();
+            Position {
+                x: client_x - rect.left() as f32,
+                y: client_y - rect.top() as f32,
+            }
+        }
+        None => Position {
+            x: client_x,
+            y: client_y,
+        },
+    }
+}
+
+/// 2本指タッチの距離と中点（コンテナ座標）
+fn pinch_geometry(container_ref: &NodeRef, e: &TouchEvent) -> Option<(f32, Position)> {
+    let touches = e.touches();
+    if touches.length() != 2 {
+        return None;
+    }
+    let a = touches.get(0)?;
+    let b = touches.get(1)?;
+    let dx = (b.client_x() - a.client_x()) as f32;
+    let dy = (b.client_y() - a.client_y()) as f32;
+    let midpoint = local_position(
+        container_ref,
+        (a.client_x() + b.client_x()) as f32 / 2.0,
+        (a.client_y() + b.client_y()) as f32 / 2.0,
+    );
+    Some(((dx * dx + dy * dy).sqrt(), midpoint))
+}
 
 #[derive(Properties)]
 pub struct PhysicsRendererProps {
-    pub node_registry: Rc<RefCell<NodeRegistry>>,
-    pub physics_world: Rc<RefCell<PhysicsWorld>>,
+    pub graph: GraphController,
     pub container_bound: ContainerBound,
     pub container_ref: NodeRef,
     pub on_node_click: Callback<NodeId>,
+    /// 右クリックなどの副操作（近傍の展開・折りたたみ）
+    pub on_node_expand: Callback<NodeId>,
+    /// ノードのホバー・フォーカスの開始（Some）と終了（None）
+    pub on_node_hover: Callback<Option<NodeId>>,
+    /// ホバー・フォーカス中のノードの下に表示するプレビュー
+    pub node_preview: Callback<NodeId, Html>,
+    /// グラフの上に重ねる操作パネル（コンテナ基準で配置する）
+    #[prop_or_default]
+    pub children: Html,
 }
 
 impl PartialEq for PhysicsRendererProps {
     fn eq(&self, other: &Self) -> bool {
-        // Rc<RefCell<T>>の比較は参照の比較のみ行う
-        Rc::ptr_eq(&self.node_registry, &other.node_registry)
-            && Rc::ptr_eq(&self.physics_world, &other.physics_world)
+        // GraphControllerの比較は参照の比較のみ行う
+        self.graph == other.graph
             && self.container_bound == other.container_bound
             && self.container_ref == other.container_ref
+            && self.children == other.children
     }
 }
 
 #[function_component(PhysicsRenderer)]
 pub fn physics_renderer(props: &PhysicsRendererProps) -> Html {
-    let dragged_node_id = use_state(|| None::<NodeId>);
     let viewport = use_state(Viewport::default);
     let force_settings = use_state(ForceSettings::default);
-    let drag_start_pos = use_state(|| None::<(i32, i32)>);
-    let is_dragging = use_state(|| false);
-    let scroll = use_window_scroll();
+    // 複数の指で同時に操作できるよう、ポインタIDごとに状態を持つ
+    // 同一フレーム内に複数のイベントが届くため、再描画を伴わないuse_mut_refで管理
+    let node_drags = use_mut_ref(HashMap::<i32, NodeDrag>::new);
+    // 背景ドラッグによるパン: (ポインタID, 開始時のポインタ位置, 開始時のビューポート)
+    let pan_start = use_state(|| None::<(i32, Position, Viewport)>);
+    let pinch_start = use_state(|| None::<PinchStart>);
+    // ホバー・フォーカス中のノード（隣接ノードの強調とプレビュー用）
+    let focused = use_state(|| None::<NodeId>);
+    // 物理シミュレーション（ワーカーで動き、静止したら止まる。コマンドを送ると再開する）
+    // クライアントはGraphControllerに接続し、構造の変更と同じ経路で送る
+    let send = {
+        let graph = props.graph.clone();
+        Rc::new(move |command: PhysicsCommand| graph.send(command))
+    };
 
-    // 力の設定が変更されたらPhysicsWorldを更新
+    // 力の設定が変更されたらシミュレーションに送る
     {
-        let physics_world = props.physics_world.clone();
-        let force_settings_clone = force_settings.clone();
+        let send = send.clone();
         use_effect_update_with_deps(
-            move |_| {
-                physics_world
-                    .borrow_mut()
-                    .update_force_settings(*force_settings_clone);
+            move |settings| {
+                send(PhysicsCommand::UpdateForceSettings {
+                    settings: **settings,
+                });
                 || {}
             },
             force_settings.clone(),
         );
     }
 
-    // コンテナ境界が変更されたらPhysicsWorldを更新
+    // コンテナ境界が変更されたら作者ノードを引き寄せる中心を更新
     {
-        let physics_world = props.physics_world.clone();
+        let send = send.clone();
         use_effect_update_with_deps(
             move |container_bound| {
                 #[cfg(target_arch = "wasm32")]
                 web_sys::console::log_1(
                     &format!("Container bound changed in effect: {:?}", container_bound).into(),
                 );
-                physics_world
-                    .borrow_mut()
-                    .update_container_bound(container_bound.clone());
+                send(PhysicsCommand::SetCenter {
+                    center: container_bound.center(),
+                });
                 || {}
             },
             props.container_bound.clone(),
         );
     }
 
-    // マウス移動処理
-    let on_mouse_move = {
-        let dragged_node_id = dragged_node_id.clone();
-        let physics_world = props.physics_world.clone();
-        let viewport = viewport.clone();
-        let drag_start_pos = drag_start_pos.clone();
-        let is_dragging = is_dragging.clone();
-
-        Callback::from(move |e: MouseEvent| {
-            if let Some(id) = *dragged_node_id {
-                if let Some((start_x, start_y)) = *drag_start_pos {
-                    let dx = e.client_x() - start_x;
-                    let dy = e.client_y() - start_y;
-                    let distance = ((dx * dx + dy * dy) as f32).sqrt();
-
-                    // 5px以上移動したらドラッグ開始
-                    if distance > 5.0 && !*is_dragging {
-                        is_dragging.set(true);
-                        physics_world.borrow_mut().set_node_kinematic(id);
-                    }
+    // 再描画用（位置はワーカーから届く）
+    let rerender = use_state(|| ());
 
-                    // ドラッグ中の場合のみノード位置を更新
-                    if *is_dragging {
-                        let mut world = physics_world.borrow_mut();
-                        let screen_pos = Position {
-                            x: (e.client_x() + scroll.0 as i32) as f32,
-                            y: (e.client_y() + scroll.1 as i32) as f32,
-                        };
-                        world.set_node_position(id, &screen_pos, &viewport);
-                    }
+    // ノード上でのポインタダウン: キャプチャしてタップ・ドラッグ・長押しを判定
+    let on_node_pointer_down = {
+        let node_drags = node_drags.clone();
+        let node_registry = props.graph.registry();
+        let send = send.clone();
+        let rerender = rerender.clone();
+
+        Callback::from(move |(id, e): (NodeId, PointerEvent)| {
+            // 主ボタン以外（右クリックなど）は無視
+            if e.button() > 0 {
+                return;
+            }
+            e.prevent_default();
+            if let Some(target) = e.current_target() {
+                let _ = target
+                    .unchecked_into::<web_sys::Element>()
+                    .set_pointer_capture(e.pointer_id());
+            }
+
+            let threshold = if e.pointer_type() == "mouse" {
+                MOUSE_DRAG_THRESHOLD
+            } else {
+                TOUCH_DRAG_THRESHOLD
+            };
+            let pointer_id = e.pointer_id();
+            let pressed_at = now_ms();
+            node_drags.borrow_mut().insert(
+                pointer_id,
+                NodeDrag {
+                    node: id,
+                    start_x: e.client_x(),
+                    start_y: e.client_y(),
+                    threshold,
+                    pressed_at,
+                    dragging: false,
+                    long_pressed: false,
+                },
+            );
+            send(PhysicsCommand::Grab { id });
+
+            // 動かさずに押し続けたらノードを固定/固定解除
+            let node_drags = node_drags.clone();
+            let node_registry = node_registry.clone();
+            let send = send.clone();
+            let rerender = rerender.clone();
+            let on_long_press = Closure::once_into_js(move || {
+                let mut drags = node_drags.borrow_mut();
+                let Some(drag) = drags.get_mut(&pointer_id) else {
+                    return;
+                };
+                // 同じ押下が動かされずに続いている場合のみ
+                if drag.pressed_at != pressed_at || drag.dragging || drag.long_pressed {
+                    return;
                 }
+                drag.long_pressed = true;
+                let pinned = node_registry.borrow_mut().toggle_pinned(drag.node);
+                send(PhysicsCommand::SetPinned {
+                    id: drag.node,
+                    pinned,
+                });
+                rerender.set(());
+            });
+            if let Some(window) = web_sys::window() {
+                let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(
+                    on_long_press.unchecked_ref(),
+                    LONG_PRESS_MS,
+                );
+            }
+        })
+    };
+
+    // ノードの副操作（右クリック）で展開・折りたたみ
+    // タッチの長押しでもcontextmenuが届くが、それは固定の操作なので無視する
+    let on_node_context_menu = {
+        let node_drags = node_drags.clone();
+        let on_node_expand = props.on_node_expand.clone();
+
+        Callback::from(move |(id, _): (NodeId, MouseEvent)| {
+            if node_drags.borrow().values().any(|drag| drag.node == id) {
+                return;
             }
+            on_node_expand.emit(id);
         })
     };
 
-    // マウスダウン処理
-    let on_mouse_down = {
-        let dragged_node_id = dragged_node_id.clone();
-        let drag_start_pos = drag_start_pos.clone();
-        let is_dragging = is_dragging.clone();
+    let on_focus_change = {
+        let focused = focused.clone();
+        let on_node_hover = props.on_node_hover.clone();
 
-        Callback::from(move |(id, e): (NodeId, MouseEvent)| {
-            drag_start_pos.set(Some((e.client_x(), e.client_y())));
-            is_dragging.set(false);
-            dragged_node_id.set(Some(id));
+        Callback::from(move |id: Option<NodeId>| {
+            if *focused != id {
+                focused.set(id);
+                on_node_hover.emit(id);
+            }
         })
     };
 
-    // マウスアップ処理
-    let on_mouse_up = {
-        let dragged_node_id = dragged_node_id.clone();
-        let physics_world = props.physics_world.clone();
-        let drag_start_pos = drag_start_pos.clone();
-        let is_dragging = is_dragging.clone();
+    // 注目中のノードの下にプレビューを表示（退場したノードには出さない）
+    let preview = focused.and_then(|id| {
+        let registry = props.graph.registry();
+        let registry = registry.borrow();
+        if registry.is_exiting(id) {
+            return None;
+        }
+        let pos = viewport.world_to_screen(registry.positions.get(&id)?);
+        let radius = *registry.radii.get(&id)? as f32 * viewport.scale;
+        Some(html! {
+            <div style={PreviewCardStyles::card(pos.x, pos.y + radius + PREVIEW_GAP)}>
+                {props.node_preview.emit(id)}
+            </div>
+        })
+    });
+
+    // 背景のポインタダウンでパン開始（マウスのみ。タッチはページのスクロールに任せる）
+    let on_background_pointer_down = {
+        let pan_start = pan_start.clone();
+        let viewport = viewport.clone();
+        let container_ref = props.container_ref.clone();
+
+        Callback::from(move |e: PointerEvent| {
+            if e.pointer_type() != "mouse" || e.button() != 0 {
+                return;
+            }
+            if let Some(element) = container_ref.cast::<web_sys::Element>() {
+                let _ = element.set_pointer_capture(e.pointer_id());
+            }
+            let pos = local_position(&container_ref, e.client_x() as f32, e.client_y() as f32);
+            pan_start.set(Some((e.pointer_id(), pos, *viewport)));
+        })
+    };
+
+    // ポインタ移動処理
+    let on_pointer_move = {
+        let node_drags = node_drags.clone();
+        let send = send.clone();
+        let viewport = viewport.clone();
+        let pan_start = pan_start.clone();
+        let container_ref = props.container_ref.clone();
+
+        Callback::from(move |e: PointerEvent| {
+            let mut drags = node_drags.borrow_mut();
+            if let Some(drag) = drags.get_mut(&e.pointer_id()) {
+                let dx = (e.client_x() - drag.start_x) as f32;
+                let dy = (e.client_y() - drag.start_y) as f32;
+
+                // 閾値以上移動したらドラッグ開始（長押し判定より優先）
+                if !drag.dragging && (dx * dx + dy * dy).sqrt() > drag.threshold {
+                    drag.dragging = true;
+                }
+
+                // ドラッグ中の場合のみノード位置を更新（ビューポートの逆変換でワールド座標へ）
+                if drag.dragging {
+                    let screen_pos =
+                        local_position(&container_ref, e.client_x() as f32, e.client_y() as f32);
+                    send(PhysicsCommand::MoveTo {
+                        id: drag.node,
+                        position: viewport.screen_to_world(&screen_pos),
+                    });
+                }
+            } else if let Some((pointer_id, start, start_viewport)) = *pan_start {
+                if pointer_id == e.pointer_id() {
+                    let pos =
+                        local_position(&container_ref, e.client_x() as f32, e.client_y() as f32);
+                    viewport.set(start_viewport.pan_by(pos.x - start.x, pos.y - start.y));
+                }
+            }
+        })
+    };
+
+    // ポインタアップ処理: 動かさず長押しでもなければタップとしてクリック扱い
+    let on_pointer_up = {
+        let node_drags = node_drags.clone();
+        let send = send.clone();
+        let pan_start = pan_start.clone();
         let on_node_click = props.on_node_click.clone();
 
-        Callback::from(move |_: MouseEvent| {
-            if let Some(id) = *dragged_node_id {
-                if *is_dragging {
-                    physics_world.borrow_mut().set_node_dynamic(id);
-                } else {
-                    on_node_click.emit(id);
+        Callback::from(move |e: PointerEvent| {
+            let drag = node_drags.borrow_mut().remove(&e.pointer_id());
+            if let Some(drag) = drag {
+                send(PhysicsCommand::Release { id: drag.node });
+                if !drag.dragging && !drag.long_pressed {
+                    on_node_click.emit(drag.node);
                 }
             }
 
-            dragged_node_id.set(None);
-            drag_start_pos.set(None);
-            is_dragging.set(false);
+            if matches!(*pan_start, Some((pointer_id, _, _)) if pointer_id == e.pointer_id()) {
+                pan_start.set(None);
+            }
         })
     };
 
-    // 物理シミュレーションのステップ実行
-    let rerender = use_state(|| ());
-    {
-        let physics_world = props.physics_world.clone();
+    // スクロール開始などでブラウザにポインタを奪われた場合はクリックせずに終了
+    let on_pointer_cancel = {
+        let node_drags = node_drags.clone();
+        let send = send.clone();
+        let pan_start = pan_start.clone();
+
+        Callback::from(move |e: PointerEvent| {
+            let drag = node_drags.borrow_mut().remove(&e.pointer_id());
+            if let Some(drag) = drag {
+                send(PhysicsCommand::Release { id: drag.node });
+            }
+
+            if matches!(*pan_start, Some((pointer_id, _, _)) if pointer_id == e.pointer_id()) {
+                pan_start.set(None);
+            }
+        })
+    };
+
+    // ホイールでカーソル位置を中心にズーム
+    let on_wheel = {
+        let viewport = viewport.clone();
+        let container_ref = props.container_ref.clone();
+        let container_height = props.container_bound.height;
+
+        Callback::from(move |e: WheelEvent| {
+            e.prevent_default();
+            // deltaModeに応じてピクセル単位に揃える（1: 行, 2: ページ）
+            let delta = match e.delta_mode() {
+                1 => e.delta_y() as f32 * 16.0,
+                2 => e.delta_y() as f32 * container_height,
+                _ => e.delta_y() as f32,
+            };
+            let anchor = local_position(&container_ref, e.client_x() as f32, e.client_y() as f32);
+            viewport.set(viewport.zoom_at(&anchor, (-delta * WHEEL_ZOOM_SPEED).exp()));
+        })
+    };
+
+    // ピンチズーム（2本指）
+    let on_touch_start = {
+        let pinch_start = pinch_start.clone();
+        let viewport = viewport.clone();
+        let container_ref = props.container_ref.clone();
+
+        let node_drags = node_drags.clone();
+
+        Callback::from(move |e: TouchEvent| {
+            // ノードを指で動かしている間はピンチとみなさない
+            if !node_drags.borrow().is_empty() {
+                return;
+            }
+            if let Some((distance, midpoint)) = pinch_geometry(&container_ref, &e) {
+                pinch_start.set(Some(PinchStart {
+                    distance,
+                    midpoint,
+                    viewport: *viewport,
+                }));
+            }
+        })
+    };
+
+    let on_touch_move = {
+        let pinch_start = pinch_start.clone();
+        let viewport = viewport.clone();
+        let container_ref = props.container_ref.clone();
+
+        Callback::from(move |e: TouchEvent| {
+            if let (Some(start), Some((distance, midpoint))) =
+                (*pinch_start, pinch_geometry(&container_ref, &e))
+            {
+                if start.distance > 0.0 {
+                    // 開始時の中点を基準にズームし、中点の移動分だけパン
+                    let zoomed = start
+                        .viewport
+                        .zoom_at(&start.midpoint, distance / start.distance);
+                    viewport.set(
+                        zoomed.pan_by(midpoint.x - start.midpoint.x, midpoint.y - start.midpoint.y),
+                    );
+                }
+            }
+        })
+    };
+
+    let on_touch_end = {
+        let pinch_start = pinch_start.clone();
+        Callback::from(move |e: TouchEvent| {
+            if e.touches().length() < 2 {
+                pinch_start.set(None);
+            }
+        })
+    };
+
+    // ズームボタン（コンテナ中心を基準）
+    let container_center = Position {
+        x: props.container_bound.width / 2.0,
+        y: props.container_bound.height / 2.0,
+    };
+    let zoom_by = |factor: f32| {
+        let viewport = viewport.clone();
+        Callback::from(move |_: MouseEvent| {
+            viewport.set(viewport.zoom_at(&container_center, factor));
+        })
+    };
+    let on_zoom_in = zoom_by(BUTTON_ZOOM_FACTOR);
+    let on_zoom_out = zoom_by(1.0 / BUTTON_ZOOM_FACTOR);
+
+    // 全ノードが収まるようにズーム
+    let on_zoom_to_fit = {
         let viewport = viewport.clone();
+        let node_registry = props.graph.registry();
+        let width = props.container_bound.width;
+        let height = props.container_bound.height;
+
+        Callback::from(move |_: MouseEvent| {
+            if let Some((min, max)) = node_registry.borrow().bounds() {
+                viewport.set(Viewport::fit_to(&min, &max, width, height));
+            }
+        })
+    };
+
+    // コントロール上の操作でパンが始まらないようにする
+    let stop_propagation = Callback::from(|e: PointerEvent| e.stop_propagation());
+
+    // 物理シミュレーションの起動
+    // グラフが作り直されたら（データ変更時）シミュレーションも作り直す
+    // ノードやエッジの追加・削除はGraphController経由で動いているシミュレーションに反映される
+    {
         let rerender = rerender.clone();
+        let settings = *force_settings;
+        let center = props.container_bound.center();
+
+        use_effect_with(props.graph.clone(), move |graph| {
+            let graph = graph.clone();
+            let node_registry = graph.registry();
+            graph.set_on_change({
+                let rerender = rerender.clone();
+                Callback::from(move |_| rerender.set(()))
+            });
+            let on_positions = {
+                let node_registry = node_registry.clone();
+                Rc::new(move |order: &[_], buffer: &[f32]| {
+                    let mut registry = node_registry.borrow_mut();
+                    for (id, pos) in unpack_positions(order, buffer) {
+                        if let Some(current) = registry.positions.get_mut(&id) {
+                            *current = pos;
+                        }
+                    }
+                    rerender.set(());
+                })
+            };
+            let client = PhysicsClient::new(on_positions);
+            let registry = node_registry.borrow();
+            client.send(PhysicsCommand::Init {
+                nodes: registry.physics_nodes(),
+                edges: registry.physics_edges(),
+                settings,
+                center,
+                start_settled: registry.layout_precomputed
+                    && !PhysicsConfig::ANIMATE_PRECOMPUTED_LAYOUT,
+            });
+            for id in &registry.pinned_nodes {
+                client.send(PhysicsCommand::SetPinned {
+                    id: *id,
+                    pinned: true,
+                });
+            }
+            drop(registry);
+            graph.attach(Some(client));
 
-        use_interval(
             move || {
-                let mut world = physics_world.borrow_mut();
-                world.step(&viewport);
-                rerender.set(());
-            },
-            8, // ~120fps
-        );
+                graph.attach(None);
+                graph.set_on_change(Callback::noop());
+            }
+        });
     }
 
     // 力の設定変更コールバック
@@ -169,11 +541,23 @@ pub fn physics_renderer(props: &PhysicsRendererProps) -> Html {
         <>
             <style>
                 {AnimationStyles::spinner_keyframes()}
+                {AnimationStyles::node_transition_keyframes()}
             </style>
             <div
-                style={LayoutStyles::physics_container()}
-                onmousemove={on_mouse_move}
-                onmouseup={on_mouse_up}
+                style={format!(
+                    "{} cursor: {};",
+                    LayoutStyles::physics_container(),
+                    if pan_start.is_some() { "grabbing" } else { "grab" }
+                )}
+                onpointerdown={on_background_pointer_down}
+                onpointermove={on_pointer_move}
+                onpointerup={on_pointer_up}
+                onpointercancel={on_pointer_cancel}
+                onwheel={on_wheel}
+                ontouchstart={on_touch_start}
+                ontouchmove={on_touch_move}
+                ontouchend={on_touch_end.clone()}
+                ontouchcancel={on_touch_end}
                 ref={props.container_ref.clone()}
             >
                 // ウェルカムオーバーレイていうんや
@@ -208,11 +592,34 @@ pub fn physics_renderer(props: &PhysicsRendererProps) -> Html {
                     on_settings_change={on_settings_change}
                 />
 
-                // ノード描画
-                <NodeRenderer
-                    node_registry={props.node_registry.clone()}
-                    on_mouse_down={on_mouse_down}
-                />
+                // ノード描画（ビューポートの変換をレイヤーごと適用）
+                <div style={LayoutStyles::graph_layer(&viewport.css_transform())}>
+                    <NodeRenderer
+                        node_registry={props.graph.registry()}
+                        on_pointer_down={on_node_pointer_down}
+                        on_context_menu={on_node_context_menu}
+                        focused={*focused}
+                        on_focus_change={on_focus_change}
+                    />
+                </div>
+
+                // ホバー中のノードのプレビュー
+                {preview}
+
+                // 展開や凡例などのグラフ操作
+                <div onpointerdown={stop_propagation.clone()}>
+                    {props.children.clone()}
+                </div>
+
+                // ズーム操作
+                <div style={LayoutStyles::zoom_controls()} onpointerdown={stop_propagation}>
+                    <span style="color: #E0E0E0; font-size: 12px; min-width: 40px; text-align: right;">
+                        {format!("{:.0}%", viewport.scale * 100.0)}
+                    </span>
+                    <button style={ButtonStyles::secondary()} title="縮小" onclick={on_zoom_out}>{"−"}</button>
+                    <button style={ButtonStyles::secondary()} title="拡大" onclick={on_zoom_in}>{"+"}</button>
+                    <button style={ButtonStyles::secondary()} title="全体を表示" onclick={on_zoom_to_fit}>{"⤢"}</button>
+                </div>
             </div>
         </>
     }
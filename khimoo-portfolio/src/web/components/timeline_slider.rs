@@ -0,0 +1,108 @@
+use crate::core::graph::Timeline;
+use crate::web::styles::{ButtonStyles, TimelineStyles};
+use wasm_bindgen::JsCast;
+use yew::prelude::*;
+use yew_hooks::use_interval;
+
+/// 再生時に次の日付へ進む間隔（ミリ秒）
+const PLAY_STEP_MS: u32 = 900;
+
+#[derive(Properties, PartialEq)]
+pub struct TimelineSliderProps {
+    pub timeline: Timeline,
+    /// 表示中の日付（Noneは現在）
+    pub until: Option<String>,
+    pub on_change: Callback<Option<String>>,
+}
+
+/// 記事の作成日をたどってグラフの成長を再生するスライダー
+/// 右端は「現在」で、すべての記事を表示する
+#[function_component(TimelineSlider)]
+pub fn timeline_slider(props: &TimelineSliderProps) -> Html {
+    let playing = use_state(|| false);
+
+    // 再生中は次の日付へ進め、最後まで進んだら現在に戻して止める
+    {
+        let millis = if *playing { PLAY_STEP_MS } else { 0 };
+        let playing = playing.clone();
+        let timeline = props.timeline.clone();
+        let until = props.until.clone();
+        let on_change = props.on_change.clone();
+        use_interval(
+            move || {
+                let next = timeline.next(until.as_deref()).map(str::to_string);
+                if next.is_none() {
+                    playing.set(false);
+                }
+                on_change.emit(next);
+            },
+            millis,
+        );
+    }
+
+    if props.timeline.is_empty() {
+        return html! {};
+    }
+
+    let dates = props.timeline.dates();
+    let now = dates.len();
+    let step = props
+        .until
+        .as_deref()
+        .map_or(now, |until| props.timeline.step_of(until));
+
+    let on_input = {
+        let on_change = props.on_change.clone();
+        let dates = dates.to_vec();
+        let playing = playing.clone();
+        Callback::from(move |e: InputEvent| {
+            let Some(input) = e
+                .target()
+                .and_then(|target| target.dyn_into::<web_sys::HtmlInputElement>().ok())
+            else {
+                return;
+            };
+            let step = input.value().parse::<usize>().unwrap_or(now);
+            playing.set(false);
+            on_change.emit(dates.get(step).cloned());
+        })
+    };
+    let on_play = {
+        let playing = playing.clone();
+        let on_change = props.on_change.clone();
+        let first = dates.first().cloned();
+        let at_now = props.until.is_none();
+        Callback::from(move |_: MouseEvent| {
+            // 現在を表示中なら最初の日付から再生する
+            if !*playing && at_now {
+                on_change.emit(first.clone());
+            }
+            playing.set(!*playing);
+        })
+    };
+
+    html! {
+        <div style={TimelineStyles::container()}>
+            <button
+                style={ButtonStyles::secondary()}
+                title="記事が増えていく様子を再生します"
+                onclick={on_play}
+            >
+                { if *playing { "⏸" } else { "▶" } }
+            </button>
+            <input
+                type="range"
+                min="0"
+                max={now.to_string()}
+                step="1"
+                value={step.to_string()}
+                style={TimelineStyles::slider()}
+                aria-label="表示する時点"
+                oninput={on_input}
+            />
+            <span style={TimelineStyles::label()}>
+                { props.until.clone().unwrap_or_else(|| "現在".to_string()) }
+            </span>
+        </div>
+    }
+}
@@ -12,7 +12,7 @@ impl PhysicsConfig {
     /// 高性能設定（軽量）
     pub fn performance_force_settings() -> ForceSettings {
         ForceSettings {
-            repulsion_strength: 50000.0,
+            repulsion_strength: 100000.0,
             repulsion_min_distance: 100.0,
             author_repulsion_min_distance: 120.0,
             link_strength: 3000.0,
@@ -25,13 +25,14 @@ impl PhysicsConfig {
             category_attraction_strength: 1000.0,
             category_attraction_range: 250.0,
             enable_category_clustering: true,
+            barnes_hut_theta: 1.0,
         }
     }
 
     /// 高品質設定（重い）
     pub fn quality_force_settings() -> ForceSettings {
         ForceSettings {
-            repulsion_strength: 80000.0,
+            repulsion_strength: 160000.0,
             repulsion_min_distance: 180.0,
             author_repulsion_min_distance: 200.0,
             link_strength: 7000.0,
@@ -44,9 +45,14 @@ impl PhysicsConfig {
             category_attraction_strength: 2000.0,
             category_attraction_range: 350.0,
             enable_category_clustering: true,
+            barnes_hut_theta: 0.5,
         }
     }
 
+    /// ビルド時に計算済みのレイアウトから始める場合も、読み込み時にシミュレーションを動かすか
+    /// falseなら最初の操作まで静止したまま表示する
+    pub const ANIMATE_PRECOMPUTED_LAYOUT: bool = false;
+
     /// 物理シミュレーションのフレームレート設定
     pub const PHYSICS_FPS: u32 = 120;
     pub const PHYSICS_INTERVAL_MS: u32 = 1000 / Self::PHYSICS_FPS;
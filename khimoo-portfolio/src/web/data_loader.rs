@@ -1,64 +1,20 @@
-use pulldown_cmark::{Event, Parser};
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::JsFuture;
 use web_sys::{Request, RequestInit, RequestMode, Response};
 use yew::prelude::*;
 
 use crate::config::{get_config, AppConfig};
-use crate::core::articles::links::ExtractedLink;
 use crate::core::articles::metadata::ArticleMetadata;
+use crate::core::model::{is_compatible_schema, SchemaHeader, SCHEMA_VERSION};
 use crate::web::types::data_types::NodeRegistry;
 use crate::web::types::node_types::{ConnectionLineType, NodeContent, NodeId, AUTHOR_NODE_ID};
 use crate::web::types::physics_types::Position;
 
-/// Articles data structure matching CLI output
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct ArticlesData {
-    pub articles: Vec<ProcessedArticle>,
-    pub generated_at: String,
-    pub total_count: usize,
-    pub home_articles: Vec<String>,
-}
-
-/// Processed article data structure matching CLI output
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct ProcessedArticle {
-    pub slug: String,
-    pub title: String,
-    pub metadata: ArticleMetadata,
-    pub file_path: String,
-    pub outbound_links: Vec<ExtractedLink>,
-    pub inbound_links: Vec<ExtractedLink>,
-    pub processed_at: String,
-}
-
-/// Lightweight article data for list display and node graph construction
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct LightweightArticle {
-    pub slug: String,
-    pub title: String,
-    pub metadata: ArticleMetadata,
-    pub inbound_links: Vec<String>, // Pre-calculated for performance optimization
-    pub summary: Option<String>,    // Article summary for list display
-}
-
-impl From<ProcessedArticle> for LightweightArticle {
-    fn from(article: ProcessedArticle) -> Self {
-        Self {
-            slug: article.slug,
-            title: article.title,
-            metadata: article.metadata,
-            inbound_links: article
-                .inbound_links
-                .into_iter()
-                .map(|link| link.target_slug)
-                .collect(),
-            summary: None, // Summary will be loaded separately for performance
-        }
-    }
-}
+// The data model is shared with the CLI
+pub use crate::core::model::{ArticleIndex, ArticleIndexEntry, ProcessedArticle};
 
 /// Error types for data loading
 #[derive(Debug, Clone, PartialEq)]
@@ -67,6 +23,11 @@ pub enum DataLoadError {
     ParseError(String),
     NotFound(String),
     NodeGraphError(String),
+    /// Data was written with a schema version this build cannot read
+    IncompatibleSchema {
+        found: u32,
+        expected: u32,
+    },
 }
 
 impl std::fmt::Display for DataLoadError {
@@ -76,14 +37,28 @@ impl std::fmt::Display for DataLoadError {
             DataLoadError::ParseError(msg) => write!(f, "Parse error: {}", msg),
             DataLoadError::NotFound(msg) => write!(f, "Not found: {}", msg),
             DataLoadError::NodeGraphError(msg) => write!(f, "Node graph error: {}", msg),
+            DataLoadError::IncompatibleSchema { found, expected } => write!(
+                f,
+                "Data built by incompatible CLI version (data schema {}, app expects {}). Rebuild the data with `just process-data`.",
+                found, expected
+            ),
         }
     }
 }
 
 impl std::error::Error for DataLoadError {}
 
+thread_local! {
+    /// Article shards already fetched in this session, by slug
+    static ARTICLE_CACHE: RefCell<HashMap<String, ProcessedArticle>> = RefCell::new(HashMap::new());
+    /// Markdown bodies without front matter, by file path
+    static CONTENT_CACHE: RefCell<HashMap<String, String>> = RefCell::new(HashMap::new());
+    /// Slugs whose prefetch is in flight
+    static PREFETCHING: RefCell<HashSet<String>> = RefCell::new(HashSet::new());
+}
+
 /// DataLoader provides interface between core modules and web components
-/// Handles loading ArticlesData and converting to web-specific data structures
+/// Handles loading the article index and shards and converting to web-specific data structures
 #[derive(Debug, Clone)]
 pub struct DataLoader {
     config: &'static AppConfig,
@@ -97,115 +72,65 @@ impl DataLoader {
         }
     }
 
-    /// Load articles data from JSON file (generated by CLI)
+    /// Load the article index from JSON file (generated by CLI)
     /// This is the primary interface to core module data
-    pub async fn load_articles(&self) -> Result<ArticlesData, DataLoadError> {
-        let url = self.config.data_url("articles.json");
+    pub async fn load_index(&self) -> Result<ArticleIndex, DataLoadError> {
+        let url = self.config.data_url("index.json");
 
-        web_sys::console::log_1(&format!("DataLoader: Loading articles from: {}", url).into());
+        web_sys::console::log_1(&format!("DataLoader: Loading index from: {}", url).into());
 
-        match self.fetch_json::<ArticlesData>(&url).await {
-            Ok(data) => {
+        match self.fetch_json::<ArticleIndex>(&url).await {
+            Ok(index) => {
                 web_sys::console::log_1(
                     &format!(
-                        "DataLoader: Successfully loaded {} articles",
-                        data.articles.len()
+                        "DataLoader: Successfully loaded {} index entries",
+                        index.articles.len()
                     )
                     .into(),
                 );
-                Ok(data)
+                Ok(index)
+            }
+            Err(e @ DataLoadError::IncompatibleSchema { .. }) => {
+                web_sys::console::error_1(&format!("{}", e).into());
+                Err(e)
             }
             Err(e) => {
-                web_sys::console::warn_1(&format!("Failed to load articles data: {}", e).into());
+                web_sys::console::warn_1(&format!("Failed to load article index: {}", e).into());
                 // Fallback to empty data structure
-                Ok(ArticlesData {
-                    articles: Vec::new(),
-                    generated_at: "1970-01-01T00:00:00Z".to_string(),
-                    total_count: 0,
-                    home_articles: Vec::new(),
-                })
+                Ok(ArticleIndex::empty())
             }
         }
     }
 
-    /// Convert ArticlesData to LightweightArticle for performance optimization
+    /// Load lightweight articles (with summaries) for list display
     /// Requirements: 3.2, 3.3 - Data flow optimization
-    pub async fn load_lightweight_articles(
-        &self,
-    ) -> Result<Vec<LightweightArticle>, DataLoadError> {
-        let articles_data = self.load_articles().await?;
-        let lightweight_articles: Vec<LightweightArticle> = articles_data
-            .articles
-            .into_iter()
-            .map(LightweightArticle::from)
-            .collect();
-
-        web_sys::console::log_1(
-            &format!(
-                "DataLoader: Converted to {} lightweight articles",
-                lightweight_articles.len()
-            )
-            .into(),
-        );
-        Ok(lightweight_articles)
-    }
-
-    /// Load lightweight articles with summaries for list display
-    pub async fn load_lightweight_articles_with_summaries(
-        &self,
-    ) -> Result<Vec<LightweightArticle>, DataLoadError> {
-        let articles_data = self.load_articles().await?;
-        let mut lightweight_articles: Vec<LightweightArticle> = Vec::new();
-
-        web_sys::console::log_1(&"DataLoader: Loading articles with summaries...".into());
-
-        for article in articles_data.articles {
-            let mut lightweight_article = LightweightArticle::from(article.clone());
-
-            // Generate summary from content
-            match self.generate_article_summary(&article.file_path).await {
-                Ok(summary) => {
-                    lightweight_article.summary = Some(summary);
-                }
-                Err(e) => {
-                    web_sys::console::warn_1(
-                        &format!("Failed to generate summary for {}: {}", article.slug, e).into(),
-                    );
-                    lightweight_article.summary =
-                        Some("サマリーを読み込めませんでした。".to_string());
-                }
-            }
-
-            lightweight_articles.push(lightweight_article);
-        }
-
-        web_sys::console::log_1(
-            &format!(
-                "DataLoader: Loaded {} articles with summaries",
-                lightweight_articles.len()
-            )
-            .into(),
-        );
-        Ok(lightweight_articles)
+    pub async fn load_lightweight_articles(&self) -> Result<Vec<ArticleIndexEntry>, DataLoadError> {
+        let index = self.load_index().await?;
+        Ok(index.articles)
     }
 
     /// Build NodeRegistry for node graph visualization
     /// Requirements: 3.2, 3.3 - Node graph data construction
     pub async fn build_node_registry(&self) -> Result<NodeRegistry, DataLoadError> {
-        let articles_data = self.load_articles().await?;
+        let index = self.load_index().await?;
+        Ok(self.build_node_registry_from_index(&index))
+    }
+
+    /// Build NodeRegistry from an already loaded index
+    pub fn build_node_registry_from_index(&self, index: &ArticleIndex) -> NodeRegistry {
         let mut registry = NodeRegistry::new_with_config(self.config.node_config.clone());
 
-        web_sys::console::log_1(&"DataLoader: Building node registry from articles data".into());
+        web_sys::console::log_1(&"DataLoader: Building node registry from article index".into());
 
         // Add author node first
-        self.add_author_node(&mut registry)?;
+        self.add_author_node(&mut registry);
 
         // Add article nodes
         let mut node_id_counter = 1u32; // Start after author node (0)
         let mut slug_to_node_id: HashMap<String, NodeId> = HashMap::new();
 
         // First pass: create nodes for all articles
-        for article in &articles_data.articles {
+        for article in &index.articles {
             let node_id = NodeId(node_id_counter);
             node_id_counter += 1;
 
@@ -219,7 +144,12 @@ impl DataLoader {
             };
 
             // Add node to registry
-            registry.add_node(node_id, position, registry.node_config.default_node_radius, content);
+            registry.add_node(
+                node_id,
+                position,
+                registry.node_config.default_node_radius,
+                content,
+            );
 
             // Set category and importance
             if let Some(category) = &article.metadata.category {
@@ -227,15 +157,14 @@ impl DataLoader {
             }
             registry.set_node_importance(node_id, article.metadata.importance);
 
-            // Calculate inbound count for dynamic sizing
-            let inbound_count = article.inbound_links.len();
-            registry.set_node_inbound_count(node_id, inbound_count);
+            // Inbound count for dynamic sizing
+            registry.set_node_inbound_count(node_id, article.inbound_count);
 
             // Update radius based on importance and inbound links
             let dynamic_radius = registry.calculate_dynamic_radius(
                 node_id,
                 Some(article.metadata.importance),
-                inbound_count,
+                article.inbound_count,
             );
             registry.update_node_radius(node_id, dynamic_radius);
 
@@ -243,46 +172,26 @@ impl DataLoader {
         }
 
         // Second pass: create connections between nodes
-        for article in &articles_data.articles {
+        for article in &index.articles {
             if let Some(&from_node_id) = slug_to_node_id.get(&article.slug) {
-                // Add connections to outbound links
-                for link in &article.outbound_links {
-                    if let Some(&to_node_id) = slug_to_node_id.get(&link.target_slug) {
-                        registry.add_edge(from_node_id, to_node_id);
-
-                        // Add connection line with appropriate type and strength
-                        let connection_type = match link.link_type {
-                            crate::core::articles::links::LinkType::MarkdownLink => {
-                                ConnectionLineType::Medium
-                            }
-                            crate::core::articles::links::LinkType::ExternalLink => {
-                                ConnectionLineType::Weak
-                            }
-                        };
-
-                        let strength = match connection_type {
-                            ConnectionLineType::Strong => 1.0,
-                            ConnectionLineType::Medium => 0.7,
-                            ConnectionLineType::Weak => 0.4,
-                            _ => 0.5,
-                        };
-
+                // Add connections to internal links
+                for target_slug in &article.outbound_links {
+                    if let Some(&to_node_id) = slug_to_node_id.get(target_slug) {
                         registry.add_connection_line(
                             from_node_id,
                             to_node_id,
-                            connection_type,
-                            strength,
+                            ConnectionLineType::Medium,
+                            0.7,
                         );
                     }
                 }
 
                 // Add connection from author to home display articles
                 if article.metadata.home_display {
-                    registry.add_edge(AUTHOR_NODE_ID, from_node_id);
                     registry.add_connection_line(
                         AUTHOR_NODE_ID,
                         from_node_id,
-                        ConnectionLineType::Medium,
+                        ConnectionLineType::AuthorToArticle,
                         0.8,
                     );
                 }
@@ -298,39 +207,74 @@ impl DataLoader {
             .into(),
         );
 
-        Ok(registry)
+        registry
+    }
+
+    /// Article shard fetched earlier in this session, if any
+    pub fn cached_article(slug: &str) -> Option<ProcessedArticle> {
+        ARTICLE_CACHE.with(|cache| cache.borrow().get(slug).cloned())
+    }
+
+    /// Markdown body fetched earlier in this session, if any
+    pub fn cached_content(file_path: &str) -> Option<String> {
+        CONTENT_CACHE.with(|cache| cache.borrow().get(file_path).cloned())
+    }
+
+    /// Fetch an article's shard and markdown in the background so opening it is instant
+    pub fn prefetch_article(slug: &str) {
+        if Self::cached_article(slug)
+            .is_some_and(|article| Self::cached_content(&article.file_path).is_some())
+        {
+            return;
+        }
+        if !PREFETCHING.with(|prefetching| prefetching.borrow_mut().insert(slug.to_string())) {
+            return;
+        }
+
+        let slug = slug.to_string();
+        wasm_bindgen_futures::spawn_local(async move {
+            let loader = DataLoader::new();
+            if let Ok(article) = loader.load_article_by_slug(&slug).await {
+                if let Err(e) = loader.load_article_content_only(&article.file_path).await {
+                    web_sys::console::warn_1(
+                        &format!("DataLoader: Failed to prefetch {}: {}", slug, e).into(),
+                    );
+                }
+            }
+            PREFETCHING.with(|prefetching| prefetching.borrow_mut().remove(&slug));
+        });
     }
 
     /// Load article by slug (metadata only, content loaded separately)
+    /// Fetches only the article's own shard instead of the whole data set
     pub async fn load_article_by_slug(
         &self,
         slug: &str,
     ) -> Result<ProcessedArticle, DataLoadError> {
-        web_sys::console::log_1(
-            &format!("DataLoader: Looking for article with slug: {}", slug).into(),
-        );
-
-        let articles_data = self.load_articles().await?;
+        if let Some(article) = Self::cached_article(slug) {
+            return Ok(article);
+        }
 
-        let found_article = articles_data
-            .articles
-            .into_iter()
-            .find(|article| article.slug == slug);
+        let url = self.config.data_url(&format!("articles/{}.json", slug));
+        web_sys::console::log_1(&format!("DataLoader: Loading article from: {}", url).into());
 
-        match found_article {
-            Some(article) => {
+        match self.fetch_json::<ProcessedArticle>(&url).await {
+            Ok(article) => {
                 web_sys::console::log_1(
                     &format!("DataLoader: Found article: {}", article.title).into(),
                 );
+                ARTICLE_CACHE
+                    .with(|cache| cache.borrow_mut().insert(slug.to_string(), article.clone()));
                 Ok(article)
             }
-            None => {
+            Err(DataLoadError::NotFound(_)) => {
                 web_sys::console::log_1(&format!("DataLoader: Article not found: {}", slug).into());
                 Err(DataLoadError::NotFound(format!(
                     "Article not found: {}",
                     slug
                 )))
             }
+            Err(e) => Err(e),
         }
     }
 
@@ -387,16 +331,26 @@ impl DataLoader {
         &self,
         file_path: &str,
     ) -> Result<String, DataLoadError> {
+        if let Some(content) = Self::cached_content(file_path) {
+            return Ok(content);
+        }
+
         let full_content = self.load_article_content(file_path).await?;
 
         // Parse and extract only the markdown content (without metadata)
         let content_only = self.parse_content_only(&full_content);
 
         web_sys::console::log_1(&"DataLoader: Successfully separated content from metadata".into());
+        CONTENT_CACHE.with(|cache| {
+            cache
+                .borrow_mut()
+                .insert(file_path.to_string(), content_only.clone())
+        });
         Ok(content_only)
     }
 
-    /// Generic JSON fetching method
+    /// Generic JSON fetching method for versioned data files
+    /// The schema version is checked before the file is deserialized into `T`
     async fn fetch_json<T>(&self, url: &str) -> Result<T, DataLoadError>
     where
         T: for<'de> Deserialize<'de>,
@@ -435,6 +389,16 @@ impl DataLoader {
         .await
         .map_err(|e| DataLoadError::ParseError(format!("Failed to parse JSON: {:?}", e)))?;
 
+        let header: SchemaHeader = serde_wasm_bindgen::from_value(json.clone()).map_err(|e| {
+            DataLoadError::ParseError(format!("Failed to read schema version: {:?}", e))
+        })?;
+        if !is_compatible_schema(header.schema_version) {
+            return Err(DataLoadError::IncompatibleSchema {
+                found: header.schema_version,
+                expected: SCHEMA_VERSION,
+            });
+        }
+
         let data: T = serde_wasm_bindgen::from_value(json)
             .map_err(|e| DataLoadError::ParseError(format!("Failed to deserialize: {:?}", e)))?;
 
@@ -442,7 +406,7 @@ impl DataLoader {
     }
 
     /// Add author node to the registry
-    fn add_author_node(&self, registry: &mut NodeRegistry) -> Result<(), DataLoadError> {
+    fn add_author_node(&self, registry: &mut NodeRegistry) {
         let author_position = Position { x: 0.0, y: 0.0 }; // Center position
 
         registry.add_author_node(
@@ -451,8 +415,6 @@ impl DataLoader {
             "/assets/img/author_img_small.webp".to_string(),
             Some("Software Developer & Content Creator".to_string()),
         );
-
-        Ok(())
     }
 
     /// Calculate node position based on metadata
@@ -474,81 +436,6 @@ impl DataLoader {
         }
     }
 
-    /// Generate summary from article content
-    pub async fn generate_article_summary(&self, file_path: &str) -> Result<String, DataLoadError> {
-        let content = self.load_article_content_only(file_path).await?;
-
-        // Extract first paragraph or first few sentences as summary
-        let summary = self.extract_summary_from_content(&content);
-        Ok(summary)
-    }
-
-    /// Extract summary from article content (first paragraph or 150 characters)
-    fn extract_summary_from_content(&self, content: &str) -> String {
-        // First, convert markdown to plain text
-        let plain_text = self.extract_plain_text_from_markdown(content);
-        let content = plain_text.trim();
-
-        // Try to get first paragraph
-        if let Some(first_paragraph) = content.split("\n\n").next() {
-            let cleaned = first_paragraph
-                .lines()
-                .map(|line| line.trim())
-                .filter(|line| !line.is_empty())
-                .collect::<Vec<_>>()
-                .join(" ");
-
-            if !cleaned.is_empty() {
-                return self.truncate_string_safely(&cleaned, 150);
-            }
-        }
-
-        // Fallback: take first 150 characters
-        let cleaned = content
-            .lines()
-            .map(|line| line.trim())
-            .filter(|line| !line.is_empty())
-            .collect::<Vec<_>>()
-            .join(" ");
-
-        if cleaned.is_empty() {
-            "記事の概要はありません。".to_string()
-        } else {
-            self.truncate_string_safely(&cleaned, 150)
-        }
-    }
-
-    /// Safely truncate string at character boundary
-    fn truncate_string_safely(&self, text: &str, max_len: usize) -> String {
-        if text.len() <= max_len {
-            return text.to_string();
-        }
-
-        // Find safe truncation point
-        let mut truncate_at = max_len - 3; // Reserve space for "..."
-
-        // Move back to find character boundary
-        while truncate_at > 0 && !text.is_char_boundary(truncate_at) {
-            truncate_at -= 1;
-        }
-
-        format!("{}...", &text[..truncate_at])
-    }
-
-    /// Extract plain text from markdown using pulldown-cmark
-    fn extract_plain_text_from_markdown(&self, content: &str) -> String {
-        let parser = Parser::new(content);
-        let mut plain_text = String::new();
-
-        for event in parser {
-            if let Event::Text(text) = event {
-                plain_text.push_str(&text);
-            }
-        }
-
-        plain_text
-    }
-
     /// Parse content only (remove front matter)
     fn parse_content_only(&self, content: &str) -> String {
         let content = content.trim();
@@ -590,10 +477,10 @@ pub fn use_data_loader() -> UseStateHandle<Option<DataLoader>> {
     use_state(|| Some(DataLoader::new()))
 }
 
-/// Hook for loading articles data
+/// Hook for loading the article index
 #[hook]
-pub fn use_articles_data() -> (
-    UseStateHandle<Option<ArticlesData>>,
+pub fn use_article_index() -> (
+    UseStateHandle<Option<ArticleIndex>>,
     UseStateHandle<bool>,
     UseStateHandle<Option<DataLoadError>>,
 ) {
@@ -613,9 +500,9 @@ pub fn use_articles_data() -> (
 
             wasm_bindgen_futures::spawn_local(async move {
                 let loader = DataLoader::new();
-                match loader.load_articles().await {
-                    Ok(articles_data) => {
-                        data.set(Some(articles_data));
+                match loader.load_index().await {
+                    Ok(index) => {
+                        data.set(Some(index));
                         error.set(None);
                     }
                     Err(e) => {
@@ -632,10 +519,10 @@ pub fn use_articles_data() -> (
     (data, loading, error)
 }
 
-/// Hook for loading lightweight articles (for list display)
+/// Hook for loading lightweight articles with summaries (for list display)
 #[hook]
 pub fn use_lightweight_articles() -> (
-    UseStateHandle<Option<Vec<LightweightArticle>>>,
+    UseStateHandle<Option<Vec<ArticleIndexEntry>>>,
     UseStateHandle<bool>,
     UseStateHandle<Option<DataLoadError>>,
 ) {
@@ -674,48 +561,6 @@ pub fn use_lightweight_articles() -> (
     (data, loading, error)
 }
 
-/// Hook for loading lightweight articles with summaries (for list display)
-#[hook]
-pub fn use_lightweight_articles_with_summaries() -> (
-    UseStateHandle<Option<Vec<LightweightArticle>>>,
-    UseStateHandle<bool>,
-    UseStateHandle<Option<DataLoadError>>,
-) {
-    let data = use_state(|| None);
-    let loading = use_state(|| true);
-    let error = use_state(|| None);
-
-    {
-        let data = data.clone();
-        let loading = loading.clone();
-        let error = error.clone();
-
-        use_effect_with((), move |_| {
-            let data = data.clone();
-            let loading = loading.clone();
-            let error = error.clone();
-
-            wasm_bindgen_futures::spawn_local(async move {
-                let loader = DataLoader::new();
-                match loader.load_lightweight_articles_with_summaries().await {
-                    Ok(lightweight_articles) => {
-                        data.set(Some(lightweight_articles));
-                        error.set(None);
-                    }
-                    Err(e) => {
-                        error.set(Some(e));
-                    }
-                }
-                loading.set(false);
-            });
-
-            || {}
-        });
-    }
-
-    (data, loading, error)
-}
-
 /// Hook for loading node registry (for node graph)
 #[hook]
 pub fn use_node_registry() -> (
@@ -777,7 +622,12 @@ pub fn use_article_content(
         let error = error.clone();
 
         use_effect_with(slug.clone(), move |slug| {
-            if let Some(slug) = slug {
+            // Prefetched articles are shown without a loading state
+            if let Some(article) = slug.as_deref().and_then(DataLoader::cached_article) {
+                data.set(Some(article));
+                loading.set(false);
+                error.set(None);
+            } else if let Some(slug) = slug {
                 web_sys::console::log_1(
                     &format!("use_article_content: Loading article with slug: {}", slug).into(),
                 );
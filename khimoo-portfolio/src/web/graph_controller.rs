@@ -0,0 +1,195 @@
+//! グラフの構造を表示中に変更するための窓口
+//!
+//! ノードとエッジの追加・削除をノードレジストリと物理シミュレーションの両方に反映する。
+//! フィルタや展開、データの再読み込みでグラフを作り直さずに済むようにする。
+
+use crate::core::physics::PhysicsCommand;
+use crate::web::physics_client::PhysicsClient;
+use crate::web::styles::AnimationStyles;
+use crate::web::types::*;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use yew::Callback;
+
+#[derive(Clone)]
+pub struct GraphController {
+    inner: Rc<ControllerInner>,
+}
+
+struct ControllerInner {
+    registry: Rc<RefCell<NodeRegistry>>,
+    /// 描画側が起動した物理シミュレーション
+    physics: RefCell<Option<PhysicsClient>>,
+    /// レジストリを変更した後に呼ぶ（再描画用）
+    on_change: RefCell<Callback<()>>,
+    /// 退場中のノードごとの削除予約。予約後に戻されたノードを消さないために使う
+    exits: RefCell<HashMap<NodeId, u32>>,
+    next_exit: Cell<u32>,
+    /// 固定レイアウトの目標位置（Noneは物理シミュレーション）
+    layout: RefCell<Option<Vec<(NodeId, Position)>>>,
+}
+
+impl PartialEq for GraphController {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl GraphController {
+    pub fn new(registry: NodeRegistry) -> Self {
+        Self {
+            inner: Rc::new(ControllerInner {
+                registry: Rc::new(RefCell::new(registry)),
+                physics: RefCell::new(None),
+                on_change: RefCell::new(Callback::noop()),
+                exits: RefCell::new(HashMap::new()),
+                next_exit: Cell::new(0),
+                layout: RefCell::new(None),
+            }),
+        }
+    }
+
+    pub fn registry(&self) -> Rc<RefCell<NodeRegistry>> {
+        Rc::clone(&self.inner.registry)
+    }
+
+    /// 物理シミュレーションを接続（Noneで切断）
+    /// 固定レイアウト中なら、接続したシミュレーションにも目標位置を送る
+    pub fn attach(&self, client: Option<PhysicsClient>) {
+        *self.inner.physics.borrow_mut() = client;
+        let layout = self.inner.layout.borrow().clone();
+        if layout.is_some() {
+            self.send(PhysicsCommand::Arrange { targets: layout });
+        }
+    }
+
+    /// ノードを目標位置へ移動させて固定する。Noneで物理シミュレーションに戻す
+    pub fn arrange(&self, targets: Option<Vec<(NodeId, Position)>>) {
+        *self.inner.layout.borrow_mut() = targets.clone();
+        self.send(PhysicsCommand::Arrange { targets });
+    }
+
+    pub fn set_on_change(&self, on_change: Callback<()>) {
+        *self.inner.on_change.borrow_mut() = on_change;
+    }
+
+    /// 物理シミュレーションにコマンドを送る（未接続なら何もしない）
+    pub fn send(&self, command: PhysicsCommand) {
+        if let Some(physics) = self.inner.physics.borrow().as_ref() {
+            physics.send(command);
+        }
+    }
+
+    /// ノードを追加（登場アニメーション付き）。退場中のノードなら呼び戻す
+    /// `add`でレジストリに位置・内容・重要度などを登録し、それをもとにシミュレーションに加える。
+    /// 既に表示中のノードなら何もせずfalseを返す
+    pub fn add_node(&self, id: NodeId, add: impl FnOnce(&mut NodeRegistry)) -> bool {
+        let node = {
+            let mut registry = self.inner.registry.borrow_mut();
+            if registry.contains_node(id) && !registry.is_exiting(id) {
+                return false;
+            }
+            registry.exiting_nodes.remove(&id);
+            add(&mut registry);
+            registry.physics_node(id)
+        };
+        self.inner.exits.borrow_mut().remove(&id);
+        if let Some(node) = node {
+            self.send(PhysicsCommand::AddNode { node });
+        }
+        self.notify();
+        true
+    }
+
+    /// ノードを退場させる。物理シミュレーションとエッジからはすぐに外し、
+    /// アニメーションが終わったらレジストリからも削除する
+    /// シミュレーションの起動前（初期表示の組み立て中）はアニメーションせずに削除する
+    pub fn remove_node(&self, id: NodeId) -> bool {
+        if self.inner.physics.borrow().is_none() {
+            let removed = self.inner.registry.borrow_mut().remove_node(id);
+            if removed {
+                self.notify();
+            }
+            return removed;
+        }
+        {
+            let mut registry = self.inner.registry.borrow_mut();
+            if !registry.contains_node(id) || registry.is_exiting(id) {
+                return false;
+            }
+            registry.exiting_nodes.insert(id);
+            registry.pinned_nodes.remove(&id);
+            registry.remove_edges_of(id);
+        }
+        self.send(PhysicsCommand::RemoveNode { id });
+
+        let exit = self.inner.next_exit.get();
+        self.inner.next_exit.set(exit.wrapping_add(1));
+        self.inner.exits.borrow_mut().insert(id, exit);
+
+        let inner = Rc::downgrade(&self.inner);
+        let on_exited = Closure::once_into_js(move || {
+            let Some(inner) = inner.upgrade() else {
+                return;
+            };
+            // 予約後に呼び戻されたり、再度退場したりしていれば何もしない
+            if inner.exits.borrow().get(&id) != Some(&exit) {
+                return;
+            }
+            inner.exits.borrow_mut().remove(&id);
+            inner.registry.borrow_mut().remove_node(id);
+            inner.on_change.borrow().emit(());
+        });
+        if let Some(window) = web_sys::window() {
+            let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(
+                on_exited.unchecked_ref(),
+                AnimationStyles::NODE_TRANSITION_MS,
+            );
+        }
+        self.notify();
+        true
+    }
+
+    /// エッジを追加。両端のノードが表示中でなければ追加しない
+    pub fn add_edge(&self, edge: Edge, label: Option<String>) -> bool {
+        {
+            let mut registry = self.inner.registry.borrow_mut();
+            let visible = |id| registry.contains_node(id) && !registry.is_exiting(id);
+            if !visible(edge.from) || !visible(edge.to) || registry.has_edge(edge.from, edge.to) {
+                return false;
+            }
+            registry.add_typed_edge(edge, label);
+        }
+        self.send(PhysicsCommand::AddEdge { edge });
+        self.notify();
+        true
+    }
+
+    pub fn remove_edge(&self, from: NodeId, to: NodeId) -> bool {
+        if !self.inner.registry.borrow_mut().remove_edge(from, to) {
+            return false;
+        }
+        self.send(PhysicsCommand::RemoveEdge { from, to });
+        self.notify();
+        true
+    }
+
+    /// 表示中（退場中を除く）のノード
+    pub fn shown_nodes(&self) -> Vec<NodeId> {
+        let registry = self.inner.registry.borrow();
+        registry
+            .positions
+            .keys()
+            .filter(|id| !registry.is_exiting(**id))
+            .copied()
+            .collect()
+    }
+
+    /// レジストリを直接変更した後に再描画させる
+    pub fn notify(&self) {
+        self.inner.on_change.borrow().emit(());
+    }
+}
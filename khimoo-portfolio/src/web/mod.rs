@@ -8,11 +8,16 @@ pub mod article_manager;
 pub mod components;
 pub mod config;
 pub mod data_loader;
+pub mod graph_controller;
 pub mod header;
 pub mod pages;
+pub mod physics_client;
 pub mod physics_sim;
+pub mod physics_worker;
 pub mod routes;
+pub mod simulation_loop;
 pub mod styles;
+pub mod svg_export;
 pub mod types;
 
 // Re-export commonly used items
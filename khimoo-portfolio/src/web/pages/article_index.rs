@@ -1,12 +1,12 @@
 use crate::web::components::ArticleStateRenderer;
-use crate::web::data_loader::{use_lightweight_articles_with_summaries, LightweightArticle};
+use crate::web::data_loader::{use_lightweight_articles, ArticleIndexEntry};
 use crate::web::routes::Route;
 use yew::prelude::*;
 use yew_router::prelude::*;
 
 #[function_component(ArticleIndexPage)]
 pub fn article_index_page() -> Html {
-    let (articles, loading, error) = use_lightweight_articles_with_summaries();
+    let (articles, loading, error) = use_lightweight_articles();
 
     if *loading {
         return ArticleStateRenderer::render_index_loading();
@@ -28,7 +28,7 @@ pub fn article_index_page() -> Html {
     }
 }
 
-fn render_articles_list(articles: &Option<Vec<LightweightArticle>>) -> Html {
+fn render_articles_list(articles: &Option<Vec<ArticleIndexEntry>>) -> Html {
     if let Some(articles_list) = articles.as_ref() {
         html! {
             <ul style="list-style: none; padding: 0;">
@@ -44,7 +44,7 @@ fn render_articles_list(articles: &Option<Vec<LightweightArticle>>) -> Html {
     }
 }
 
-fn render_article_item(article: &LightweightArticle) -> Html {
+fn render_article_item(article: &ArticleIndexEntry) -> Html {
     html! {
         <li key={article.slug.clone()} class="article-item">
             <h3 class="article-title">
@@ -58,7 +58,7 @@ fn render_article_item(article: &LightweightArticle) -> Html {
     }
 }
 
-fn render_article_summary(article: &LightweightArticle) -> Html {
+fn render_article_summary(article: &ArticleIndexEntry) -> Html {
     if let Some(summary) = &article.summary {
         html! {
             <p class="article-summary">{summary}</p>
@@ -68,11 +68,12 @@ fn render_article_summary(article: &LightweightArticle) -> Html {
     }
 }
 
-fn render_article_meta(article: &LightweightArticle) -> Html {
+fn render_article_meta(article: &ArticleIndexEntry) -> Html {
     html! {
         <div class="article-meta">
             {render_category(&article.metadata.category)}
-            <span>{"Links: "}{article.inbound_links.len()}</span>
+            <span style="margin-right: 16px;">{"Links: "}{article.inbound_count}</span>
+            {render_reading_time(article.stats.reading_time_minutes)}
         </div>
     }
 }
@@ -85,6 +86,14 @@ fn render_category(category: &Option<String>) -> Html {
     }
 }
 
+fn render_reading_time(minutes: u32) -> Html {
+    if minutes > 0 {
+        html! { <span>{minutes}{" min read"}</span> }
+    } else {
+        html! {}
+    }
+}
+
 fn index_styles() -> &'static str {
     r#"
     body {
@@ -1,4 +1,4 @@
-use crate::web::components::{ArticleContent, ArticleHeader, ArticleStateRenderer};
+use crate::web::components::{ArticleContent, ArticleHeader, ArticleStateRenderer, LocalGraph};
 use crate::web::data_loader::{use_article_content, DataLoader};
 use yew::prelude::*;
 
@@ -23,7 +23,15 @@ pub fn article_view_page(props: &ArticleViewProps) -> Html {
         let content_error = content_error.clone();
 
         use_effect_with(article.clone(), move |article| {
-            if let Some(article_data) = article.as_ref() {
+            // 先読み済みの本文はそのまま表示する
+            let cached = article
+                .as_ref()
+                .and_then(|article_data| DataLoader::cached_content(&article_data.file_path));
+            if let Some(content) = cached {
+                article_content.set(Some(content));
+                content_loading.set(false);
+                content_error.set(None);
+            } else if let Some(article_data) = article.as_ref() {
                 let file_path = article_data.file_path.clone();
                 let article_content = article_content.clone();
                 let content_loading = content_loading.clone();
@@ -84,6 +92,9 @@ pub fn article_view_page(props: &ArticleViewProps) -> Html {
                                 content={raw_content.clone()}
                             />
                         </article>
+                        <div class="article-sidebar">
+                            <LocalGraph slug={props.slug.clone()} />
+                        </div>
                     </div>
                 </>
             };
@@ -111,5 +122,17 @@ fn article_styles() -> &'static str {
         background: #081D35; 
         min-height: 100vh;
     }
+    /* 幅があればサイドバーを記事の右に置く */
+    .article-sidebar { margin-top: 32px; }
+    @media (min-width: 1200px) {
+        .article-container {
+            max-width: 1100px;
+            display: grid;
+            grid-template-columns: minmax(0, 800px) 276px;
+            gap: 24px;
+            align-items: start;
+        }
+        .article-sidebar { margin-top: 0; position: sticky; top: 16px; }
+    }
     "#
 }
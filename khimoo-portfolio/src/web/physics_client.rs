@@ -0,0 +1,326 @@
+//! 物理シミュレーションのメインスレッド側の窓口
+//!
+//! 通常は専用ワーカーでエンジンを動かし、コマンドを送って位置のバッファを受け取る。
+//! ワーカーを起動できない環境では同じスレッドでエンジンを動かす。
+
+use crate::config::get_config;
+use crate::core::physics::{NodeId, NodeOrder, PhysicsCommand, PhysicsEngine};
+use crate::web::config::PhysicsConfig;
+use crate::web::simulation_loop::SimulationLoop;
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::rc::{Rc, Weak};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{MessageEvent, Worker};
+
+/// Trunkが生成するワーカーの読み込みスクリプト
+const WORKER_SCRIPT: &str = "physics_worker_loader.js";
+
+/// 位置の受け取り先。ノードの並びと、その順に詰めた[x0, y0, x1, y1, ...]を受け取る
+pub type PositionsCallback = Rc<dyn Fn(&[NodeId], &[f32])>;
+
+pub struct PhysicsClient {
+    inner: Rc<RefCell<ClientInner>>,
+    visibility_listener: Closure<dyn FnMut()>,
+}
+
+struct ClientInner {
+    backend: Option<Backend>,
+    /// 位置バッファのノード順。ワーカーと同じコマンドを適用して揃える
+    order: NodeOrder,
+    /// 直近のInit。その後のグラフ・設定・中心の変更を反映しておき、フォールバック時に再送する
+    init: Option<PhysicsCommand>,
+    /// Init以降の直近のArrange。フォールバック時にInitの後に再送する
+    arrange: Option<PhysicsCommand>,
+    /// 固定中のノード。フォールバック時にSetPinnedとして再送する
+    pinned: HashSet<NodeId>,
+    /// 直近のPause/Resume。フォールバック時に引き継ぐ
+    paused: bool,
+    on_positions: PositionsCallback,
+}
+
+enum Backend {
+    Worker(WorkerBackend),
+    Local(LocalBackend),
+}
+
+impl PhysicsClient {
+    pub fn new(on_positions: PositionsCallback) -> Self {
+        let inner = Rc::new(RefCell::new(ClientInner {
+            backend: None,
+            order: NodeOrder::default(),
+            init: None,
+            arrange: None,
+            pinned: HashSet::new(),
+            paused: false,
+            on_positions,
+        }));
+
+        let backend = match WorkerBackend::spawn(Rc::downgrade(&inner)) {
+            Ok(worker) => Backend::Worker(worker),
+            Err(err) => {
+                web_sys::console::warn_2(
+                    &"Physics worker unavailable, running on the main thread:".into(),
+                    &err,
+                );
+                let on_positions = Rc::clone(&inner.borrow().on_positions);
+                Backend::Local(LocalBackend::new(on_positions))
+            }
+        };
+        inner.borrow_mut().backend = Some(backend);
+
+        // ページが非表示の間はシミュレーションを止める
+        // どちらの実装でも同じ経路で送り、フォールバック時に引き継げるようにする
+        let visibility_listener = {
+            let inner = Rc::downgrade(&inner);
+            Closure::wrap(Box::new(move || {
+                let Some(inner) = inner.upgrade() else {
+                    return;
+                };
+                let hidden = web_sys::window()
+                    .and_then(|w| w.document())
+                    .is_some_and(|document| document.hidden());
+                let command = if hidden {
+                    PhysicsCommand::Pause
+                } else {
+                    PhysicsCommand::Resume
+                };
+                inner.borrow_mut().send(command);
+            }) as Box<dyn FnMut()>)
+        };
+        if let Some(document) = web_sys::window().and_then(|w| w.document()) {
+            let _ = document.add_event_listener_with_callback(
+                "visibilitychange",
+                visibility_listener.as_ref().unchecked_ref(),
+            );
+        }
+
+        Self {
+            inner,
+            visibility_listener,
+        }
+    }
+
+    pub fn send(&self, command: PhysicsCommand) {
+        self.inner.borrow_mut().send(command);
+    }
+}
+
+impl Drop for PhysicsClient {
+    fn drop(&mut self) {
+        if let Some(document) = web_sys::window().and_then(|w| w.document()) {
+            let _ = document.remove_event_listener_with_callback(
+                "visibilitychange",
+                self.visibility_listener.as_ref().unchecked_ref(),
+            );
+        }
+    }
+}
+
+impl ClientInner {
+    fn send(&mut self, command: PhysicsCommand) {
+        self.remember(&command);
+        match &self.backend {
+            Some(Backend::Worker(worker)) => worker.post(&command),
+            Some(Backend::Local(local)) => local.handle(command),
+            None => {}
+        }
+    }
+
+    fn remember(&mut self, command: &PhysicsCommand) {
+        self.order.apply(command);
+        match command {
+            PhysicsCommand::Init { .. } => self.pinned.clear(),
+            PhysicsCommand::RemoveNode { id } => {
+                self.pinned.remove(id);
+            }
+            PhysicsCommand::SetPinned { id, pinned: true } => {
+                self.pinned.insert(*id);
+            }
+            PhysicsCommand::SetPinned { id, pinned: false } => {
+                self.pinned.remove(id);
+            }
+            PhysicsCommand::Pause => self.paused = true,
+            PhysicsCommand::Resume => self.paused = false,
+            _ => {}
+        }
+        match (command, &mut self.init) {
+            (PhysicsCommand::Init { .. }, init) => {
+                *init = Some(command.clone());
+                self.arrange = None;
+            }
+            (PhysicsCommand::Arrange { .. }, _) => {
+                self.arrange = Some(command.clone());
+            }
+            (PhysicsCommand::AddNode { node }, Some(PhysicsCommand::Init { nodes, .. }))
+                if !nodes.iter().any(|other| other.id == node.id) =>
+            {
+                nodes.push(node.clone());
+            }
+            (
+                PhysicsCommand::RemoveNode { id },
+                Some(PhysicsCommand::Init { nodes, edges, .. }),
+            ) => {
+                nodes.retain(|node| node.id != *id);
+                edges.retain(|edge| edge.from != *id && edge.to != *id);
+            }
+            (PhysicsCommand::AddEdge { edge }, Some(PhysicsCommand::Init { edges, .. })) => {
+                edges.push(*edge);
+            }
+            (PhysicsCommand::RemoveEdge { from, to }, Some(PhysicsCommand::Init { edges, .. })) => {
+                edges.retain(|edge| edge.key() != (*from, *to));
+            }
+            (
+                PhysicsCommand::UpdateForceSettings { settings },
+                Some(PhysicsCommand::Init {
+                    settings: current, ..
+                }),
+            ) => *current = *settings,
+            (
+                PhysicsCommand::SetCenter { center },
+                Some(PhysicsCommand::Init {
+                    center: current, ..
+                }),
+            ) => *current = *center,
+            _ => {}
+        }
+    }
+
+    /// ワーカーが読み込めなかった場合に同じスレッドでの実行に切り替える
+    fn fall_back_to_local(&mut self) {
+        web_sys::console::warn_1(&"Physics worker failed, running on the main thread".into());
+        let local = LocalBackend::new(Rc::clone(&self.on_positions));
+        if let Some(init) = self.init.clone() {
+            local.handle(init);
+        }
+        if let Some(arrange) = self.arrange.clone() {
+            local.handle(arrange);
+        }
+        for &id in &self.pinned {
+            local.handle(PhysicsCommand::SetPinned { id, pinned: true });
+        }
+        if self.paused {
+            local.handle(PhysicsCommand::Pause);
+        }
+        self.backend = Some(Backend::Local(local));
+    }
+}
+
+struct WorkerBackend {
+    worker: Worker,
+    _on_message: Closure<dyn FnMut(MessageEvent)>,
+    _on_error: Closure<dyn FnMut()>,
+}
+
+impl WorkerBackend {
+    fn spawn(inner: Weak<RefCell<ClientInner>>) -> Result<Self, JsValue> {
+        let worker = Worker::new(&get_config().get_url(WORKER_SCRIPT))?;
+
+        // 受け取った位置をノード順と合わせて渡す
+        // 直近のノード追加・削除より前に詰められたバッファは並びが違うので捨てる
+        let on_message = {
+            let inner = inner.clone();
+            Closure::wrap(Box::new(move |e: MessageEvent| {
+                let Some(inner) = inner.upgrade() else {
+                    return;
+                };
+                let message = js_sys::Array::from(&e.data());
+                let generation = message.get(0).as_f64();
+                let Ok(array) = message.get(1).dyn_into::<js_sys::Float32Array>() else {
+                    return;
+                };
+                let inner = inner.borrow();
+                if generation != Some(inner.order.generation() as f64) {
+                    return;
+                }
+                let buffer = array.to_vec();
+                (inner.on_positions)(inner.order.ids(), &buffer);
+            }) as Box<dyn FnMut(MessageEvent)>)
+        };
+        worker.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+
+        // スクリプトの読み込みに失敗したらフォールバック
+        // 実行中のクロージャを破棄しないよう、切り替えはイベント処理の後に行う
+        let on_error = Closure::wrap(Box::new(move || {
+            let inner = inner.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                if let Some(inner) = inner.upgrade() {
+                    inner.borrow_mut().fall_back_to_local();
+                }
+            });
+        }) as Box<dyn FnMut()>);
+        worker.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+
+        Ok(Self {
+            worker,
+            _on_message: on_message,
+            _on_error: on_error,
+        })
+    }
+
+    fn post(&self, command: &PhysicsCommand) {
+        post_command(&self.worker, command);
+    }
+}
+
+impl Drop for WorkerBackend {
+    fn drop(&mut self) {
+        self.worker.set_onmessage(None);
+        self.worker.set_onerror(None);
+        self.worker.terminate();
+    }
+}
+
+fn post_command(worker: &Worker, command: &PhysicsCommand) {
+    match serde_wasm_bindgen::to_value(command) {
+        Ok(message) => {
+            if let Err(err) = worker.post_message(&message) {
+                web_sys::console::error_1(&err);
+            }
+        }
+        Err(err) => {
+            web_sys::console::error_1(&format!("Failed to encode physics command: {}", err).into())
+        }
+    }
+}
+
+/// 同じスレッドでエンジンを動かす実装
+struct LocalBackend {
+    engine: Rc<RefCell<PhysicsEngine>>,
+    simulation: SimulationLoop,
+}
+
+impl LocalBackend {
+    fn new(on_positions: PositionsCallback) -> Self {
+        let engine = Rc::new(RefCell::new(PhysicsEngine::new()));
+        let simulation = {
+            let engine = Rc::clone(&engine);
+            let mut buffer = Vec::new();
+            SimulationLoop::new(PhysicsConfig::PHYSICS_FPS, move |steps| {
+                let mut engine = engine.borrow_mut();
+                engine.step(steps);
+                if steps > 0 {
+                    engine.pack_positions(&mut buffer);
+                    on_positions(engine.order(), &buffer);
+                }
+                engine.energy()
+            })
+        };
+        Self { engine, simulation }
+    }
+
+    fn handle(&self, command: PhysicsCommand) {
+        match command {
+            PhysicsCommand::Pause => self.simulation.set_paused(true),
+            PhysicsCommand::Resume => self.simulation.set_paused(false),
+            command => {
+                let wake = command.wakes_simulation();
+                self.engine.borrow_mut().handle(command);
+                if wake {
+                    self.simulation.wake();
+                }
+            }
+        }
+    }
+}
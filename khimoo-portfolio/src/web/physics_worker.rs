@@ -0,0 +1,69 @@
+//! 物理シミュレーション用ワーカーの本体
+//!
+//! メインスレッドから`PhysicsCommand`を受け取ってエンジンに渡し、
+//! 動いている間は毎フレーム位置を`Float32Array`（[x0, y0, x1, y1, ...]）で送り返す。
+//! ノードの追加・削除で並びが変わるので、並びの世代と組にして`[generation, positions]`で送る。
+//! バッファは転送するのでコピーは発生しない。
+
+use crate::core::physics::{PhysicsCommand, PhysicsEngine};
+use crate::web::config::PhysicsConfig;
+use crate::web::simulation_loop::SimulationLoop;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{DedicatedWorkerGlobalScope, MessageEvent};
+
+/// ワーカーのエントリーポイント。メッセージの受信を開始する
+pub fn run() {
+    let scope: DedicatedWorkerGlobalScope = js_sys::global().unchecked_into();
+    let engine = Rc::new(RefCell::new(PhysicsEngine::new()));
+
+    let simulation = {
+        let engine = Rc::clone(&engine);
+        let scope = scope.clone();
+        let mut buffer = Vec::new();
+        SimulationLoop::new(PhysicsConfig::PHYSICS_FPS, move |steps| {
+            let mut engine = engine.borrow_mut();
+            engine.step(steps);
+            if steps > 0 {
+                engine.pack_positions(&mut buffer);
+                post_positions(&scope, engine.generation(), &buffer);
+            }
+            engine.energy()
+        })
+    };
+
+    let on_message = Closure::wrap(Box::new(move |e: MessageEvent| {
+        let command: PhysicsCommand = match serde_wasm_bindgen::from_value(e.data()) {
+            Ok(command) => command,
+            Err(err) => {
+                web_sys::console::error_1(&format!("Invalid physics command: {}", err).into());
+                return;
+            }
+        };
+        match command {
+            PhysicsCommand::Pause => simulation.set_paused(true),
+            PhysicsCommand::Resume => simulation.set_paused(false),
+            command => {
+                let wake = command.wakes_simulation();
+                engine.borrow_mut().handle(command);
+                if wake {
+                    simulation.wake();
+                }
+            }
+        }
+    }) as Box<dyn FnMut(MessageEvent)>);
+    scope.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+    // ワーカーが終了するまで受信し続けるので解放しない
+    on_message.forget();
+}
+
+fn post_positions(scope: &DedicatedWorkerGlobalScope, generation: u32, buffer: &[f32]) {
+    let array = js_sys::Float32Array::from(buffer);
+    let message = js_sys::Array::of2(&generation.into(), &array);
+    let transfer = js_sys::Array::of1(&array.buffer());
+    if let Err(err) = scope.post_message_with_transfer(&message, &transfer) {
+        web_sys::console::error_1(&err);
+    }
+}
@@ -0,0 +1,217 @@
+use crate::core::physics::{FixedTimestep, SettleDetector};
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// 静止とみなす運動エネルギー（単位質量あたり、速度約1px/sに相当）
+pub const SETTLE_ENERGY_THRESHOLD: f32 = 0.5;
+/// 静止と判定するまでに閾値を下回り続ける必要があるフレーム数
+pub const SETTLE_FRAMES: usize = 30;
+/// ワーカーでのフレーム間隔（ms）。位置の送信を画面の更新頻度程度に抑える
+const WORKER_FRAME_MS: i32 = 16;
+
+type FrameCallback = Box<dyn FnMut(usize) -> Option<f32>>;
+/// 自身を再要求するフレーム用クロージャ
+type FrameClosure = Rc<RefCell<Option<Closure<dyn FnMut()>>>>;
+
+/// 予約済みのフレーム。ウィンドウではrequestAnimationFrame、ワーカーではsetTimeoutを使う
+#[derive(Clone, Copy)]
+enum ScheduledFrame {
+    AnimationFrame(i32),
+    Timeout(i32),
+}
+
+struct LoopState {
+    timestep: FixedTimestep,
+    settle: SettleDetector,
+    last_time: Option<f64>,
+    frame_id: Option<ScheduledFrame>,
+    paused: bool,
+    on_frame: FrameCallback,
+}
+
+/// 固定タイムステップのシミュレーションループ
+///
+/// `on_frame`はそのフレームで進めるステップ数を受け取り、運動エネルギーを返す。
+/// エネルギーが一定時間閾値を下回るとループは停止し、`wake`で再開する。
+/// Noneを返すと（ドラッグ中など）静止判定を行わずに動き続ける。
+/// メインスレッドではrequestAnimationFrameで駆動し、ページが非表示の間は止まる。
+/// ワーカー内ではsetTimeoutで駆動し、`set_paused`で止める。
+pub struct SimulationLoop {
+    state: Rc<RefCell<LoopState>>,
+    frame: FrameClosure,
+    visibility_listener: Option<Closure<dyn FnMut()>>,
+}
+
+impl SimulationLoop {
+    pub fn new<F>(steps_per_second: u32, on_frame: F) -> Self
+    where
+        F: FnMut(usize) -> Option<f32> + 'static,
+    {
+        let state = Rc::new(RefCell::new(LoopState {
+            timestep: FixedTimestep::new(steps_per_second),
+            settle: SettleDetector::new(SETTLE_ENERGY_THRESHOLD, SETTLE_FRAMES),
+            last_time: None,
+            frame_id: None,
+            paused: false,
+            on_frame: Box::new(on_frame),
+        }));
+        let frame: FrameClosure = Rc::new(RefCell::new(None));
+
+        // フレームごとの処理。継続する場合は自身を再度要求する
+        {
+            let state = Rc::clone(&state);
+            let frame_handle = Rc::clone(&frame);
+            *frame.borrow_mut() = Some(Closure::wrap(Box::new(move || {
+                let mut state = state.borrow_mut();
+                state.frame_id = None;
+                if state.paused || is_page_hidden() {
+                    return;
+                }
+
+                // 初回は1ステップ分進める
+                let time = now_ms();
+                let elapsed = state
+                    .last_time
+                    .map_or(state.timestep.step_ms(), |last| time - last);
+                state.last_time = Some(time);
+                let steps = state.timestep.advance(elapsed);
+
+                let settled = match (state.on_frame)(steps) {
+                    Some(energy) if steps > 0 => state.settle.observe(energy),
+                    Some(_) => state.settle.is_settled(),
+                    None => {
+                        state.settle.reset();
+                        false
+                    }
+                };
+                if !settled {
+                    state.frame_id = request_frame(&frame_handle);
+                }
+            }) as Box<dyn FnMut()>));
+        }
+
+        let mut simulation_loop = Self {
+            state,
+            frame,
+            visibility_listener: None,
+        };
+        simulation_loop.listen_visibility();
+        simulation_loop
+    }
+
+    /// ループを（止まっていれば）再開し、静止判定をやり直す
+    pub fn wake(&self) {
+        wake(&self.state, &self.frame);
+    }
+
+    /// 一時停止と再開（ワーカーではページの表示状態を直接見られないため、これで止める）
+    pub fn set_paused(&self, paused: bool) {
+        self.state.borrow_mut().paused = paused;
+        if paused {
+            cancel(&self.state);
+        } else {
+            wake(&self.state, &self.frame);
+        }
+    }
+
+    // ページが非表示になったら止め、表示されたら再開する
+    fn listen_visibility(&mut self) {
+        let Some(document) = web_sys::window().and_then(|w| w.document()) else {
+            return;
+        };
+        let state = Rc::clone(&self.state);
+        let frame = Rc::clone(&self.frame);
+        let listener = Closure::wrap(Box::new(move || {
+            if is_page_hidden() {
+                cancel(&state);
+            } else {
+                wake(&state, &frame);
+            }
+        }) as Box<dyn FnMut()>);
+        let _ = document.add_event_listener_with_callback(
+            "visibilitychange",
+            listener.as_ref().unchecked_ref(),
+        );
+        self.visibility_listener = Some(listener);
+    }
+}
+
+impl Drop for SimulationLoop {
+    fn drop(&mut self) {
+        cancel(&self.state);
+        if let Some(listener) = self.visibility_listener.take() {
+            if let Some(document) = web_sys::window().and_then(|w| w.document()) {
+                let _ = document.remove_event_listener_with_callback(
+                    "visibilitychange",
+                    listener.as_ref().unchecked_ref(),
+                );
+            }
+        }
+        // フレームのクロージャは自身への参照を持つので、明示的に外して循環を断つ
+        self.frame.borrow_mut().take();
+    }
+}
+
+fn is_page_hidden() -> bool {
+    web_sys::window()
+        .and_then(|w| w.document())
+        .is_some_and(|document| document.hidden())
+}
+
+/// 現在時刻（ms）。ウィンドウでもワーカーでも使えるようグローバルのperformanceを参照する
+pub fn now_ms() -> f64 {
+    js_sys::Reflect::get(&js_sys::global(), &JsValue::from_str("performance"))
+        .ok()
+        .and_then(|performance| performance.dyn_into::<web_sys::Performance>().ok())
+        .map_or(0.0, |performance| performance.now())
+}
+
+fn request_frame(frame: &FrameClosure) -> Option<ScheduledFrame> {
+    let frame = frame.borrow();
+    let callback = frame.as_ref()?.as_ref().unchecked_ref();
+    if let Some(window) = web_sys::window() {
+        return window
+            .request_animation_frame(callback)
+            .ok()
+            .map(ScheduledFrame::AnimationFrame);
+    }
+    let scope = js_sys::global()
+        .dyn_into::<web_sys::WorkerGlobalScope>()
+        .ok()?;
+    scope
+        .set_timeout_with_callback_and_timeout_and_arguments_0(callback, WORKER_FRAME_MS)
+        .ok()
+        .map(ScheduledFrame::Timeout)
+}
+
+fn wake(state: &Rc<RefCell<LoopState>>, frame: &FrameClosure) {
+    let mut state = state.borrow_mut();
+    state.settle.reset();
+    if state.frame_id.is_some() || state.paused || is_page_hidden() {
+        return;
+    }
+    // 停止中の経過時間はシミュレーションに反映しない
+    state.last_time = None;
+    state.timestep.reset();
+    state.frame_id = request_frame(frame);
+}
+
+fn cancel(state: &Rc<RefCell<LoopState>>) {
+    let Some(scheduled) = state.borrow_mut().frame_id.take() else {
+        return;
+    };
+    match scheduled {
+        ScheduledFrame::AnimationFrame(id) => {
+            if let Some(window) = web_sys::window() {
+                let _ = window.cancel_animation_frame(id);
+            }
+        }
+        ScheduledFrame::Timeout(id) => {
+            if let Ok(scope) = js_sys::global().dyn_into::<web_sys::WorkerGlobalScope>() {
+                scope.clear_timeout_with_handle(id);
+            }
+        }
+    }
+}
@@ -1,4 +1,6 @@
 use super::theme::*;
+use crate::core::graph::svg::{edge_stroke, DEFAULT_NODE_FILL};
+use crate::core::physics::ConnectionLineType;
 
 /// Button styles
 pub struct ButtonStyles;
@@ -137,19 +139,236 @@ impl NodeStyles {
         )
     }
 
+    /// Fill of nodes without a category
+    pub const DEFAULT_NODE_COLOR: &'static str = DEFAULT_NODE_FILL;
+
     pub fn node_circle(size: f64) -> String {
         format!(
-            "width: {}px; height: {}px; background-color: slateblue; border-radius: {}; transform: translate(-50%, -50%); position: absolute;",
+            "width: {}px; height: {}px; background-color: {}; border-radius: {}; transform: translate(-50%, -50%); position: absolute;",
             size,
             size,
+            Self::DEFAULT_NODE_COLOR,
             BORDER_RADIUS.full
         )
     }
 
-    pub fn connection_line() -> String {
+    /// Fill of a node in its category colour
+    pub fn category_fill(color: &str) -> String {
+        format!("background-color: {};", color)
+    }
+
+    /// Outline marking a node pinned by long-press
+    pub fn pinned_outline() -> String {
         format!(
-            "stroke: {}; stroke-width: 1.5; opacity: 0.6;",
-            DARK_THEME.text_muted
+            "outline: 3px solid {}; outline-offset: 2px;",
+            DARK_THEME.accent_orange
+        )
+    }
+
+    /// Ring marking a node whose neighbourhood is expanded
+    pub fn expanded_ring() -> String {
+        format!(
+            "box-shadow: 0 0 0 4px {}, 0 4px 8px rgba(0,0,0,0.2);",
+            DARK_THEME.accent_blue
+        )
+    }
+
+    /// Edge colour by connection type (also used for its arrowhead)
+    pub fn connection_color(kind: ConnectionLineType) -> &'static str {
+        match kind {
+            ConnectionLineType::DirectLink | ConnectionLineType::Medium => DARK_THEME.text_muted,
+            ConnectionLineType::Bidirectional => DARK_THEME.link_color,
+            ConnectionLineType::AuthorToArticle => DARK_THEME.accent_orange,
+            ConnectionLineType::Strong => DARK_THEME.text_secondary,
+            ConnectionLineType::Weak => DARK_THEME.text_muted,
+        }
+    }
+
+    /// Edge styled by connection type; heavier and stronger edges are wider and more opaque
+    pub fn connection_line(kind: ConnectionLineType, strength: f32) -> String {
+        let (width, opacity) = edge_stroke(kind, strength);
+        format!(
+            "stroke: {}; stroke-width: {:.2}; opacity: {:.2}; fill: none; {}",
+            Self::connection_color(kind),
+            width,
+            opacity,
+            if kind == ConnectionLineType::Weak {
+                "stroke-dasharray: 4 4;"
+            } else {
+                ""
+            }
+        )
+    }
+
+    /// Edge touching the hovered node
+    pub fn connection_line_highlighted() -> String {
+        format!(
+            "stroke: {}; stroke-width: 2.5; opacity: 1; fill: none;",
+            DARK_THEME.accent_blue
+        )
+    }
+
+    /// Relation label on an edge, outlined so it stays readable over other edges
+    pub fn edge_label() -> String {
+        format!(
+            "fill: {}; font-size: 11px; text-anchor: middle; dominant-baseline: middle; paint-order: stroke; stroke: {}; stroke-width: 3px;",
+            DARK_THEME.text_secondary, DARK_THEME.primary_bg
+        )
+    }
+
+    /// Nodes and edges unrelated to the hovered node fade into the background
+    pub fn faded() -> &'static str {
+        "opacity: 0.2;"
+    }
+}
+
+/// Preview card shown while a graph node is hovered or focused
+pub struct PreviewCardStyles;
+
+impl PreviewCardStyles {
+    /// Anchored below the node's screen position
+    pub fn card(x: f32, y: f32) -> String {
+        format!(
+            "position: absolute; left: {:.0}px; top: {:.0}px; transform: translateX(-50%); width: 260px; background: {}; color: {}; padding: {}; border-radius: {}; box-shadow: 0 4px 16px rgba(0, 0, 0, 0.3); z-index: {}; pointer-events: none; font-size: {};",
+            x,
+            y,
+            DARK_THEME.surface,
+            DARK_THEME.text_primary,
+            SPACING.md,
+            BORDER_RADIUS.md,
+            Z_INDEX.dropdown,
+            TYPOGRAPHY.body_sm
+        )
+    }
+
+    pub fn title() -> String {
+        format!(
+            "margin: 0 0 {} 0; font-size: {}; font-weight: bold;",
+            SPACING.xs, TYPOGRAPHY.body_md
+        )
+    }
+
+    pub fn summary() -> String {
+        format!(
+            "margin: {} 0; color: {}; line-height: 1.5;",
+            SPACING.xs, DARK_THEME.text_secondary
+        )
+    }
+
+    pub fn meta() -> String {
+        format!(
+            "display: flex; flex-wrap: wrap; align-items: center; gap: {}; color: {}; font-size: {};",
+            SPACING.sm, DARK_THEME.text_muted, TYPOGRAPHY.caption
+        )
+    }
+}
+
+/// Category and tag legend of the home graph
+pub struct LegendStyles;
+
+impl LegendStyles {
+    pub fn panel() -> String {
+        format!(
+            "position: absolute; top: {}; left: {}; background: {}; color: {}; padding: {}; border-radius: {}; z-index: {}; max-width: 240px; max-height: 60vh; overflow-y: auto; font-size: {};",
+            SPACING.md,
+            SPACING.md,
+            DARK_THEME.surface,
+            DARK_THEME.text_primary,
+            SPACING.md,
+            BORDER_RADIUS.md,
+            Z_INDEX.dropdown,
+            TYPOGRAPHY.body_sm
+        )
+    }
+
+    /// One toggleable row; hidden entries are dimmed and struck through
+    pub fn item(hidden: bool) -> String {
+        format!(
+            "display: flex; align-items: center; gap: {}; width: 100%; padding: 2px 0; background: none; border: none; color: inherit; font: inherit; cursor: pointer; text-align: left; opacity: {}; text-decoration: {};",
+            SPACING.sm,
+            if hidden { "0.4" } else { "1" },
+            if hidden { "line-through" } else { "none" }
+        )
+    }
+
+    pub fn swatch(color: &str) -> String {
+        format!(
+            "width: 12px; height: 12px; border-radius: {}; background-color: {}; flex-shrink: 0;",
+            BORDER_RADIUS.full, color
+        )
+    }
+
+    pub fn count() -> String {
+        format!("margin-left: auto; color: {};", DARK_THEME.text_muted)
+    }
+
+    /// Tags are shown as chips that wrap
+    pub fn tag(hidden: bool) -> String {
+        format!(
+            "padding: 2px {}; border: 1px solid {}; border-radius: {}; background: none; color: inherit; font: inherit; cursor: pointer; opacity: {}; text-decoration: {};",
+            SPACING.sm,
+            DARK_THEME.text_muted,
+            BORDER_RADIUS.full,
+            if hidden { "0.4" } else { "1" },
+            if hidden { "line-through" } else { "none" }
+        )
+    }
+
+    pub fn section_title() -> String {
+        format!(
+            "margin: {} 0 {} 0; font-size: {}; color: {};",
+            SPACING.sm, SPACING.xs, TYPOGRAPHY.caption, DARK_THEME.text_secondary
+        )
+    }
+}
+
+/// Timeline scrubber in the graph controls
+pub struct TimelineStyles;
+
+impl TimelineStyles {
+    pub fn container() -> String {
+        format!(
+            "display: flex; align-items: center; gap: {}; background: {}; padding: {} {}; border-radius: {};",
+            SPACING.sm, DARK_THEME.surface, SPACING.xs, SPACING.sm, BORDER_RADIUS.md
+        )
+    }
+
+    pub fn slider() -> &'static str {
+        "width: 160px; cursor: pointer;"
+    }
+
+    /// Date label; fixed width so the controls do not jump while playing
+    pub fn label() -> &'static str {
+        "min-width: 80px; font-variant-numeric: tabular-nums;"
+    }
+}
+
+/// Local graph styles (article page sidebar)
+pub struct LocalGraphStyles;
+
+impl LocalGraphStyles {
+    pub fn panel() -> String {
+        format!(
+            "background: {}; border-radius: {}; padding: {};",
+            DARK_THEME.surface, BORDER_RADIUS.md, SPACING.sm
+        )
+    }
+
+    pub fn title() -> String {
+        format!(
+            "margin: 0 0 {} 0; font-size: {}; color: {};",
+            SPACING.sm, TYPOGRAPHY.body_sm, DARK_THEME.text_secondary
+        )
+    }
+
+    /// Square drawing area; the graph inside is scaled down to fit
+    pub fn viewport(size: f32) -> String {
+        format!("position: relative; width: {size}px; height: {size}px; overflow: hidden;")
+    }
+
+    pub fn canvas(width: f32, height: f32, scale: f32, offset: f32) -> String {
+        format!(
+            "position: absolute; left: {offset:.1}px; top: {offset:.1}px; width: {width:.1}px; height: {height:.1}px; transform: scale({scale:.4}); transform-origin: 0 0;"
         )
     }
 }
@@ -60,13 +60,45 @@ impl LayoutStyles {
     }
 
     /// Physics simulation container
+    /// Browser pinch zoom is disabled so pinch gestures zoom the graph instead
     pub fn physics_container() -> String {
         format!(
-            "display: flex; width: 100%; height: 100%; background: {}; position: relative;",
+            "display: flex; width: 100%; height: 100%; background: {}; position: relative; overflow: hidden; touch-action: pan-x pan-y;",
             DARK_THEME.primary_bg
         )
     }
 
+    /// Zoomable graph layer; `transform` maps world coordinates to the container
+    pub fn graph_layer(transform: &str) -> String {
+        format!(
+            "position: absolute; left: 0; top: 0; width: 100%; height: 100%; transform-origin: 0 0; transform: {};",
+            transform
+        )
+    }
+
+    /// Zoom control buttons in the bottom-right corner of the graph
+    pub fn zoom_controls() -> String {
+        format!(
+            "position: absolute; right: {}; bottom: {}; display: flex; align-items: center; gap: {}; z-index: {};",
+            SPACING.md,
+            SPACING.md,
+            SPACING.sm,
+            Z_INDEX.dropdown
+        )
+    }
+
+    /// Graph options in the bottom-left corner, opposite the zoom controls
+    pub fn graph_controls() -> String {
+        format!(
+            "position: absolute; left: {}; bottom: {}; display: flex; flex-wrap: wrap; align-items: center; gap: {}; z-index: {}; color: {}; font-size: 12px;",
+            SPACING.md,
+            SPACING.md,
+            SPACING.sm,
+            Z_INDEX.dropdown,
+            DARK_THEME.text_secondary
+        )
+    }
+
     /// Welcome message overlay
     pub fn welcome_overlay() -> String {
         format!(
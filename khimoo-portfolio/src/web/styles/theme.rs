@@ -1,3 +1,4 @@
+use crate::config::CATEGORY_PALETTE;
 use std::collections::HashMap;
 
 /// Core color palette for the application
@@ -81,63 +82,19 @@ pub struct CategoryColor {
 
 /// Get default category colors
 pub fn get_default_category_colors() -> HashMap<String, CategoryColor> {
-    let mut colors = HashMap::new();
-
-    colors.insert(
-        "programming".to_string(),
-        CategoryColor {
-            primary: DARK_THEME.accent_blue.to_string(),
-            secondary: "#357ABD".to_string(),
-            text: DARK_THEME.text_primary.to_string(),
-        },
-    );
-
-    colors.insert(
-        "web".to_string(),
-        CategoryColor {
-            primary: DARK_THEME.accent_green.to_string(),
-            secondary: "#5BA517".to_string(),
-            text: DARK_THEME.text_primary.to_string(),
-        },
-    );
-
-    colors.insert(
-        "rust".to_string(),
-        CategoryColor {
-            primary: DARK_THEME.accent_red.to_string(),
-            secondary: "#A0341F".to_string(),
-            text: DARK_THEME.text_primary.to_string(),
-        },
-    );
-
-    colors.insert(
-        "design".to_string(),
-        CategoryColor {
-            primary: DARK_THEME.accent_purple.to_string(),
-            secondary: "#9013B0".to_string(),
-            text: DARK_THEME.text_primary.to_string(),
-        },
-    );
-
-    colors.insert(
-        "tutorial".to_string(),
-        CategoryColor {
-            primary: DARK_THEME.accent_orange.to_string(),
-            secondary: "#D1891C".to_string(),
-            text: DARK_THEME.text_primary.to_string(),
-        },
-    );
-
-    colors.insert(
-        "default".to_string(),
-        CategoryColor {
-            primary: "#9B9B9B".to_string(),
-            secondary: "#7B7B7B".to_string(),
-            text: DARK_THEME.text_primary.to_string(),
-        },
-    );
-
-    colors
+    CATEGORY_PALETTE
+        .iter()
+        .map(|(name, primary, secondary)| {
+            (
+                name.to_string(),
+                CategoryColor {
+                    primary: primary.to_string(),
+                    secondary: secondary.to_string(),
+                    text: DARK_THEME.text_primary.to_string(),
+                },
+            )
+        })
+        .collect()
 }
 
 /// Typography scale
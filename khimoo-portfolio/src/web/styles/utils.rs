@@ -15,6 +15,40 @@ impl AnimationStyles {
         "#
     }
 
+    /// Length of the node enter and exit animations
+    pub const NODE_TRANSITION_MS: i32 = 300;
+
+    /// CSS keyframes for nodes entering and leaving the graph
+    /// Uses the `scale` property so the centring `transform` is left alone.
+    pub fn node_transition_keyframes() -> &'static str {
+        r#"
+        @keyframes node-enter {
+            0% { opacity: 0; scale: 0.3; }
+            100% { opacity: 1; scale: 1; }
+        }
+        @keyframes node-exit {
+            0% { opacity: 1; scale: 1; }
+            100% { opacity: 0; scale: 0.3; }
+        }
+        "#
+    }
+
+    /// Animation of a node entering the graph
+    pub fn node_enter() -> String {
+        format!(
+            "animation: node-enter {}ms ease-out;",
+            Self::NODE_TRANSITION_MS
+        )
+    }
+
+    /// Animation of a node leaving the graph; it stays hidden at the end
+    pub fn node_exit() -> String {
+        format!(
+            "animation: node-exit {}ms ease-in forwards; pointer-events: none;",
+            Self::NODE_TRANSITION_MS
+        )
+    }
+
     /// Fade in animation
     pub fn fade_in(duration: &str) -> String {
         format!(
@@ -0,0 +1,87 @@
+//! 表示中のグラフをSVGファイルとして書き出す
+
+use crate::core::graph::svg::{SvgEdge, SvgNode, SvgSnapshot, SvgTheme};
+use crate::web::styles::{NodeStyles, DARK_THEME};
+use crate::web::types::*;
+use std::collections::HashMap;
+use wasm_bindgen::JsCast;
+
+/// 書き出すファイル名
+pub const SVG_FILE_NAME: &str = "article-graph.svg";
+
+/// 画面と同じ配色のテーマ
+pub fn svg_theme() -> SvgTheme {
+    SvgTheme {
+        background: DARK_THEME.primary_bg,
+        text: DARK_THEME.text_primary,
+        edge_color: NodeStyles::connection_color,
+    }
+}
+
+/// レジストリの現在の位置・半径・カテゴリ色・ラベル・エッジ（退場中のノードは除く）
+pub fn snapshot(registry: &NodeRegistry) -> SvgSnapshot {
+    let mut ids: Vec<NodeId> = registry
+        .positions
+        .keys()
+        .filter(|id| !registry.is_exiting(**id))
+        .copied()
+        .collect();
+    ids.sort_by_key(|id| id.0);
+    let index_of: HashMap<NodeId, usize> = ids.iter().enumerate().map(|(i, id)| (*id, i)).collect();
+
+    let nodes = ids
+        .iter()
+        .map(|id| SvgNode {
+            position: registry.positions[id],
+            radius: registry.radii.get(id).copied().unwrap_or_default() as f32,
+            fill: registry
+                .get_node_category(*id)
+                .map(|category| registry.get_category_color(category).primary.clone())
+                .unwrap_or_else(|| NodeStyles::DEFAULT_NODE_COLOR.to_string()),
+            label: registry.contents.get(id).map(label).unwrap_or_default(),
+        })
+        .collect();
+    let edges = registry
+        .connection_lines
+        .iter()
+        .filter(|line| line.visible)
+        .filter_map(|line| {
+            Some(SvgEdge {
+                from: *index_of.get(&line.from)?,
+                to: *index_of.get(&line.to)?,
+                kind: line.connection_type,
+                strength: line.strength,
+                label: line.label.clone(),
+            })
+        })
+        .collect();
+    SvgSnapshot { nodes, edges }
+}
+
+/// ノードに表示している文字（画像だけのノードは空）
+fn label(content: &NodeContent) -> String {
+    match content {
+        NodeContent::Text(text) => text.clone(),
+        NodeContent::Link { text, .. } => text.clone(),
+        NodeContent::Author { name, .. } => name.clone(),
+        NodeContent::Article { title, .. } => title.clone(),
+        NodeContent::Image(_) => String::new(),
+    }
+}
+
+/// SVGをダウンロードさせる（data URLを持つリンクをクリックする）
+pub fn download(svg: &str) {
+    let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+        return;
+    };
+    let Ok(element) = document.create_element("a") else {
+        return;
+    };
+    let Ok(anchor) = element.dyn_into::<web_sys::HtmlAnchorElement>() else {
+        return;
+    };
+    let encoded: String = js_sys::encode_uri_component(svg).into();
+    anchor.set_href(&format!("data:image/svg+xml;charset=utf-8,{}", encoded));
+    anchor.set_download(SVG_FILE_NAME);
+    anchor.click();
+}
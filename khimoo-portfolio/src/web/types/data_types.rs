@@ -1,8 +1,8 @@
 use super::node_types::*;
-use super::physics_types::Position;
+use super::physics_types::{PhysicsNode, Position};
 use crate::web::styles::{get_default_category_colors, CategoryColor};
 use crate::config::NodeConfig;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// ノードレジストリ - ノード管理の中心的な構造体
 #[derive(Debug, Clone, PartialEq)]
@@ -17,6 +17,17 @@ pub struct NodeRegistry {
     pub category_colors: HashMap<String, CategoryColor>,
     pub node_importance: HashMap<NodeId, u8>,
     pub node_inbound_counts: HashMap<NodeId, usize>,
+    /// 長押しで固定されたノード
+    pub pinned_nodes: HashSet<NodeId>,
+    /// 全ノードがビルド時に計算済みのレイアウトに配置されているか
+    pub layout_precomputed: bool,
+    /// 退場アニメーション中のノード（物理シミュレーションからは削除済み）
+    pub exiting_nodes: HashSet<NodeId>,
+    /// 近傍を展開しているノード
+    pub expanded_nodes: HashSet<NodeId>,
+    /// カテゴリを物理シミュレーションに渡してまとめるか
+    /// falseならカテゴリは色分けと絞り込みにだけ使う
+    pub cluster_by_category: bool,
     pub node_config: NodeConfig,
 }
 
@@ -35,6 +46,11 @@ impl NodeRegistry {
             category_colors,
             node_importance: HashMap::new(),
             node_inbound_counts: HashMap::new(),
+            pinned_nodes: HashSet::new(),
+            layout_precomputed: false,
+            exiting_nodes: HashSet::new(),
+            expanded_nodes: HashSet::new(),
+            cluster_by_category: true,
             node_config: NodeConfig::default(),
         }
     }
@@ -53,6 +69,11 @@ impl NodeRegistry {
             category_colors,
             node_importance: HashMap::new(),
             node_inbound_counts: HashMap::new(),
+            pinned_nodes: HashSet::new(),
+            layout_precomputed: false,
+            exiting_nodes: HashSet::new(),
+            expanded_nodes: HashSet::new(),
+            cluster_by_category: true,
             node_config,
         }
     }
@@ -96,6 +117,7 @@ impl NodeRegistry {
             .map(|(id, _)| *id)
     }
 
+    /// 種類と強さを持つエッジを追加（描画用の接続線も登録する）
     pub fn add_connection_line(
         &mut self,
         from: NodeId,
@@ -103,12 +125,14 @@ impl NodeRegistry {
         connection_type: ConnectionLineType,
         strength: f32,
     ) {
+        self.edges.push((from, to));
         let line = ConnectionLine {
             from,
             to,
             connection_type,
             strength,
             visible: true,
+            label: None,
         };
         self.connection_lines.push(line);
     }
@@ -173,27 +197,12 @@ impl NodeRegistry {
         &self,
         node_id: NodeId,
         importance: Option<u8>,
-        inbound_count: usize,
+        _inbound_count: usize,
     ) -> i32 {
-        let base_size = if self.is_author_node(node_id) {
-            self.node_config.author_node_radius
-        } else {
-            self.node_config.default_node_radius
-        };
-
         if self.is_author_node(node_id) {
-            return base_size;
+            return self.node_config.author_node_radius;
         }
-
-        let importance_multiplier = importance.unwrap_or(self.node_config.default_importance) as i32;
-        let importance_bonus = (importance_multiplier - self.node_config.default_importance as i32) * self.node_config.importance_multiplier;
-
-        // let inbound_multiplier = (inbound_count as f32).sqrt() as i32;
-        // let inbound_bonus = inbound_multiplier * self.node_config.inbound_link_multiplier;
-
-        // let calculated_size = base_size + importance_bonus + inbound_bonus;
-        let calculated_size = base_size + importance_bonus;
-        calculated_size.clamp(self.node_config.min_node_radius, self.node_config.max_node_radius)
+        self.node_config.article_node_radius(importance)
     }
 
     pub fn iter(&self) -> impl Iterator<Item = (&NodeId, &Position, &i32, &NodeContent)> {
@@ -205,13 +214,86 @@ impl NodeRegistry {
     }
 
     pub fn add_edge(&mut self, from: NodeId, to: NodeId) {
-        self.edges.push((from, to));
+        self.add_connection_line(from, to, ConnectionLineType::DirectLink, 1.0);
+    }
+
+    /// 種類付きのエッジを追加。ラベルはエッジの上に描く
+    pub fn add_typed_edge(&mut self, edge: Edge, label: Option<String>) {
+        self.add_connection_line(edge.from, edge.to, edge.kind, edge.strength);
+        if let Some(line) = self.connection_lines.last_mut() {
+            line.label = label;
+        }
+    }
+
+    /// 物理シミュレーションに渡すエッジ
+    pub fn physics_edges(&self) -> Vec<Edge> {
+        self.connection_lines
+            .iter()
+            .map(ConnectionLine::edge)
+            .collect()
     }
 
     pub fn iter_edges(&self) -> impl Iterator<Item = &(NodeId, NodeId)> {
         self.edges.iter()
     }
 
+    /// エッジで直接つながっているノード（向きは問わない）
+    pub fn neighbours(&self, node_id: NodeId) -> HashSet<NodeId> {
+        self.edges
+            .iter()
+            .filter_map(|&(from, to)| match (from == node_id, to == node_id) {
+                (true, false) => Some(to),
+                (false, true) => Some(from),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn contains_node(&self, node_id: NodeId) -> bool {
+        self.positions.contains_key(&node_id)
+    }
+
+    pub fn has_edge(&self, from: NodeId, to: NodeId) -> bool {
+        self.edges.contains(&(from, to))
+    }
+
+    /// エッジとその接続線を削除。存在したかを返す
+    pub fn remove_edge(&mut self, from: NodeId, to: NodeId) -> bool {
+        let before = self.edges.len();
+        self.edges.retain(|edge| *edge != (from, to));
+        self.connection_lines
+            .retain(|line| (line.from, line.to) != (from, to));
+        self.edges.len() != before
+    }
+
+    /// ノードに接続するエッジと接続線を削除
+    pub fn remove_edges_of(&mut self, node_id: NodeId) {
+        self.edges
+            .retain(|(from, to)| *from != node_id && *to != node_id);
+        self.connection_lines
+            .retain(|line| line.from != node_id && line.to != node_id);
+    }
+
+    /// ノードとそれに関する情報をすべて削除。存在したかを返す
+    pub fn remove_node(&mut self, node_id: NodeId) -> bool {
+        let existed = self.positions.remove(&node_id).is_some();
+        self.radii.remove(&node_id);
+        self.contents.remove(&node_id);
+        self.node_types.remove(&node_id);
+        self.node_categories.remove(&node_id);
+        self.node_importance.remove(&node_id);
+        self.node_inbound_counts.remove(&node_id);
+        self.pinned_nodes.remove(&node_id);
+        self.exiting_nodes.remove(&node_id);
+        self.expanded_nodes.remove(&node_id);
+        self.remove_edges_of(node_id);
+        existed
+    }
+
+    pub fn is_exiting(&self, node_id: NodeId) -> bool {
+        self.exiting_nodes.contains(&node_id)
+    }
+
     pub fn get_node_inbound_count(&self, node_id: NodeId) -> usize {
         self.node_inbound_counts.get(&node_id).copied().unwrap_or(0)
     }
@@ -220,18 +302,89 @@ impl NodeRegistry {
         self.node_inbound_counts.insert(node_id, count);
     }
 
-    pub fn calculate_physics_radius(&self, node_id: NodeId) -> f32 {
-        let visual_radius = self.radii.get(&node_id).copied().unwrap_or(self.node_config.default_node_radius);
-        let importance = self.get_node_importance(node_id);
-
-        if let Some(imp) = importance {
-            if imp >= self.node_config.high_importance_threshold {
-                visual_radius as f32 * self.node_config.physics_radius_multiplier_high_importance
-            } else {
-                visual_radius as f32 * self.node_config.physics_radius_multiplier_default
-            }
+    pub fn is_pinned(&self, node_id: NodeId) -> bool {
+        self.pinned_nodes.contains(&node_id)
+    }
+
+    /// ノードを固定（既に固定されていれば解除）し、固定後の状態を返す
+    pub fn toggle_pinned(&mut self, node_id: NodeId) -> bool {
+        if self.pinned_nodes.remove(&node_id) {
+            false
         } else {
-            visual_radius as f32 * self.node_config.physics_radius_multiplier_default
+            self.pinned_nodes.insert(node_id);
+            true
         }
     }
+
+    /// 物理シミュレーションに渡すノード一覧（ID順、退場中のノードを除く）
+    pub fn physics_nodes(&self) -> Vec<PhysicsNode> {
+        let mut nodes: Vec<PhysicsNode> = self
+            .positions
+            .keys()
+            .filter(|id| !self.is_exiting(**id))
+            .filter_map(|id| self.physics_node(*id))
+            .collect();
+        nodes.sort_by_key(|node| node.id.0);
+        nodes
+    }
+
+    /// 物理シミュレーションに渡すノード
+    pub fn physics_node(&self, node_id: NodeId) -> Option<PhysicsNode> {
+        Some(PhysicsNode {
+            id: node_id,
+            position: *self.positions.get(&node_id)?,
+            radius: self
+                .radii
+                .get(&node_id)
+                .copied()
+                .unwrap_or(self.node_config.default_node_radius) as f32,
+            collider_radius: self.calculate_physics_radius(node_id),
+            category: self
+                .node_categories
+                .get(&node_id)
+                .filter(|_| self.cluster_by_category)
+                .cloned(),
+            is_author: self.is_author_node(node_id),
+        })
+    }
+
+    /// 全ノードを半径込みで囲む矩形 (min, max)。ノードがなければNone
+    pub fn bounds(&self) -> Option<(Position, Position)> {
+        let mut iter = self.iter().map(|(_, pos, radius, _)| {
+            let r = *radius as f32;
+            (
+                Position {
+                    x: pos.x - r,
+                    y: pos.y - r,
+                },
+                Position {
+                    x: pos.x + r,
+                    y: pos.y + r,
+                },
+            )
+        });
+        let first = iter.next()?;
+        Some(iter.fold(first, |(min, max), (lo, hi)| {
+            (
+                Position {
+                    x: min.x.min(lo.x),
+                    y: min.y.min(lo.y),
+                },
+                Position {
+                    x: max.x.max(hi.x),
+                    y: max.y.max(hi.y),
+                },
+            )
+        }))
+    }
+
+    pub fn calculate_physics_radius(&self, node_id: NodeId) -> f32 {
+        let visual_radius = self
+            .radii
+            .get(&node_id)
+            .copied()
+            .unwrap_or(self.node_config.default_node_radius);
+        self.node_config
+            .physics_radius(visual_radius, self.get_node_importance(node_id))
+    }
 }
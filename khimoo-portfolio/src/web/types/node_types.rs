@@ -3,8 +3,7 @@ use crate::web::styles::NodeStyles;
 use yew::{html, Html};
 use yew_router::prelude::*;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
-pub struct NodeId(pub u32);
+pub use crate::core::physics::{ConnectionLineType, Edge, NodeId};
 
 // Special node ID for the author node (always 0)
 pub const AUTHOR_NODE_ID: NodeId = NodeId(0);
@@ -143,14 +142,13 @@ pub struct ConnectionLine {
     pub connection_type: ConnectionLineType,
     pub strength: f32,
     pub visible: bool,
+    /// 関係の種類（型付きの関係のみ）
+    pub label: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
-pub enum ConnectionLineType {
-    DirectLink,
-    Bidirectional,
-    AuthorToArticle,
-    Strong,
-    Medium,
-    Weak,
+impl ConnectionLine {
+    /// 物理シミュレーションに渡すエッジ（種類と強さでばねの強さが決まる）
+    pub fn edge(&self) -> Edge {
+        Edge::new(self.from, self.to, self.connection_type).with_strength(self.strength)
+    }
 }